@@ -0,0 +1,232 @@
+//! Deterministic inputs shaped like the workloads the library is used for.
+//!
+//! Outer contours are counterclockwise and holes clockwise, matching the core crates.
+
+use std::f64::consts::TAU;
+
+use i_shape_ffi::*;
+use i_triangle::i_overlay::i_float::float::point::FloatPoint;
+use i_triangle::i_overlay::i_shape::base::data::{Contour, Shapes};
+
+pub type Point = FloatPoint<f64>;
+
+pub struct Dataset {
+    pub name: &'static str,
+    pub subject: Shapes<Point>,
+    pub clip: Shapes<Point>,
+    /// The boolean operation that is typical for the workload.
+    pub rule: IntOverlayRule,
+}
+
+impl Dataset {
+    pub fn all() -> [Dataset; 3] {
+        [map_tile(), glyphs(), cad_part()]
+    }
+
+    /// Points across subject and clip, the input of an overlay.
+    pub fn points_count(&self) -> usize {
+        self.subject_points_count() + self.clip.iter().flatten().map(Vec::len).sum::<usize>()
+    }
+
+    /// Points of the subject, the input of the single-operand operations.
+    pub fn subject_points_count(&self) -> usize {
+        self.subject.iter().flatten().map(Vec::len).sum()
+    }
+}
+
+/// Building footprints on a city block grid, cut by a road network.
+fn map_tile() -> Dataset {
+    const LOTS: usize = 24;
+    const LOT: f64 = 40.0;
+
+    let mut subject = Vec::new();
+    for row in 0..LOTS {
+        for col in 0..LOTS {
+            let seed = row * LOTS + col;
+            let x = col as f64 * LOT + 2.0 + jitter(seed, 4.0);
+            let y = row as f64 * LOT + 2.0 + jitter(seed + 1, 4.0);
+            let w = 20.0 + jitter(seed + 2, 12.0);
+            let h = 20.0 + jitter(seed + 3, 12.0);
+            let notch = 4.0 + jitter(seed + 4, 6.0);
+            // An L-shaped footprint with a courtyard notch cut from the top right corner.
+            subject.push(vec![vec![
+                point(x, y),
+                point(x + w, y),
+                point(x + w, y + h - notch),
+                point(x + w - notch, y + h - notch),
+                point(x + w - notch, y + h),
+                point(x, y + h),
+            ]]);
+        }
+    }
+
+    let size = LOTS as f64 * LOT;
+    let mut clip = Vec::new();
+    for i in 1..LOTS / 4 {
+        let offset = (4 * i) as f64 * LOT - 6.0;
+        clip.push(vec![rectangle(offset, -10.0, 12.0, size + 20.0)]);
+        clip.push(vec![rectangle(-10.0, offset, size + 20.0, 12.0)]);
+    }
+
+    Dataset {
+        name: "map_tile",
+        subject,
+        clip,
+        rule: IntOverlayRule::Difference,
+    }
+}
+
+/// A line of text: wobbly rings with counters, unioned with a shifted copy as a drop shadow.
+fn glyphs() -> Dataset {
+    const COLUMNS: usize = 16;
+    const ROWS: usize = 4;
+
+    let mut subject = Vec::new();
+    for row in 0..ROWS {
+        for col in 0..COLUMNS {
+            let seed = row * COLUMNS + col;
+            let cx = col as f64 * 30.0;
+            let cy = row as f64 * 40.0;
+            let wobble = 0.5 + jitter(seed, 1.5);
+            let outer = ring(cx, cy, 12.0, 16.0, wobble, 96);
+            let mut counter = ring(cx, cy, 6.0, 9.0, wobble * 0.5, 64);
+            counter.reverse();
+            subject.push(vec![outer, counter]);
+        }
+    }
+
+    let clip = subject
+        .iter()
+        .map(|shape| {
+            shape
+                .iter()
+                .map(|contour| {
+                    contour
+                        .iter()
+                        .map(|p| point(p.x + 2.0, p.y - 2.0))
+                        .collect()
+                })
+                .collect()
+        })
+        .collect();
+
+    Dataset {
+        name: "glyphs",
+        subject,
+        clip,
+        rule: IntOverlayRule::Union,
+    }
+}
+
+/// A rounded mounting plate with a grid of bolt holes, milled with slots.
+fn cad_part() -> Dataset {
+    let mut plate = vec![rounded_rectangle(0.0, 0.0, 400.0, 200.0, 20.0, 16)];
+    for row in 0..5 {
+        for col in 0..10 {
+            let mut hole = ring(
+                30.0 + col as f64 * 38.0,
+                25.0 + row as f64 * 37.5,
+                8.0,
+                8.0,
+                0.0,
+                48,
+            );
+            hole.reverse();
+            plate.push(hole);
+        }
+    }
+
+    let clip = (0..6)
+        .map(|i| {
+            vec![rounded_rectangle(
+                20.0 + i as f64 * 62.0,
+                60.0,
+                40.0,
+                80.0,
+                20.0,
+                16,
+            )]
+        })
+        .collect();
+
+    Dataset {
+        name: "cad_part",
+        subject: vec![plate],
+        clip,
+        rule: IntOverlayRule::Difference,
+    }
+}
+
+fn point(x: f64, y: f64) -> Point {
+    FloatPoint::new(x, y)
+}
+
+/// A repeatable pseudo-random value in `[0, scale)`.
+fn jitter(seed: usize, scale: f64) -> f64 {
+    let hash = (seed as u64 ^ 0x9E37_79B9_7F4A_7C15).wrapping_mul(0xBF58_476D_1CE4_E5B9);
+    (hash >> 40) as f64 / (1u64 << 24) as f64 * scale
+}
+
+fn rectangle(x: f64, y: f64, w: f64, h: f64) -> Contour<Point> {
+    vec![
+        point(x, y),
+        point(x + w, y),
+        point(x + w, y + h),
+        point(x, y + h),
+    ]
+}
+
+/// A counterclockwise ellipse with a radial wobble, approximated by `count` points.
+fn ring(cx: f64, cy: f64, rx: f64, ry: f64, wobble: f64, count: usize) -> Contour<Point> {
+    (0..count)
+        .map(|i| {
+            let angle = TAU * i as f64 / count as f64;
+            let scale = 1.0 + wobble * (5.0 * angle).sin() / rx;
+            point(cx + scale * rx * angle.cos(), cy + scale * ry * angle.sin())
+        })
+        .collect()
+}
+
+/// A counterclockwise rectangle whose corners are quarter circles of `radius`.
+fn rounded_rectangle(
+    x: f64,
+    y: f64,
+    w: f64,
+    h: f64,
+    radius: f64,
+    corner_points: usize,
+) -> Contour<Point> {
+    let corners = [
+        (x + w - radius, y + radius),
+        (x + w - radius, y + h - radius),
+        (x + radius, y + h - radius),
+        (x + radius, y + radius),
+    ];
+    let mut contour = Vec::with_capacity(4 * corner_points);
+    for (quarter, (cx, cy)) in corners.into_iter().enumerate() {
+        for i in 0..corner_points {
+            let angle = TAU / 4.0 * (quarter as f64 - 1.0 + i as f64 / (corner_points - 1) as f64);
+            contour.push(point(cx + radius * angle.cos(), cy + radius * angle.sin()));
+        }
+    }
+    contour
+}
+
+/// Flat `[x0, y0, x1, y1, ...]` coordinates of a contour, as a C caller holds them.
+pub fn coords(contour: &[Point]) -> Vec<f64> {
+    contour.iter().flat_map(|p| [p.x, p.y]).collect()
+}
+
+/// Copies `shapes` into a new flat buffer, which the caller releases.
+pub fn flat_shapes(shapes: &Shapes<Point>) -> *mut FlatF64ShapesBuffer {
+    let buffer = ishape_flat_f64_shapes_create();
+    for shape in shapes {
+        assert_eq!(ishape_flat_f64_shapes_begin_shape(buffer), IShapeStatus::Ok);
+        for contour in shape {
+            let coords = coords(contour);
+            let status = ishape_flat_f64_shapes_push_contour(buffer, coords.as_ptr(), coords.len());
+            assert_eq!(status, IShapeStatus::Ok);
+        }
+    }
+    buffer
+}
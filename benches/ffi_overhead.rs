@@ -0,0 +1,211 @@
+//! Compares each `ishape_*` entry point with the core call it wraps, on the same input.
+//!
+//! Every group has a `direct` case that calls i_overlay or i_triangle on prepared shapes and an
+//! `ffi` case that starts from flat coordinate arrays, as a C caller does, so the gap between
+//! them is the cost of the conversions done by this crate. Run with `cargo bench`.
+
+mod datasets;
+
+use std::hint::black_box;
+
+use criterion::{BenchmarkId, Criterion, Throughput, criterion_group, criterion_main};
+use datasets::{Dataset, coords, flat_shapes};
+use i_shape_ffi::*;
+use i_triangle::float::triangulator::Triangulator;
+use i_triangle::i_overlay::core::fill_rule::FillRule;
+use i_triangle::i_overlay::core::overlay_rule::OverlayRule;
+use i_triangle::i_overlay::core::solver::Solver;
+use i_triangle::i_overlay::float::overlay::FloatOverlay;
+use i_triangle::i_overlay::mesh::outline::offset::OutlineOffset;
+use i_triangle::i_overlay::mesh::style::OutlineStyle;
+
+/// The solver every FFI handle is created with.
+fn solver() -> Solver {
+    Solver {
+        multithreading: None,
+        ..Default::default()
+    }
+}
+
+fn overlay(c: &mut Criterion) {
+    let mut group = c.benchmark_group("overlay");
+    for dataset in Dataset::all() {
+        group.throughput(Throughput::Elements(dataset.points_count() as u64));
+        let options = Float64OverlayOptions::default();
+        let rule: OverlayRule = dataset.rule.into();
+        let subject: Vec<_> = dataset.subject.iter().flatten().cloned().collect();
+        let clip: Vec<_> = dataset.clip.iter().flatten().cloned().collect();
+
+        group.bench_function(BenchmarkId::new("direct", dataset.name), |b| {
+            b.iter(|| {
+                let mut overlay = FloatOverlay::with_subj_and_clip_custom(
+                    &subject,
+                    &clip,
+                    options.into(),
+                    solver(),
+                );
+                black_box(overlay.overlay(rule, FillRule::NonZero))
+            })
+        });
+
+        let contours: Vec<_> = subject
+            .iter()
+            .map(|contour| (coords(contour), IntShapeType::Subject))
+            .chain(
+                clip.iter()
+                    .map(|contour| (coords(contour), IntShapeType::Clip)),
+            )
+            .collect();
+        let output = ishape_flat_f64_shapes_create();
+
+        group.bench_function(BenchmarkId::new("ffi", dataset.name), |b| {
+            b.iter(|| {
+                let handle = ishape_overlay_f64_create(contours.len(), options);
+                for (coords, shape_type) in &contours {
+                    ishape_overlay_f64_add_contour(
+                        handle,
+                        coords.as_ptr(),
+                        coords.len(),
+                        *shape_type,
+                    );
+                }
+                let status = ishape_overlay_f64_overlay_into_flat(
+                    handle,
+                    dataset.rule,
+                    IntFillRule::NonZero,
+                    output,
+                );
+                ishape_overlay_f64_free(handle);
+                black_box(status)
+            })
+        });
+
+        // One handle cleared and refilled per operation, as a renderer clipping tiles does.
+        let handle = ishape_overlay_f64_create(contours.len(), options);
+        group.bench_function(BenchmarkId::new("ffi_reused", dataset.name), |b| {
+            b.iter(|| {
+                ishape_overlay_f64_clear(handle);
+                for (coords, shape_type) in &contours {
+                    ishape_overlay_f64_add_contour(
+                        handle,
+                        coords.as_ptr(),
+                        coords.len(),
+                        *shape_type,
+                    );
+                }
+                black_box(ishape_overlay_f64_overlay_into_flat(
+                    handle,
+                    dataset.rule,
+                    IntFillRule::NonZero,
+                    output,
+                ))
+            })
+        });
+
+        ishape_overlay_f64_free(handle);
+        ishape_flat_f64_shapes_free(output);
+    }
+    group.finish();
+}
+
+fn triangulate(c: &mut Criterion) {
+    let mut group = c.benchmark_group("triangulate");
+    for dataset in Dataset::all() {
+        group.throughput(Throughput::Elements(dataset.subject_points_count() as u64));
+        let validation = IntTriangulatorValidation::default();
+
+        let capacity = dataset.subject_points_count();
+        let mut triangulator = Triangulator::<u32>::new(capacity, validation.into(), solver());
+        group.bench_function(BenchmarkId::new("direct", dataset.name), |b| {
+            b.iter(|| black_box(triangulator.triangulate(&dataset.subject)))
+        });
+
+        let handle = ishape_triangle_f64_triangulator_create(capacity, validation);
+        let shapes = flat_shapes(&dataset.subject);
+        let output = ishape_triangle_flat_f64_triangulation_create();
+        group.bench_function(BenchmarkId::new("ffi", dataset.name), |b| {
+            b.iter(|| {
+                black_box(ishape_triangle_f64_triangulator_triangulate_flat(
+                    handle, shapes, output,
+                ))
+            })
+        });
+
+        ishape_triangle_flat_f64_triangulation_free(output);
+        ishape_flat_f64_shapes_free(shapes);
+        ishape_triangle_f64_triangulator_free(handle);
+    }
+    group.finish();
+}
+
+fn outline(c: &mut Criterion) {
+    const OFFSET: f64 = 1.5;
+
+    let mut group = c.benchmark_group("outline");
+    for dataset in Dataset::all() {
+        group.throughput(Throughput::Elements(dataset.subject_points_count() as u64));
+        let style = OutlineStyle::new(OFFSET);
+        group.bench_function(BenchmarkId::new("direct", dataset.name), |b| {
+            b.iter(|| black_box(dataset.subject.outline(&style)))
+        });
+
+        let shapes = flat_shapes(&dataset.subject);
+        let output = ishape_flat_f64_shapes_create();
+        group.bench_function(BenchmarkId::new("ffi", dataset.name), |b| {
+            b.iter(|| black_box(ishape_outline_f64_shapes_to_flat(shapes, -OFFSET, output)))
+        });
+
+        ishape_flat_f64_shapes_free(output);
+        ishape_flat_f64_shapes_free(shapes);
+    }
+    group.finish();
+}
+
+/// Moving shapes in and out of a flat buffer, which every shapes-based entry point pays for.
+fn shapes_buffer(c: &mut Criterion) {
+    let mut group = c.benchmark_group("shapes_buffer");
+    for dataset in Dataset::all() {
+        group.throughput(Throughput::Elements(dataset.subject_points_count() as u64));
+        let shapes: Vec<Vec<Vec<f64>>> = dataset
+            .subject
+            .iter()
+            .map(|shape| shape.iter().map(|contour| coords(contour)).collect())
+            .collect();
+        let copy = ishape_flat_f64_shapes_create();
+        group.bench_function(BenchmarkId::new("push", dataset.name), |b| {
+            b.iter(|| {
+                ishape_flat_f64_shapes_clear(copy);
+                for shape in &shapes {
+                    ishape_flat_f64_shapes_begin_shape(copy);
+                    for coords in shape {
+                        ishape_flat_f64_shapes_push_contour(copy, coords.as_ptr(), coords.len());
+                    }
+                }
+                black_box(copy)
+            })
+        });
+
+        let buffer = flat_shapes(&dataset.subject);
+        let view = ishape_flat_f64_shapes_view(buffer);
+        group.bench_function(BenchmarkId::new("set", dataset.name), |b| {
+            b.iter(|| {
+                black_box(ishape_flat_f64_shapes_set(
+                    copy,
+                    view.points,
+                    view.points_len,
+                    view.contours,
+                    view.contours_len,
+                    view.shapes,
+                    view.shapes_len,
+                ))
+            })
+        });
+
+        ishape_flat_f64_shapes_free(copy);
+        ishape_flat_f64_shapes_free(buffer);
+    }
+    group.finish();
+}
+
+criterion_group!(benches, overlay, triangulate, outline, shapes_buffer);
+criterion_main!(benches);
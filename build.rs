@@ -0,0 +1,69 @@
+use std::env;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+fn main() {
+    let crate_dir = PathBuf::from(env::var("CARGO_MANIFEST_DIR").unwrap());
+    let out_dir = PathBuf::from(env::var("OUT_DIR").unwrap());
+
+    println!("cargo:rerun-if-changed=src");
+    println!("cargo:rerun-if-changed=cbindgen.toml");
+    println!("cargo:rerun-if-env-changed=ISHAPE_UPDATE_HEADER");
+
+    export_dependency_versions(&crate_dir, &out_dir);
+
+    let config = cbindgen::Config::from_file(crate_dir.join("cbindgen.toml"))
+        .expect("cbindgen.toml is invalid");
+
+    let bindings = cbindgen::Builder::new()
+        .with_crate(&crate_dir)
+        .with_config(config)
+        .generate()
+        .expect("failed to generate the C header");
+
+    // `tests/header.rs` compares this copy with the committed `include/ishape.h`.
+    bindings.write_to_file(out_dir.join("ishape.h"));
+
+    if env::var_os("ISHAPE_UPDATE_HEADER").is_some() {
+        bindings.write_to_file(crate_dir.join("include").join("ishape.h"));
+    }
+}
+
+/// Exposes the resolved `i_triangle` and `i_overlay` versions to `ishape_version`.
+///
+/// The versions are read from the `Cargo.lock` of the workspace being built, found next to the
+/// target directory or the manifest; `unknown` is reported when no lock file is found.
+fn export_dependency_versions(crate_dir: &Path, out_dir: &Path) {
+    let lock = out_dir
+        .ancestors()
+        .chain(crate_dir.ancestors())
+        .map(|dir| dir.join("Cargo.lock"))
+        .find(|path| path.is_file());
+
+    let text = match &lock {
+        Some(path) => {
+            println!("cargo:rerun-if-changed={}", path.display());
+            fs::read_to_string(path).unwrap_or_default()
+        }
+        None => String::new(),
+    };
+
+    for (name, key) in [
+        ("i_triangle", "ISHAPE_I_TRIANGLE_VERSION"),
+        ("i_overlay", "ISHAPE_I_OVERLAY_VERSION"),
+    ] {
+        let version = locked_version(&text, name).unwrap_or("unknown");
+        println!("cargo:rustc-env={key}={version}");
+    }
+}
+
+fn locked_version<'a>(lock: &'a str, name: &str) -> Option<&'a str> {
+    let name_line = format!("name = \"{name}\"");
+    lock.split("[[package]]").find_map(|package| {
+        let mut lines = package.lines().map(str::trim);
+        lines.find(|line| *line == name_line)?;
+        lines
+            .find_map(|line| line.strip_prefix("version = \""))
+            .and_then(|rest| rest.strip_suffix('"'))
+    })
+}
@@ -0,0 +1,53 @@
+#![no_main]
+
+use i_shape_ffi::*;
+use i_shape_ffi_fuzz::*;
+use libfuzzer_sys::arbitrary::{self, Arbitrary};
+use libfuzzer_sys::fuzz_target;
+
+#[derive(Arbitrary, Debug)]
+enum Op {
+    Set(FlatShapesInput),
+    BeginShape,
+    PushContour(Contour),
+    Clear,
+}
+
+fuzz_target!(|ops: Vec<Op>| {
+    let buffer = ishape_flat_f64_shapes_create();
+
+    for op in &ops {
+        let before = snapshot(buffer);
+        let status = match op {
+            Op::Set(input) => input.layout().set_f64(buffer),
+            Op::BeginShape => ishape_flat_f64_shapes_begin_shape(buffer),
+            Op::PushContour(contour) => {
+                let coords = contour.coords();
+                ishape_flat_f64_shapes_push_contour(buffer, coords.as_ptr(), coords.len())
+            }
+            Op::Clear => {
+                ishape_flat_f64_shapes_clear(buffer);
+                IShapeStatus::Ok
+            }
+        };
+
+        if !check_status(status).is_ok() {
+            // Rejected input leaves the buffer untouched.
+            assert!(snapshot(buffer) == before, "buffer changed on {status:?}");
+        }
+        FlatShapes::from(ishape_flat_f64_shapes_view(buffer)).check_layout();
+    }
+
+    ishape_flat_f64_shapes_free(buffer);
+});
+
+type Snapshot = (Vec<u64>, Vec<RangeFFI>, Vec<RangeFFI>);
+
+fn snapshot(buffer: *const FlatF64ShapesBuffer) -> Snapshot {
+    let shapes = FlatShapes::from(ishape_flat_f64_shapes_view(buffer));
+    (
+        shapes.points.iter().map(|value| value.to_bits()).collect(),
+        shapes.contours.to_vec(),
+        shapes.shapes.to_vec(),
+    )
+}
@@ -0,0 +1,91 @@
+#![no_main]
+
+use i_shape_ffi::*;
+use i_shape_ffi_fuzz::*;
+use libfuzzer_sys::arbitrary::{self, Arbitrary};
+use libfuzzer_sys::fuzz_target;
+
+#[derive(Arbitrary, Debug)]
+struct Input {
+    contour: Contour,
+    shapes: FlatShapesInput,
+    outer_offset: Coord,
+    inner_offset: Coord,
+    join_kind: u8,
+    join_value: Coord,
+    non_finite: u8,
+}
+
+fuzz_target!(|input: Input| {
+    let coords = input.contour.coords();
+    let outer = input.outer_offset.value();
+    let inner = input.inner_offset.value();
+    let join_kind = input.join_kind as u32 % 4;
+    let join_value = input.join_value.value();
+
+    let output = ishape_flat_f64_shapes_create();
+
+    if check_status(ishape_outline_f64_contour_to_flat(
+        coords.as_ptr(),
+        coords.len(),
+        outer,
+        output,
+    ))
+    .is_ok()
+    {
+        // Non-finite points and offsets are rejected, so accepted output is finite.
+        FlatShapes::from(ishape_flat_f64_shapes_view(output)).check_output(true);
+    }
+
+    let status = ishape_outline_f64_contour_to_flat_styled(
+        coords.as_ptr(),
+        coords.len(),
+        outer,
+        inner,
+        join_kind,
+        join_value,
+        output,
+    );
+    if check_status(status).is_ok() {
+        let finite = join_value.is_finite();
+        FlatShapes::from(ishape_flat_f64_shapes_view(output)).check_output(finite);
+    }
+
+    let style = FfiOutlineStyle {
+        outer_offset: outer,
+        inner_offset: inner,
+        join: FfiLineJoin {
+            kind: [
+                FfiLineJoinKind::Bevel,
+                FfiLineJoinKind::Miter,
+                FfiLineJoinKind::Round,
+            ][input.join_kind as usize % 3],
+            value: join_value,
+        },
+        non_finite: non_finite_policy(input.non_finite),
+    };
+    let status =
+        ishape_outline_f64_contour_to_flat_with_style(coords.as_ptr(), coords.len(), style, output);
+    if check_status(status).is_ok() {
+        FlatShapes::from(ishape_flat_f64_shapes_view(output)).check_output(join_value.is_finite());
+    }
+
+    let layout = input.shapes.layout();
+    let shapes = ishape_flat_f64_shapes_create();
+    if check_status(layout.set_f64(shapes)).is_ok() {
+        if check_status(ishape_outline_f64_shapes_to_flat(shapes, outer, output)).is_ok() {
+            FlatShapes::from(ishape_flat_f64_shapes_view(output)).check_output(true);
+        }
+        if check_status(ishape_outline_f64_shapes_to_flat_with_style(
+            shapes, style, output,
+        ))
+        .is_ok()
+        {
+            FlatShapes::from(ishape_flat_f64_shapes_view(output))
+                .check_output(join_value.is_finite());
+        }
+    }
+
+    ishape_flat_f64_shapes_free(shapes);
+    ishape_flat_f64_shapes_free(output);
+});
@@ -0,0 +1,99 @@
+#![no_main]
+
+use i_shape_ffi::*;
+use i_shape_ffi_fuzz::*;
+use libfuzzer_sys::arbitrary::{self, Arbitrary};
+use libfuzzer_sys::fuzz_target;
+
+#[derive(Arbitrary, Debug)]
+struct Input {
+    subject: Vec<Contour>,
+    clip: Vec<Contour>,
+    overlay_rule: u8,
+    /// A second operation run on the same handle, which must stay usable after the first.
+    next_overlay_rule: u8,
+    fill_rule: u8,
+    options: OverlayOptions,
+}
+
+fuzz_target!(|input: Input| {
+    let rules = [
+        overlay_rule(input.overlay_rule),
+        overlay_rule(input.next_overlay_rule),
+    ];
+    let fill_rule = fill_rule(input.fill_rule);
+    let contours = input
+        .subject
+        .iter()
+        .map(|contour| (contour.coords(), IntShapeType::Subject))
+        .chain(
+            input
+                .clip
+                .iter()
+                .map(|contour| (contour.coords(), IntShapeType::Clip)),
+        )
+        .collect::<Vec<_>>();
+    let overlay = ishape_overlay_f64_create(0, input.options.f64());
+    assert!(!overlay.is_null());
+    for (coords, shape_type) in &contours {
+        let status =
+            ishape_overlay_f64_add_contour(overlay, coords.as_ptr(), coords.len(), *shape_type);
+        if check_status(status) != IShapeStatus::Ok {
+            assert!(matches!(
+                status,
+                IShapeStatus::OddCoordinateCount | IShapeStatus::NonFiniteValue
+            ));
+        }
+    }
+    let output = ishape_flat_f64_shapes_create();
+    for overlay_rule in rules {
+        if check_status(ishape_overlay_f64_overlay_into_flat(
+            overlay,
+            overlay_rule,
+            fill_rule,
+            output,
+        ))
+        .is_ok()
+        {
+            // The non-finite policy keeps every accepted point finite.
+            FlatShapes::from(ishape_flat_f64_shapes_view(output)).check_output(true);
+        }
+    }
+    ishape_overlay_f64_clear(overlay);
+    let status = ishape_overlay_f64_overlay_into_flat(overlay, rules[0], fill_rule, output);
+    assert_eq!(status, IShapeStatus::Ok);
+    assert_eq!(ishape_flat_f64_shapes_view(output).points_len, 0);
+    ishape_flat_f64_shapes_free(output);
+    ishape_overlay_f64_free(overlay);
+
+    let overlay = ishape_overlay_int_create(0, input.options.int());
+    assert!(!overlay.is_null());
+    for (coords, shape_type) in &contours {
+        let coords = to_int_coords(coords);
+        check_status(ishape_overlay_int_add_contour(
+            overlay,
+            coords.as_ptr(),
+            coords.len(),
+            *shape_type,
+        ));
+    }
+    let output = ishape_flat_shapes_create();
+    for overlay_rule in rules {
+        if check_status(ishape_overlay_int_overlay_into_flat(
+            overlay,
+            overlay_rule,
+            fill_rule,
+            output,
+        ))
+        .is_ok()
+        {
+            FlatShapes::from(ishape_flat_shapes_view(output)).check_output(true);
+        }
+    }
+    ishape_overlay_int_clear(overlay);
+    let status = ishape_overlay_int_overlay_into_flat(overlay, rules[0], fill_rule, output);
+    assert_eq!(status, IShapeStatus::Ok);
+    assert_eq!(ishape_flat_shapes_view(output).points_len, 0);
+    ishape_flat_shapes_free(output);
+    ishape_overlay_int_free(overlay);
+});
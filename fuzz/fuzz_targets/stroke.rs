@@ -0,0 +1,122 @@
+#![no_main]
+
+use i_shape_ffi::*;
+use i_shape_ffi_fuzz::*;
+use libfuzzer_sys::arbitrary::{self, Arbitrary};
+use libfuzzer_sys::fuzz_target;
+
+#[derive(Arbitrary, Debug)]
+struct Cap {
+    kind: u8,
+    value: Coord,
+}
+
+#[derive(Arbitrary, Debug)]
+struct Input {
+    paths: Vec<(Contour, bool)>,
+    edits: Vec<RangeEdit>,
+    width: Coord,
+    join_kind: u8,
+    join_value: Coord,
+    start_cap: Cap,
+    end_cap: Cap,
+    start_template: Option<Contour>,
+    end_template: Option<Contour>,
+    non_finite: u8,
+}
+
+fuzz_target!(|input: Input| {
+    let width = input.width.value();
+    let join_kind = input.join_kind as u32 % 4;
+    let join_value = input.join_value.value();
+    let start_cap_kind = input.start_cap.kind as u32 % 4;
+    let end_cap_kind = input.end_cap.kind as u32 % 4;
+
+    // Paths share the flat layout of a single shape; its range edits damage the path ranges.
+    let layout = FlatShapesInput {
+        shapes: vec![input.paths.iter().map(|(path, _)| path.clone()).collect()],
+        edits: input
+            .edits
+            .iter()
+            .map(|edit| RangeEdit {
+                shape: false,
+                ..*edit
+            })
+            .collect(),
+    }
+    .layout();
+    let closed_flags: Vec<bool> = input.paths.iter().map(|&(_, closed)| closed).collect();
+    let non_finite = non_finite_policy(input.non_finite);
+
+    let output = ishape_flat_f64_shapes_create();
+
+    let status = ishape_stroke_f64_paths_to_flat_styled(
+        layout.points.as_ptr(),
+        layout.points.len(),
+        layout.contours.as_ptr(),
+        closed_flags.as_ptr(),
+        closed_flags.len(),
+        width,
+        join_kind,
+        join_value,
+        start_cap_kind,
+        input.start_cap.value.value(),
+        end_cap_kind,
+        input.end_cap.value.value(),
+        output,
+    );
+    if check_status(status).is_ok() {
+        FlatShapes::from(ishape_flat_f64_shapes_view(output)).check_output(true);
+    }
+
+    let Some((path, is_closed)) = input.paths.first() else {
+        ishape_flat_f64_shapes_free(output);
+        return;
+    };
+    let coords = path.coords();
+    let style = FfiStrokeStyle {
+        width,
+        join: FfiLineJoin {
+            kind: [
+                FfiLineJoinKind::Bevel,
+                FfiLineJoinKind::Miter,
+                FfiLineJoinKind::Round,
+            ][input.join_kind as usize % 3],
+            value: join_value,
+        },
+        start_cap: FfiLineCap::default(),
+        end_cap: FfiLineCap::default(),
+        non_finite,
+    };
+    let start_template = input
+        .start_template
+        .as_ref()
+        .map(Contour::coords)
+        .unwrap_or_default();
+    let end_template = input
+        .end_template
+        .as_ref()
+        .map(Contour::coords)
+        .unwrap_or_default();
+
+    let status = ishape_stroke_f64_contour_to_flat_custom_caps(
+        coords.as_ptr(),
+        coords.len(),
+        *is_closed,
+        style,
+        start_template.as_ptr(),
+        start_template.len(),
+        end_template.as_ptr(),
+        end_template.len(),
+        output,
+    );
+    if check_status(status).is_ok() {
+        let finite = start_template
+            .iter()
+            .chain(&end_template)
+            .all(|value| value.is_finite());
+        FlatShapes::from(ishape_flat_f64_shapes_view(output)).check_output(finite);
+    }
+
+    ishape_flat_f64_shapes_free(output);
+});
@@ -0,0 +1,75 @@
+#![no_main]
+
+use i_shape_ffi::*;
+use i_shape_ffi_fuzz::*;
+use libfuzzer_sys::arbitrary::{self, Arbitrary};
+use libfuzzer_sys::fuzz_target;
+
+#[derive(Arbitrary, Debug)]
+struct Input {
+    shapes: FlatShapesInput,
+    max_points_count: u16,
+    fill_rule: u8,
+    options: OverlayOptions,
+}
+
+fuzz_target!(|input: Input| {
+    let layout = input.shapes.layout();
+    let validation = IntTriangulatorValidation {
+        fill_rule: fill_rule(input.fill_rule),
+        options: input.options.int(),
+    };
+    let options = FloatTriangulatorOptions {
+        validation,
+        non_finite: non_finite_policy(input.options.non_finite),
+    };
+    let max_points_count = input.max_points_count as usize;
+
+    let shapes = ishape_flat_f64_shapes_create();
+    let triangulator =
+        ishape_triangle_f64_triangulator_create_with_options(max_points_count, options);
+    let output = ishape_triangle_flat_f64_triangulation_create();
+    if check_status(layout.set_f64(shapes)).is_ok() {
+        let status =
+            ishape_triangle_f64_triangulator_triangulate_flat(triangulator, shapes, output);
+        if check_status(status).is_ok() {
+            FlatTriangulation::from(ishape_triangle_flat_f64_triangulation_view(output))
+                .check_output(true);
+        }
+    }
+    ishape_triangle_flat_f64_triangulation_free(output);
+    ishape_triangle_f64_triangulator_free(triangulator);
+    ishape_flat_f64_shapes_free(shapes);
+
+    let shapes = ishape_flat_f32_shapes_create();
+    let triangulator =
+        ishape_triangle_f32_triangulator_create_with_options(max_points_count, options);
+    let output = ishape_triangle_flat_f32_triangulation_create();
+    if check_status(layout.set_f32(shapes)).is_ok() {
+        let status =
+            ishape_triangle_f32_triangulator_triangulate_flat(triangulator, shapes, output);
+        if check_status(status).is_ok() {
+            // Coordinates that overflow when narrowed fall under the non-finite policy too.
+            FlatTriangulation::from(ishape_triangle_flat_f32_triangulation_view(output))
+                .check_output(true);
+        }
+    }
+    ishape_triangle_flat_f32_triangulation_free(output);
+    ishape_triangle_f32_triangulator_free(triangulator);
+    ishape_flat_f32_shapes_free(shapes);
+
+    let shapes = ishape_flat_shapes_create();
+    let triangulator = ishape_triangle_int_triangulator_create(max_points_count, validation);
+    let output = ishape_triangle_flat_triangulation_create();
+    if check_status(layout.set_int(shapes)).is_ok() {
+        let status =
+            ishape_triangle_int_triangulator_triangulate_flat(triangulator, shapes, output);
+        if check_status(status).is_ok() {
+            FlatTriangulation::from(ishape_triangle_flat_triangulation_view(output))
+                .check_output(true);
+        }
+    }
+    ishape_triangle_flat_triangulation_free(output);
+    ishape_triangle_int_triangulator_free(triangulator);
+    ishape_flat_shapes_free(shapes);
+});
@@ -0,0 +1,457 @@
+//! Input generators and output invariants shared by the fuzz targets.
+//!
+//! Every target drives the library through its `extern "C"` entry points only. Panics inside the
+//! library are caught at the FFI boundary, but the libFuzzer panic hook aborts on them first, so
+//! any panic is reported as a crash.
+//!
+//! Run a target from this directory with `cargo +nightly fuzz run <target>`.
+
+use i_shape_ffi::*;
+use libfuzzer_sys::arbitrary::{self, Arbitrary};
+
+/// A single coordinate, biased towards a small grid so that contours touch and overlap.
+#[derive(Arbitrary, Debug, Clone, Copy)]
+pub enum Coord {
+    Grid(i8),
+    Exact(f64),
+    NaN,
+    Infinity,
+    NegInfinity,
+}
+
+impl Coord {
+    pub fn value(self) -> f64 {
+        match self {
+            Coord::Grid(value) => value as f64,
+            Coord::Exact(value) => value,
+            Coord::NaN => f64::NAN,
+            Coord::Infinity => f64::INFINITY,
+            Coord::NegInfinity => f64::NEG_INFINITY,
+        }
+    }
+}
+
+#[derive(Arbitrary, Debug, Clone, Copy)]
+pub enum Vertex {
+    Point(Coord, Coord),
+    /// Repeats the previous point.
+    Duplicate,
+}
+
+/// A contour given as a list of vertices, optionally followed by a dangling coordinate.
+#[derive(Arbitrary, Debug, Clone)]
+pub struct Contour {
+    pub vertices: Vec<Vertex>,
+    pub dangling: Option<Coord>,
+}
+
+impl Contour {
+    /// Returns the flat `[x0, y0, x1, y1, ...]` coordinates, odd in length when `dangling` is set.
+    pub fn coords(&self) -> Vec<f64> {
+        let mut coords = Vec::with_capacity(2 * self.vertices.len() + 1);
+        for vertex in &self.vertices {
+            match *vertex {
+                Vertex::Point(x, y) => coords.extend([x.value(), y.value()]),
+                Vertex::Duplicate => {
+                    let last = match coords.as_slice() {
+                        [.., x, y] => [*x, *y],
+                        _ => [0.0, 0.0],
+                    };
+                    coords.extend(last);
+                }
+            }
+        }
+        if let Some(coord) = self.dangling {
+            coords.push(coord.value());
+        }
+        coords
+    }
+}
+
+/// Shifts one contour or shape range of an otherwise well-formed layout, producing reversed,
+/// misaligned and out-of-bounds ranges.
+#[derive(Arbitrary, Debug, Clone, Copy)]
+pub struct RangeEdit {
+    pub shape: bool,
+    pub index: u8,
+    pub start: i8,
+    pub end: i8,
+}
+
+/// Shapes laid out as in `FlatF64ShapesBuffer`, with optional damage to the ranges.
+#[derive(Arbitrary, Debug, Clone)]
+pub struct FlatShapesInput {
+    pub shapes: Vec<Vec<Contour>>,
+    pub edits: Vec<RangeEdit>,
+}
+
+/// Flat arrays ready to be passed to `ishape_flat_*_shapes_set`.
+pub struct FlatLayout {
+    pub points: Vec<f64>,
+    pub contours: Vec<RangeFFI>,
+    pub shapes: Vec<RangeFFI>,
+}
+
+impl FlatShapesInput {
+    pub fn layout(&self) -> FlatLayout {
+        let mut layout = FlatLayout {
+            points: Vec::new(),
+            contours: Vec::new(),
+            shapes: Vec::new(),
+        };
+
+        for shape in &self.shapes {
+            let start = layout.contours.len() as u64;
+            for contour in shape {
+                let start = layout.points.len() as u64;
+                layout.points.extend(contour.coords());
+                let end = layout.points.len() as u64;
+                layout.contours.push(RangeFFI { start, end });
+            }
+            let end = layout.contours.len() as u64;
+            layout.shapes.push(RangeFFI { start, end });
+        }
+
+        for edit in &self.edits {
+            let ranges = if edit.shape {
+                &mut layout.shapes
+            } else {
+                &mut layout.contours
+            };
+            if ranges.is_empty() {
+                continue;
+            }
+            let index = edit.index as usize % ranges.len();
+            let range = &mut ranges[index];
+            range.start = range.start.saturating_add_signed(edit.start as i64);
+            range.end = range.end.saturating_add_signed(edit.end as i64);
+        }
+
+        layout
+    }
+}
+
+impl FlatLayout {
+    pub fn is_finite(&self) -> bool {
+        self.points.iter().all(|value| value.is_finite())
+    }
+
+    pub fn set_f64(&self, buffer: *mut FlatF64ShapesBuffer) -> IShapeStatus {
+        ishape_flat_f64_shapes_set(
+            buffer,
+            self.points.as_ptr(),
+            self.points.len(),
+            self.contours.as_ptr(),
+            self.contours.len(),
+            self.shapes.as_ptr(),
+            self.shapes.len(),
+        )
+    }
+
+    pub fn set_f32(&self, buffer: *mut FlatF32ShapesBuffer) -> IShapeStatus {
+        let points: Vec<f32> = self.points.iter().map(|&value| value as f32).collect();
+        ishape_flat_f32_shapes_set(
+            buffer,
+            points.as_ptr(),
+            points.len(),
+            self.contours.as_ptr(),
+            self.contours.len(),
+            self.shapes.as_ptr(),
+            self.shapes.len(),
+        )
+    }
+
+    pub fn set_int(&self, buffer: *mut FlatShapesBuffer) -> IShapeStatus {
+        let points = to_int_coords(&self.points);
+        ishape_flat_shapes_set(
+            buffer,
+            points.as_ptr(),
+            points.len(),
+            self.contours.as_ptr(),
+            self.contours.len(),
+            self.shapes.as_ptr(),
+            self.shapes.len(),
+        )
+    }
+}
+
+/// Converts float coordinates for the integer API, which only accepts coordinates within
+/// `±2^30` so that intermediate products fit.
+pub fn to_int_coords(coords: &[f64]) -> Vec<i32> {
+    const LIMIT: f64 = (1 << 30) as f64;
+    coords
+        .iter()
+        .map(|&value| value.clamp(-LIMIT, LIMIT) as i32)
+        .collect()
+}
+
+/// A borrowed flat shapes buffer or output arrays.
+pub struct FlatShapes<'a, T> {
+    pub points: &'a [T],
+    pub contours: &'a [RangeFFI],
+    pub shapes: &'a [RangeFFI],
+}
+
+impl<'a> From<FlatShapesView> for FlatShapes<'a, i32> {
+    fn from(view: FlatShapesView) -> Self {
+        FlatShapes {
+            points: raw_slice(view.points, view.points_len),
+            contours: raw_slice(view.contours, view.contours_len),
+            shapes: raw_slice(view.shapes, view.shapes_len),
+        }
+    }
+}
+
+impl<'a> From<FlatF32ShapesView> for FlatShapes<'a, f32> {
+    fn from(view: FlatF32ShapesView) -> Self {
+        FlatShapes {
+            points: raw_slice(view.points, view.points_len),
+            contours: raw_slice(view.contours, view.contours_len),
+            shapes: raw_slice(view.shapes, view.shapes_len),
+        }
+    }
+}
+
+impl<'a> From<FlatF64ShapesView> for FlatShapes<'a, f64> {
+    fn from(view: FlatF64ShapesView) -> Self {
+        FlatShapes {
+            points: raw_slice(view.points, view.points_len),
+            contours: raw_slice(view.contours, view.contours_len),
+            shapes: raw_slice(view.shapes, view.shapes_len),
+        }
+    }
+}
+
+impl<T: Copy + Into<f64>> FlatShapes<'_, T> {
+    /// Checks that every range is ordered, in bounds, aligned to a point and after the previous one.
+    pub fn check_layout(&self) {
+        assert!(self.points.len().is_multiple_of(2), "odd coordinate count");
+
+        let mut previous_end = 0;
+        for range in self.contours {
+            let (start, end) = (range.start as usize, range.end as usize);
+            assert!(
+                start <= end && end <= self.points.len(),
+                "contour range {range:?}"
+            );
+            assert!(
+                start.is_multiple_of(2) && end.is_multiple_of(2),
+                "misaligned {range:?}"
+            );
+            assert!(start >= previous_end, "overlapping contour range {range:?}");
+            previous_end = end;
+        }
+
+        let mut previous_end = 0;
+        for range in self.shapes {
+            let (start, end) = (range.start as usize, range.end as usize);
+            assert!(
+                start <= end && end <= self.contours.len(),
+                "shape range {range:?}"
+            );
+            assert!(start >= previous_end, "overlapping shape range {range:?}");
+            previous_end = end;
+        }
+    }
+
+    /// Checks a result produced by the library: ranges tile the arrays in order and every contour
+    /// is a closed ring of at least 3 points without a repeated closing point. Coordinates must
+    /// be finite when the input was.
+    pub fn check_output(&self, finite_input: bool) {
+        self.check_layout();
+
+        let mut next_contour = 0;
+        for shape in self.shapes {
+            assert_eq!(shape.start, next_contour, "shape ranges are not contiguous");
+            assert!(shape.end > shape.start, "empty shape");
+            next_contour = shape.end;
+        }
+        assert_eq!(
+            next_contour as usize,
+            self.contours.len(),
+            "unused contours"
+        );
+
+        let mut next_point = 0;
+        for contour in self.contours {
+            assert_eq!(
+                contour.start, next_point,
+                "contour ranges are not contiguous"
+            );
+            next_point = contour.end;
+
+            let coords = &self.points[contour.start as usize..contour.end as usize];
+            assert!(
+                coords.len() >= 6,
+                "contour {contour:?} has fewer than 3 points"
+            );
+            let (first, last) = (&coords[..2], &coords[coords.len() - 2..]);
+            let same = |a: T, b: T| a.into() == b.into();
+            assert!(
+                !(same(first[0], last[0]) && same(first[1], last[1])),
+                "contour {contour:?} repeats its first point"
+            );
+        }
+        assert_eq!(next_point as usize, self.points.len(), "unused points");
+
+        if finite_input {
+            assert!(
+                self.points.iter().all(|&value| value.into().is_finite()),
+                "non-finite output for finite input"
+            );
+        }
+    }
+}
+
+/// A borrowed triangulation buffer.
+pub struct FlatTriangulation<'a, T> {
+    pub points: &'a [T],
+    pub indices: &'a [IntTriangulationIndex],
+}
+
+impl<'a> From<FlatIntTriangulationView> for FlatTriangulation<'a, i32> {
+    fn from(view: FlatIntTriangulationView) -> Self {
+        FlatTriangulation {
+            points: raw_slice(view.points, view.points_len),
+            indices: raw_slice(view.indices, view.indices_len),
+        }
+    }
+}
+
+impl<'a> From<FlatF32TriangulationView> for FlatTriangulation<'a, f32> {
+    fn from(view: FlatF32TriangulationView) -> Self {
+        FlatTriangulation {
+            points: raw_slice(view.points, view.points_len),
+            indices: raw_slice(view.indices, view.indices_len),
+        }
+    }
+}
+
+impl<'a> From<FlatF64TriangulationView> for FlatTriangulation<'a, f64> {
+    fn from(view: FlatF64TriangulationView) -> Self {
+        FlatTriangulation {
+            points: raw_slice(view.points, view.points_len),
+            indices: raw_slice(view.indices, view.indices_len),
+        }
+    }
+}
+
+impl<T: Copy + Into<f64>> FlatTriangulation<'_, T> {
+    /// Checks that indices come in triples and every one refers to an existing point.
+    pub fn check_output(&self, finite_input: bool) {
+        assert!(self.points.len().is_multiple_of(2), "odd coordinate count");
+        assert!(
+            self.indices.len().is_multiple_of(3),
+            "index count is not a multiple of three"
+        );
+
+        let point_count = self.points.len() / 2;
+        for &index in self.indices {
+            assert!(
+                (index as usize) < point_count,
+                "index {index} out of {point_count}"
+            );
+        }
+
+        if finite_input {
+            assert!(
+                self.points.iter().all(|&value| value.into().is_finite()),
+                "non-finite output for finite input"
+            );
+        }
+    }
+}
+
+/// Fails on [`IShapeStatus::Panic`]; every other status is an accepted outcome for fuzzed input.
+pub fn check_status(status: IShapeStatus) -> IShapeStatus {
+    assert_ne!(
+        status,
+        IShapeStatus::Panic,
+        "panic caught at the FFI boundary"
+    );
+    status
+}
+
+/// Overlay options with every field under fuzzer control.
+#[derive(Arbitrary, Debug, Clone, Copy)]
+pub struct OverlayOptions {
+    pub preserve_input_collinear: bool,
+    pub clockwise: bool,
+    pub preserve_output_collinear: bool,
+    pub min_output_area: Coord,
+    pub clean_result: bool,
+    pub non_finite: u8,
+}
+
+impl OverlayOptions {
+    fn direction(&self) -> IntContourDirection {
+        if self.clockwise {
+            IntContourDirection::Clockwise
+        } else {
+            IntContourDirection::CounterClockwise
+        }
+    }
+
+    pub fn int(&self) -> IntOverlayOptions {
+        IntOverlayOptions {
+            preserve_input_collinear: self.preserve_input_collinear,
+            output_direction: self.direction(),
+            preserve_output_collinear: self.preserve_output_collinear,
+            min_output_area: self.min_output_area.value() as u64,
+        }
+    }
+
+    pub fn f64(&self) -> Float64OverlayOptions {
+        Float64OverlayOptions {
+            preserve_input_collinear: self.preserve_input_collinear,
+            output_direction: self.direction(),
+            preserve_output_collinear: self.preserve_output_collinear,
+            min_output_area: self.min_output_area.value(),
+            clean_result: self.clean_result,
+            non_finite: non_finite_policy(self.non_finite),
+        }
+    }
+}
+
+/// Maps a fuzzed byte onto one of the overlay rules.
+pub fn overlay_rule(value: u8) -> IntOverlayRule {
+    const RULES: [IntOverlayRule; 7] = [
+        IntOverlayRule::Subject,
+        IntOverlayRule::Clip,
+        IntOverlayRule::Intersect,
+        IntOverlayRule::Union,
+        IntOverlayRule::Difference,
+        IntOverlayRule::InverseDifference,
+        IntOverlayRule::Xor,
+    ];
+    RULES[value as usize % RULES.len()]
+}
+
+/// Maps a fuzzed byte onto one of the fill rules.
+pub fn fill_rule(value: u8) -> IntFillRule {
+    const RULES: [IntFillRule; 4] = [
+        IntFillRule::EvenOdd,
+        IntFillRule::NonZero,
+        IntFillRule::Positive,
+        IntFillRule::Negative,
+    ];
+    RULES[value as usize % RULES.len()]
+}
+
+/// Maps a fuzzed byte onto one of the non-finite input policies.
+pub fn non_finite_policy(value: u8) -> IShapeNonFinitePolicy {
+    const POLICIES: [IShapeNonFinitePolicy; 3] = [
+        IShapeNonFinitePolicy::Reject,
+        IShapeNonFinitePolicy::DropPoint,
+        IShapeNonFinitePolicy::DropContour,
+    ];
+    POLICIES[value as usize % POLICIES.len()]
+}
+
+fn raw_slice<'a, T>(ptr: *const T, len: usize) -> &'a [T] {
+    if len == 0 {
+        return &[];
+    }
+    assert!(!ptr.is_null(), "null pointer with length {len}");
+    unsafe { std::slice::from_raw_parts(ptr, len) }
+}
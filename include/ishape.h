@@ -0,0 +1,2384 @@
+#ifndef ISHAPE_H
+#define ISHAPE_H
+
+/* Generated by cbindgen. Do not edit; run `ISHAPE_UPDATE_HEADER=1 cargo build` instead. */
+
+#include <stdarg.h>
+#include <stdbool.h>
+#include <stddef.h>
+#include <stdint.h>
+#include <stdlib.h>
+
+/**
+ * Version of the C ABI exposed by this library, emitted into `ishape.h` for compile-time checks.
+ *
+ * Bumped whenever an existing entry point, struct layout or status code changes incompatibly;
+ * additions keep the current value.
+ */
+#define ISHAPE_ABI_VERSION 2
+
+/**
+ * Result code returned by every fallible `extern "C"` entry point.
+ */
+typedef enum IShapeStatus {
+  /**
+   * The operation completed successfully.
+   */
+  I_SHAPE_STATUS_OK = 0,
+  /**
+   * A required handle or buffer pointer was null.
+   */
+  I_SHAPE_STATUS_NULL_POINTER = 1,
+  /**
+   * The coordinate buffer length is not a multiple of two.
+   */
+  I_SHAPE_STATUS_ODD_COORDINATE_COUNT = 2,
+  /**
+   * The input does not contain enough points for the requested operation.
+   */
+  I_SHAPE_STATUS_TOO_FEW_POINTS = 3,
+  /**
+   * The line join kind is unknown or its value is not finite.
+   */
+  I_SHAPE_STATUS_INVALID_LINE_JOIN = 4,
+  /**
+   * The line cap kind is unknown or its value is not finite.
+   */
+  I_SHAPE_STATUS_INVALID_LINE_CAP = 5,
+  /**
+   * The stroke width is not a positive, finite number.
+   */
+  I_SHAPE_STATUS_INVALID_WIDTH = 6,
+  /**
+   * A range is reversed, out of bounds or not aligned to a point.
+   */
+  I_SHAPE_STATUS_INVALID_RANGE = 7,
+  /**
+   * An internal panic was caught at the FFI boundary; outputs of the call are cleared.
+   */
+  I_SHAPE_STATUS_PANIC = 8,
+  /**
+   * A caller-provided output array is too small; the required lengths have been reported.
+   */
+  I_SHAPE_STATUS_INSUFFICIENT_CAPACITY = 9,
+  /**
+   * An input coordinate or offset is NaN or infinite.
+   */
+  I_SHAPE_STATUS_NON_FINITE_VALUE = 10,
+} IShapeStatus;
+
+/**
+ * FFI-safe representation of `ContourDirection`.
+ */
+typedef enum IntContourDirection {
+  INT_CONTOUR_DIRECTION_COUNTER_CLOCKWISE = 0,
+  INT_CONTOUR_DIRECTION_CLOCKWISE = 1,
+} IntContourDirection;
+
+/**
+ * Wrapper enum mirroring `i_overlay::core::overlay::ShapeType` for FFI consumers.
+ */
+typedef enum IntShapeType {
+  INT_SHAPE_TYPE_SUBJECT = 0,
+  INT_SHAPE_TYPE_CLIP = 1,
+} IntShapeType;
+
+/**
+ * FFI-safe enum mirroring `OverlayRule`.
+ */
+typedef enum IntOverlayRule {
+  INT_OVERLAY_RULE_SUBJECT = 0,
+  INT_OVERLAY_RULE_CLIP = 1,
+  INT_OVERLAY_RULE_INTERSECT = 2,
+  INT_OVERLAY_RULE_UNION = 3,
+  INT_OVERLAY_RULE_DIFFERENCE = 4,
+  INT_OVERLAY_RULE_INVERSE_DIFFERENCE = 5,
+  INT_OVERLAY_RULE_XOR = 6,
+} IntOverlayRule;
+
+/**
+ * FFI-safe enum mirroring `FillRule`.
+ */
+typedef enum IntFillRule {
+  INT_FILL_RULE_EVEN_ODD = 0,
+  INT_FILL_RULE_NON_ZERO = 1,
+  INT_FILL_RULE_POSITIVE = 2,
+  INT_FILL_RULE_NEGATIVE = 3,
+} IntFillRule;
+
+/**
+ * How float entry points treat input points with a NaN or infinite coordinate.
+ *
+ * The core float-to-int conversion derives its bounds from the input, so a single non-finite
+ * point would corrupt the whole result.
+ */
+typedef enum IShapeNonFinitePolicy {
+  /**
+   * Fail with `IShapeStatus::NonFiniteValue`.
+   */
+  I_SHAPE_NON_FINITE_POLICY_REJECT = 0,
+  /**
+   * Remove the offending points and keep the rest of their contour.
+   */
+  I_SHAPE_NON_FINITE_POLICY_DROP_POINT = 1,
+  /**
+   * Remove every contour holding an offending point.
+   */
+  I_SHAPE_NON_FINITE_POLICY_DROP_CONTOUR = 2,
+} IShapeNonFinitePolicy;
+
+/**
+ * FFI-safe enum mirroring the variants of `i_overlay::mesh::style::LineJoin`.
+ */
+typedef enum FfiLineJoinKind {
+  FFI_LINE_JOIN_KIND_BEVEL = 0,
+  FFI_LINE_JOIN_KIND_MITER = 1,
+  FFI_LINE_JOIN_KIND_ROUND = 2,
+} FfiLineJoinKind;
+
+/**
+ * FFI-safe enum mirroring the variants of `i_overlay::mesh::style::LineCap`.
+ */
+typedef enum FfiLineCapKind {
+  FFI_LINE_CAP_KIND_BUTT = 0,
+  FFI_LINE_CAP_KIND_ROUND = 1,
+  FFI_LINE_CAP_KIND_SQUARE = 2,
+} FfiLineCapKind;
+
+/**
+ * Flattened container for `Float32Shapes` data that is easy to consume from Swift.
+ */
+typedef struct FlatF32ShapesBuffer FlatF32ShapesBuffer;
+
+/**
+ * Flattened representation of a single-precision triangulation for FFI usage.
+ */
+typedef struct FlatF32Triangulation FlatF32Triangulation;
+
+/**
+ * Flattened container for `Float64Shapes` data that is easy to consume from Swift.
+ */
+typedef struct FlatF64ShapesBuffer FlatF64ShapesBuffer;
+
+/**
+ * Flattened representation of a double-precision triangulation for FFI usage.
+ */
+typedef struct FlatF64Triangulation FlatF64Triangulation;
+
+/**
+ * Flattened representation of an integer triangulation for FFI usage.
+ */
+typedef struct FlatIntTriangulation FlatIntTriangulation;
+
+/**
+ * Flattened container for `IntShapes` data that is easy to consume from Swift.
+ *
+ * The buffer uses three parallel arrays:
+ * - `flat_points` stores the coordinates as `[x0, y0, x1, y1, ...]`.
+ * - `contour_ranges` points into `flat_points`, describing the span of every contour.
+ * - `shape_ranges` points into `contour_ranges`, describing which contours belong to each shape.
+ */
+typedef struct FlatShapesBuffer FlatShapesBuffer;
+
+/**
+ * Wrapper around floating overlay that keeps contours until execution.
+ *
+ * The contours and the core overlay are kept between calls, so one handle can run any number
+ * of operations and be cleared and refilled while reusing its memory.
+ */
+typedef struct Float32Overlay Float32Overlay;
+
+/**
+ * FFI-safe wrapper around `i_triangle::float::triangulator::Triangulator` producing `f32` results.
+ *
+ * Keeps the contours and triangulation of the last call, so triangulating inputs of a similar
+ * size again does not allocate.
+ */
+typedef struct Float32Triangulator Float32Triangulator;
+
+/**
+ * Wrapper around floating overlay that keeps contours until execution.
+ *
+ * The contours and the core overlay are kept between calls, so one handle can run any number
+ * of operations and be cleared and refilled while reusing its memory.
+ */
+typedef struct Float64Overlay Float64Overlay;
+
+/**
+ * FFI-safe wrapper around `i_triangle::float::triangulator::Triangulator` producing `f64` results.
+ *
+ * Keeps the contours and triangulation of the last call, so triangulating inputs of a similar
+ * size again does not allocate.
+ */
+typedef struct Float64Triangulator Float64Triangulator;
+
+/**
+ * Wrapper around the integer overlay that provides a stable layout for FFI consumers.
+ *
+ * The queued segments and the core's scratch buffers are kept between calls, so one handle can
+ * run any number of operations and be cleared and refilled while reusing its memory.
+ */
+typedef struct IntOverlay IntOverlay;
+
+/**
+ * FFI-safe wrapper around `i_triangle::int::triangulator::IntTriangulator`.
+ *
+ * Keeps the contours and triangulation of the last call, so triangulating inputs of a similar
+ * size again does not allocate.
+ */
+typedef struct IntTriangulator IntTriangulator;
+
+#if defined(ISHAPE_HOST_ALLOCATOR)
+/**
+ * Allocates `size` bytes aligned to `align`, returning null on failure.
+ */
+typedef void *(*IShapeAllocFn)(size_t size, size_t align, void *user_data);
+#endif
+
+#if defined(ISHAPE_HOST_ALLOCATOR)
+/**
+ * Resizes a block from `old_size` to `new_size` bytes keeping `align`, returning null on failure.
+ */
+typedef void *(*IShapeReallocFn)(void *ptr,
+                                 size_t old_size,
+                                 size_t align,
+                                 size_t new_size,
+                                 void *user_data);
+#endif
+
+#if defined(ISHAPE_HOST_ALLOCATOR)
+/**
+ * Releases a block previously returned by the matching alloc or realloc function.
+ */
+typedef void (*IShapeFreeFn)(void *ptr, size_t size, size_t align, void *user_data);
+#endif
+
+typedef uint32_t IntTriangulationIndex;
+
+/**
+ * Borrowed, layout-stable view of a [`FlatIntTriangulation`].
+ *
+ * Every pointer is valid for its matching length until the buffer is modified or freed.
+ */
+typedef struct FlatIntTriangulationView {
+  const int32_t *points;
+  size_t points_len;
+  const IntTriangulationIndex *indices;
+  size_t indices_len;
+} FlatIntTriangulationView;
+
+/**
+ * Borrowed, layout-stable view of a [`FlatF32Triangulation`].
+ *
+ * Every pointer is valid for its matching length until the buffer is modified or freed.
+ */
+typedef struct FlatF32TriangulationView {
+  const float *points;
+  size_t points_len;
+  const IntTriangulationIndex *indices;
+  size_t indices_len;
+} FlatF32TriangulationView;
+
+/**
+ * Borrowed, layout-stable view of a [`FlatF64Triangulation`].
+ *
+ * Every pointer is valid for its matching length until the buffer is modified or freed.
+ */
+typedef struct FlatF64TriangulationView {
+  const double *points;
+  size_t points_len;
+  const IntTriangulationIndex *indices;
+  size_t indices_len;
+} FlatF64TriangulationView;
+
+/**
+ * Half-open range helper that can safely cross the FFI boundary.
+ */
+typedef struct RangeFFI {
+  uint64_t start;
+  uint64_t end;
+} RangeFFI;
+
+/**
+ * Borrowed, layout-stable view of a [`FlatShapesBuffer`].
+ *
+ * Every pointer is valid for its matching length until the buffer is modified or freed.
+ */
+typedef struct FlatShapesView {
+  const int32_t *points;
+  size_t points_len;
+  const struct RangeFFI *contours;
+  size_t contours_len;
+  const struct RangeFFI *shapes;
+  size_t shapes_len;
+} FlatShapesView;
+
+/**
+ * Borrowed, layout-stable view of a [`FlatF32ShapesBuffer`].
+ *
+ * Every pointer is valid for its matching length until the buffer is modified or freed.
+ */
+typedef struct FlatF32ShapesView {
+  const float *points;
+  size_t points_len;
+  const struct RangeFFI *contours;
+  size_t contours_len;
+  const struct RangeFFI *shapes;
+  size_t shapes_len;
+} FlatF32ShapesView;
+
+/**
+ * Borrowed, layout-stable view of a [`FlatF64ShapesBuffer`].
+ *
+ * Every pointer is valid for its matching length until the buffer is modified or freed.
+ */
+typedef struct FlatF64ShapesView {
+  const double *points;
+  size_t points_len;
+  const struct RangeFFI *contours;
+  size_t contours_len;
+  const struct RangeFFI *shapes;
+  size_t shapes_len;
+} FlatF64ShapesView;
+
+/**
+ * FFI-safe options struct mirroring `i_overlay::core::overlay::IntOverlayOptions`.
+ */
+typedef struct IntOverlayOptions {
+  bool preserve_input_collinear;
+  enum IntContourDirection output_direction;
+  bool preserve_output_collinear;
+  uint64_t min_output_area;
+} IntOverlayOptions;
+
+/**
+ * Caller-provided arrays receiving integer shapes laid out like `FlatShapesBuffer`.
+ *
+ * Before the call every `*_capacity` holds the number of elements its array can take. After the
+ * call every `*_len` holds the number of elements the result needs, also when it did not fit.
+ */
+typedef struct FlatShapesSlices {
+  int32_t *points;
+  size_t points_capacity;
+  size_t points_len;
+  struct RangeFFI *contours;
+  size_t contours_capacity;
+  size_t contours_len;
+  struct RangeFFI *shapes;
+  size_t shapes_capacity;
+  size_t shapes_len;
+} FlatShapesSlices;
+
+/**
+ * FFI-safe options struct mirroring `i_overlay::float::overlay::OverlayOptions<f64>`, plus the
+ * policy for non-finite input coordinates.
+ */
+typedef struct Float64OverlayOptions {
+  bool preserve_input_collinear;
+  enum IntContourDirection output_direction;
+  bool preserve_output_collinear;
+  double min_output_area;
+  bool clean_result;
+  /**
+   * Applied to every contour passed to `add_contour`.
+   */
+  enum IShapeNonFinitePolicy non_finite;
+} Float64OverlayOptions;
+
+/**
+ * Caller-provided arrays receiving `f64` shapes laid out like `FlatF64ShapesBuffer`.
+ *
+ * Capacities and lengths follow the same rules as [`FlatShapesSlices`].
+ */
+typedef struct FlatF64ShapesSlices {
+  double *points;
+  size_t points_capacity;
+  size_t points_len;
+  struct RangeFFI *contours;
+  size_t contours_capacity;
+  size_t contours_len;
+  struct RangeFFI *shapes;
+  size_t shapes_capacity;
+  size_t shapes_len;
+} FlatF64ShapesSlices;
+
+/**
+ * FFI-safe options struct mirroring `i_overlay::float::overlay::OverlayOptions<f32>`, plus the
+ * policy for non-finite input coordinates.
+ */
+typedef struct Float32OverlayOptions {
+  bool preserve_input_collinear;
+  enum IntContourDirection output_direction;
+  bool preserve_output_collinear;
+  float min_output_area;
+  bool clean_result;
+  /**
+   * Applied to every contour passed to `add_contour`.
+   */
+  enum IShapeNonFinitePolicy non_finite;
+} Float32OverlayOptions;
+
+/**
+ * Caller-provided arrays receiving `f32` shapes laid out like `FlatF32ShapesBuffer`.
+ *
+ * Capacities and lengths follow the same rules as [`FlatShapesSlices`].
+ */
+typedef struct FlatF32ShapesSlices {
+  float *points;
+  size_t points_capacity;
+  size_t points_len;
+  struct RangeFFI *contours;
+  size_t contours_capacity;
+  size_t contours_len;
+  struct RangeFFI *shapes;
+  size_t shapes_capacity;
+  size_t shapes_len;
+} FlatF32ShapesSlices;
+
+/**
+ * FFI-safe representation of `LineJoin<f64>`.
+ *
+ * `value` is the miter limit angle for `Miter` and the segment ratio for `Round`; it is ignored
+ * for `Bevel`.
+ */
+typedef struct FfiLineJoin {
+  enum FfiLineJoinKind kind;
+  double value;
+} FfiLineJoin;
+
+/**
+ * FFI-safe options struct mirroring `i_overlay::mesh::style::OutlineStyle<f64>`, plus the policy
+ * for non-finite input coordinates.
+ *
+ * Positive offsets deflate, as in `ishape_outline_f64_contour_to_flat`, which is the reverse of
+ * the core style.
+ */
+typedef struct FfiOutlineStyle {
+  double outer_offset;
+  double inner_offset;
+  struct FfiLineJoin join;
+  /**
+   * Applied to every offset contour.
+   */
+  enum IShapeNonFinitePolicy non_finite;
+} FfiOutlineStyle;
+
+/**
+ * FFI-safe representation of `LineCap<FloatPoint<f64>, f64>`.
+ *
+ * `value` is the segment angle for `Round`; it is ignored for `Butt` and `Square`.
+ */
+typedef struct FfiLineCap {
+  enum FfiLineCapKind kind;
+  double value;
+} FfiLineCap;
+
+/**
+ * FFI-safe options struct mirroring `i_overlay::mesh::style::StrokeStyle<FloatPoint<f64>, f64>`,
+ * plus the policy for non-finite input coordinates.
+ */
+typedef struct FfiStrokeStyle {
+  double width;
+  struct FfiLineJoin join;
+  struct FfiLineCap start_cap;
+  struct FfiLineCap end_cap;
+  /**
+   * Applied to every stroked path.
+   */
+  enum IShapeNonFinitePolicy non_finite;
+} FfiStrokeStyle;
+
+/**
+ * FFI-safe representation of `i_triangle::int::validation::Validation`.
+ */
+typedef struct IntTriangulatorValidation {
+  enum IntFillRule fill_rule;
+  struct IntOverlayOptions options;
+} IntTriangulatorValidation;
+
+/**
+ * Caller-provided arrays receiving an integer triangulation laid out like `FlatIntTriangulation`.
+ *
+ * Before the call every `*_capacity` holds the number of elements its array can take. After the
+ * call every `*_len` holds the number of elements the result needs, also when it did not fit.
+ */
+typedef struct FlatIntTriangulationSlices {
+  int32_t *points;
+  size_t points_capacity;
+  size_t points_len;
+  IntTriangulationIndex *indices;
+  size_t indices_capacity;
+  size_t indices_len;
+} FlatIntTriangulationSlices;
+
+/**
+ * Options for the `f32` and `f64` triangulators: the validation shared with the integer
+ * triangulator plus the policy for non-finite input coordinates.
+ */
+typedef struct FloatTriangulatorOptions {
+  struct IntTriangulatorValidation validation;
+  /**
+   * Applied to every contour of the triangulated shapes.
+   */
+  enum IShapeNonFinitePolicy non_finite;
+} FloatTriangulatorOptions;
+
+/**
+ * Caller-provided arrays receiving an `f32` triangulation laid out like `FlatF32Triangulation`.
+ *
+ * Capacities and lengths follow the same rules as [`FlatIntTriangulationSlices`].
+ */
+typedef struct FlatF32TriangulationSlices {
+  float *points;
+  size_t points_capacity;
+  size_t points_len;
+  IntTriangulationIndex *indices;
+  size_t indices_capacity;
+  size_t indices_len;
+} FlatF32TriangulationSlices;
+
+/**
+ * Caller-provided arrays receiving an `f64` triangulation laid out like `FlatF64Triangulation`.
+ *
+ * Capacities and lengths follow the same rules as [`FlatIntTriangulationSlices`].
+ */
+typedef struct FlatF64TriangulationSlices {
+  double *points;
+  size_t points_capacity;
+  size_t points_len;
+  IntTriangulationIndex *indices;
+  size_t indices_capacity;
+  size_t indices_len;
+} FlatF64TriangulationSlices;
+
+#ifdef __cplusplus
+extern "C" {
+#endif // __cplusplus
+
+/**
+ * Returns a pointer to a static NUL-terminated version string, for example
+ * `0.1.0 (i_triangle 0.38.0, i_overlay 4.1.1)`.
+ *
+ * The crate version comes first, followed by the versions of the geometry crates the library
+ * was built against. The returned string lives for the duration of the program and must not be
+ * freed.
+ */
+const char *ishape_version(void);
+
+/**
+ * Returns the version of the C ABI implemented by the library.
+ *
+ * Hosts should refuse to run when it differs from the `ISHAPE_ABI_VERSION` of the header they
+ * were built against.
+ */
+uint32_t ishape_abi_version(void);
+
+/**
+ * Returns `true` when the optional feature `name`, such as `"host-allocator"`, was compiled in.
+ *
+ * Unknown names and a null `name` return `false`.
+ *
+ * # Safety
+ * `name` must either be null or point to a NUL-terminated string.
+ */
+bool ishape_has_feature(const char *name);
+
+/**
+ * Returns a pointer to a static NUL-terminated description of `status`.
+ *
+ * The returned string lives for the duration of the program and must not be freed.
+ */
+const char *ishape_status_message(enum IShapeStatus status);
+
+/**
+ * Copies the calling thread's last error message into `buffer` as a NUL-terminated string.
+ *
+ * The message carries context the status code cannot, such as the offending contour index.
+ * It is only meaningful after an entry point returned a status other than
+ * [`IShapeStatus::Ok`], and stays recorded until the next failure or
+ * [`ishape_last_error_clear`].
+ *
+ * Returns the length of the full message including the NUL terminator, or `0` when no error is
+ * recorded. When `len` is smaller than that, the message is truncated; pass a null `buffer` to
+ * query the required size.
+ *
+ * # Safety
+ * `buffer` must either be null or point to `len` writable bytes.
+ */
+size_t ishape_last_error_message(char *buffer, size_t len);
+
+/**
+ * Forgets the calling thread's last error message.
+ */
+void ishape_last_error_clear(void);
+
+#if defined(ISHAPE_HOST_ALLOCATOR)
+/**
+ * Routes the memory of handles and buffers created from now on through host-provided
+ * functions.
+ *
+ * Covers everything returned by the `ishape_*_create` functions, including the storage the
+ * buffers grow into; scratch memory used while an operation runs still comes from the global
+ * allocator. Each handle and buffer releases its memory through the functions that were
+ * installed when it was created, so hooks can be replaced at any time and passing null for both
+ * `alloc_fn` and `free_fn` restores the global allocator. `realloc_fn` may be null, in which case
+ * growth is done with an allocate, copy and free sequence. `user_data` is passed through to every
+ * call.
+ *
+ * Returns [`IShapeStatus::NullPointer`] when only one of `alloc_fn` and `free_fn` is null.
+ * Available when the library is built with the `host-allocator` feature; check with
+ * [`ishape_has_feature`].
+ *
+ * # Safety
+ * The functions must be safe to call from any thread, return memory aligned to the requested
+ * alignment, and stay valid together with `user_data` until every handle and buffer created
+ * while they were installed has been freed.
+ */
+enum IShapeStatus ishape_set_allocator(IShapeAllocFn alloc_fn,
+                                       IShapeReallocFn realloc_fn,
+                                       IShapeFreeFn free_fn,
+                                       void *user_data);
+#endif
+
+/**
+ * Allocates an empty flat integer triangulation buffer.
+ *
+ * # Safety
+ * The returned pointer must be released with [`ishape_triangle_flat_triangulation_free`].
+ */
+struct FlatIntTriangulation *ishape_triangle_flat_triangulation_create(void);
+
+/**
+ * Allocates a flat triangulation buffer reserving capacity for `points` vertices and `triangles`.
+ *
+ * # Safety
+ * The returned pointer must be released with [`ishape_triangle_flat_triangulation_free`].
+ */
+struct FlatIntTriangulation *ishape_triangle_flat_triangulation_with_capacity(size_t points,
+                                                                              size_t triangles);
+
+/**
+ * Clears the buffer contents without releasing the allocation.
+ *
+ * # Safety
+ * Passing a null pointer is a no-op. Otherwise the pointer must be valid and uniquely owned.
+ */
+void ishape_triangle_flat_triangulation_clear(struct FlatIntTriangulation *buffer);
+
+/**
+ * Releases a flat triangulation buffer previously allocated with
+ * `ishape_triangle_flat_triangulation_*`.
+ *
+ * # Safety
+ * Passing a null pointer is a no-op. Otherwise the pointer must have been allocated by this crate.
+ */
+void ishape_triangle_flat_triangulation_free(struct FlatIntTriangulation *buffer);
+
+/**
+ * Returns a pointer to the flattened point buffer.
+ */
+const int32_t *ishape_triangle_flat_triangulation_points_ptr(const struct FlatIntTriangulation *buffer);
+
+/**
+ * Returns the flattened point buffer length.
+ */
+size_t ishape_triangle_flat_triangulation_points_len(const struct FlatIntTriangulation *buffer);
+
+/**
+ * Returns a pointer to the triangle indices buffer.
+ */
+const IntTriangulationIndex *ishape_triangle_flat_triangulation_indices_ptr(const struct FlatIntTriangulation *buffer);
+
+/**
+ * Returns the triangle index count.
+ */
+size_t ishape_triangle_flat_triangulation_indices_len(const struct FlatIntTriangulation *buffer);
+
+/**
+ * Returns the points and triangle indices of a triangulation buffer in one call.
+ *
+ * A null buffer yields a view with null pointers and zero lengths. The view borrows the buffer
+ * and is invalidated by any call that modifies or frees it.
+ */
+struct FlatIntTriangulationView ishape_triangle_flat_triangulation_view(const struct FlatIntTriangulation *buffer);
+
+/**
+ * Allocates an empty flat `f32` triangulation buffer.
+ *
+ * # Safety
+ * The returned pointer must be released with [`ishape_triangle_flat_f32_triangulation_free`].
+ */
+struct FlatF32Triangulation *ishape_triangle_flat_f32_triangulation_create(void);
+
+/**
+ * Allocates a flat `f32` triangulation buffer reserving capacity for `points` and `triangles`.
+ *
+ * # Safety
+ * The returned pointer must be released with [`ishape_triangle_flat_f32_triangulation_free`].
+ */
+struct FlatF32Triangulation *ishape_triangle_flat_f32_triangulation_with_capacity(size_t points,
+                                                                                  size_t triangles);
+
+/**
+ * Clears the contents of a flat `f32` triangulation buffer while keeping its allocation.
+ *
+ * # Safety
+ * Passing a null pointer is a no-op.
+ */
+void ishape_triangle_flat_f32_triangulation_clear(struct FlatF32Triangulation *buffer);
+
+/**
+ * Releases a flat `f32` triangulation buffer previously allocated with
+ * `ishape_triangle_flat_f32_triangulation_*`.
+ *
+ * # Safety
+ * Passing a null pointer is a no-op.
+ */
+void ishape_triangle_flat_f32_triangulation_free(struct FlatF32Triangulation *buffer);
+
+/**
+ * Returns a pointer to the flattened single-precision point buffer.
+ */
+const float *ishape_triangle_flat_f32_triangulation_points_ptr(const struct FlatF32Triangulation *buffer);
+
+/**
+ * Returns the flattened single-precision point buffer length.
+ */
+size_t ishape_triangle_flat_f32_triangulation_points_len(const struct FlatF32Triangulation *buffer);
+
+/**
+ * Returns a pointer to the triangle indices buffer.
+ */
+const IntTriangulationIndex *ishape_triangle_flat_f32_triangulation_indices_ptr(const struct FlatF32Triangulation *buffer);
+
+/**
+ * Returns the triangle index count for the single-precision buffer.
+ */
+size_t ishape_triangle_flat_f32_triangulation_indices_len(const struct FlatF32Triangulation *buffer);
+
+/**
+ * Returns the points and triangle indices of a `f32` triangulation buffer in one call.
+ *
+ * A null buffer yields a view with null pointers and zero lengths. The view borrows the buffer
+ * and is invalidated by any call that modifies or frees it.
+ */
+struct FlatF32TriangulationView ishape_triangle_flat_f32_triangulation_view(const struct FlatF32Triangulation *buffer);
+
+/**
+ * Allocates an empty flat `f64` triangulation buffer.
+ *
+ * # Safety
+ * The returned pointer must be released with [`ishape_triangle_flat_f64_triangulation_free`].
+ */
+struct FlatF64Triangulation *ishape_triangle_flat_f64_triangulation_create(void);
+
+/**
+ * Allocates a flat `f64` triangulation buffer reserving capacity for `points` and `triangles`.
+ *
+ * # Safety
+ * The returned pointer must be released with [`ishape_triangle_flat_f64_triangulation_free`].
+ */
+struct FlatF64Triangulation *ishape_triangle_flat_f64_triangulation_with_capacity(size_t points,
+                                                                                  size_t triangles);
+
+/**
+ * Clears the contents of a flat `f64` triangulation buffer while keeping its allocation.
+ *
+ * # Safety
+ * Passing a null pointer is a no-op.
+ */
+void ishape_triangle_flat_f64_triangulation_clear(struct FlatF64Triangulation *buffer);
+
+/**
+ * Releases a flat `f64` triangulation buffer previously allocated with
+ * `ishape_triangle_flat_f64_triangulation_*`.
+ *
+ * # Safety
+ * Passing a null pointer is a no-op.
+ */
+void ishape_triangle_flat_f64_triangulation_free(struct FlatF64Triangulation *buffer);
+
+/**
+ * Returns a pointer to the flattened double-precision point buffer.
+ */
+const double *ishape_triangle_flat_f64_triangulation_points_ptr(const struct FlatF64Triangulation *buffer);
+
+/**
+ * Returns the flattened double-precision point buffer length.
+ */
+size_t ishape_triangle_flat_f64_triangulation_points_len(const struct FlatF64Triangulation *buffer);
+
+/**
+ * Returns a pointer to the triangle indices buffer for `f64` results.
+ */
+const IntTriangulationIndex *ishape_triangle_flat_f64_triangulation_indices_ptr(const struct FlatF64Triangulation *buffer);
+
+/**
+ * Returns the triangle index count for the double-precision buffer.
+ */
+size_t ishape_triangle_flat_f64_triangulation_indices_len(const struct FlatF64Triangulation *buffer);
+
+/**
+ * Returns the points and triangle indices of a `f64` triangulation buffer in one call.
+ *
+ * A null buffer yields a view with null pointers and zero lengths. The view borrows the buffer
+ * and is invalidated by any call that modifies or frees it.
+ */
+struct FlatF64TriangulationView ishape_triangle_flat_f64_triangulation_view(const struct FlatF64Triangulation *buffer);
+
+/**
+ * Allocates an empty flat shapes buffer on the heap.
+ *
+ * # Safety
+ * The returned pointer must be released with [`ishape_flat_shapes_free`].
+ */
+struct FlatShapesBuffer *ishape_flat_shapes_create(void);
+
+/**
+ * Allocates a flat shapes buffer reserving `points`, `contours`, and `shapes` capacity.
+ *
+ * # Safety
+ * The returned pointer must be released with [`ishape_flat_shapes_free`].
+ */
+struct FlatShapesBuffer *ishape_flat_shapes_with_capacity(size_t points,
+                                                          size_t contours,
+                                                          size_t shapes);
+
+/**
+ * Clears the buffer contents without releasing the allocation.
+ *
+ * # Safety
+ * Passing a null pointer is a no-op. Otherwise, the pointer must be valid and uniquely owned.
+ */
+void ishape_flat_shapes_clear(struct FlatShapesBuffer *buffer);
+
+/**
+ * Releases a flat shapes buffer previously allocated with `ishape_flat_shapes_*`.
+ *
+ * # Safety
+ * Passing a null pointer is a no-op. Otherwise the pointer must have been allocated by this crate.
+ */
+void ishape_flat_shapes_free(struct FlatShapesBuffer *buffer);
+
+/**
+ * Returns a pointer to the flattened points buffer.
+ */
+const int32_t *ishape_flat_shapes_points_ptr(const struct FlatShapesBuffer *buffer);
+
+/**
+ * Returns the flattened points length.
+ */
+size_t ishape_flat_shapes_points_len(const struct FlatShapesBuffer *buffer);
+
+/**
+ * Returns a pointer to the contour ranges buffer.
+ */
+const struct RangeFFI *ishape_flat_shapes_contours_ptr(const struct FlatShapesBuffer *buffer);
+
+/**
+ * Returns the number of contour ranges.
+ */
+size_t ishape_flat_shapes_contours_len(const struct FlatShapesBuffer *buffer);
+
+/**
+ * Returns a pointer to the shape ranges buffer.
+ */
+const struct RangeFFI *ishape_flat_shapes_shapes_ptr(const struct FlatShapesBuffer *buffer);
+
+/**
+ * Returns the number of shape ranges stored.
+ */
+size_t ishape_flat_shapes_shapes_len(const struct FlatShapesBuffer *buffer);
+
+/**
+ * Returns the points, contour ranges and shape ranges of a shapes buffer in one call.
+ *
+ * A null buffer yields a view with null pointers and zero lengths. The view borrows the buffer
+ * and is invalidated by any call that modifies or frees it.
+ */
+struct FlatShapesView ishape_flat_shapes_view(const struct FlatShapesBuffer *buffer);
+
+/**
+ * Replaces the contents of a flat buffer with copies of caller-provided flat arrays.
+ *
+ * `points` holds `[x0, y0, x1, y1, ...]`, `contour_ranges` index into `points` and
+ * `shape_ranges` index into `contour_ranges`, using the same layout the buffer exposes.
+ *
+ * Returns [`IShapeStatus::NullPointer`] for a null buffer or a null array with a non-zero
+ * count, [`IShapeStatus::OddCoordinateCount`] for an odd coordinate count, or
+ * [`IShapeStatus::InvalidRange`] when a range does not fit or starts before the previous range
+ * ends. On those errors the buffer is unchanged; after [`IShapeStatus::Panic`] it is cleared.
+ *
+ * # Safety
+ * - `buffer` must be a valid pointer to a `FlatShapesBuffer`.
+ * - Every array must either be null with a zero count or point to the given number of
+ *   consecutive values.
+ * - No array may alias the storage of `buffer`, such as the arrays of
+ *   [`ishape_flat_shapes_view`]; copy them first to set a buffer from its own contents.
+ */
+enum IShapeStatus ishape_flat_shapes_set(struct FlatShapesBuffer *buffer,
+                                         const int32_t *points,
+                                         size_t points_count,
+                                         const struct RangeFFI *contour_ranges,
+                                         size_t contours_count,
+                                         const struct RangeFFI *shape_ranges,
+                                         size_t shapes_count);
+
+/**
+ * Starts a new empty shape in a flat buffer; contours pushed afterwards are added to it.
+ *
+ * There is no matching end call: the shape ends at the next `begin_shape`. A shape that gets
+ * no contours stays in the buffer as an empty range, which every entry point accepts and
+ * skips.
+ *
+ * Returns [`IShapeStatus::NullPointer`] for a null buffer.
+ *
+ * # Safety
+ * `buffer` must be a valid pointer to a `FlatShapesBuffer`.
+ */
+enum IShapeStatus ishape_flat_shapes_begin_shape(struct FlatShapesBuffer *buffer);
+
+/**
+ * Appends a contour `[x0, y0, x1, y1, ...]` to the last shape of a flat buffer, starting a
+ * new shape when the buffer has none.
+ *
+ * Returns [`IShapeStatus::NullPointer`] for a null buffer or null coordinates,
+ * [`IShapeStatus::OddCoordinateCount`] for an odd coordinate count, or
+ * [`IShapeStatus::TooFewPoints`] for an empty contour.
+ *
+ * # Safety
+ * - `buffer` must be a valid pointer to a `FlatShapesBuffer`.
+ * - `points` must point to `count` consecutive `i32` values that do not alias the storage of
+ *   `buffer`, since appending may move it.
+ */
+enum IShapeStatus ishape_flat_shapes_push_contour(struct FlatShapesBuffer *buffer,
+                                                  const int32_t *points,
+                                                  size_t count);
+
+/**
+ * Allocates an empty flat `f32` shapes buffer on the heap.
+ *
+ * # Safety
+ * The returned pointer must be released with [`ishape_flat_f32_shapes_free`].
+ */
+struct FlatF32ShapesBuffer *ishape_flat_f32_shapes_create(void);
+
+/**
+ * Allocates a flat `f32` shapes buffer reserving `points`, `contours`, and `shapes` capacity.
+ *
+ * # Safety
+ * The returned pointer must be released with [`ishape_flat_f32_shapes_free`].
+ */
+struct FlatF32ShapesBuffer *ishape_flat_f32_shapes_with_capacity(size_t points,
+                                                                 size_t contours,
+                                                                 size_t shapes);
+
+/**
+ * Clears the buffer contents without releasing the allocation.
+ *
+ * # Safety
+ * Passing a null pointer is a no-op. Otherwise, the pointer must be valid and uniquely owned.
+ */
+void ishape_flat_f32_shapes_clear(struct FlatF32ShapesBuffer *buffer);
+
+/**
+ * Releases a flat `f32` shapes buffer previously allocated with `ishape_flat_f32_shapes_*`.
+ *
+ * # Safety
+ * Passing a null pointer is a no-op. Otherwise the pointer must have been allocated by this crate.
+ */
+void ishape_flat_f32_shapes_free(struct FlatF32ShapesBuffer *buffer);
+
+/**
+ * Returns a pointer to the flattened points buffer.
+ */
+const float *ishape_flat_f32_shapes_points_ptr(const struct FlatF32ShapesBuffer *buffer);
+
+/**
+ * Returns the flattened points length.
+ */
+size_t ishape_flat_f32_shapes_points_len(const struct FlatF32ShapesBuffer *buffer);
+
+/**
+ * Returns a pointer to the contour ranges buffer.
+ */
+const struct RangeFFI *ishape_flat_f32_shapes_contours_ptr(const struct FlatF32ShapesBuffer *buffer);
+
+/**
+ * Returns the number of contour ranges.
+ */
+size_t ishape_flat_f32_shapes_contours_len(const struct FlatF32ShapesBuffer *buffer);
+
+/**
+ * Returns a pointer to the shape ranges buffer.
+ */
+const struct RangeFFI *ishape_flat_f32_shapes_shapes_ptr(const struct FlatF32ShapesBuffer *buffer);
+
+/**
+ * Returns the number of shape ranges stored.
+ */
+size_t ishape_flat_f32_shapes_shapes_len(const struct FlatF32ShapesBuffer *buffer);
+
+/**
+ * Returns the points, contour ranges and shape ranges of a `f32` shapes buffer in one call.
+ *
+ * A null buffer yields a view with null pointers and zero lengths. The view borrows the buffer
+ * and is invalidated by any call that modifies or frees it.
+ */
+struct FlatF32ShapesView ishape_flat_f32_shapes_view(const struct FlatF32ShapesBuffer *buffer);
+
+/**
+ * Replaces the contents of a flat `f32` buffer with copies of caller-provided flat arrays.
+ *
+ * `points` holds `[x0, y0, x1, y1, ...]`, `contour_ranges` index into `points` and
+ * `shape_ranges` index into `contour_ranges`, using the same layout the buffer exposes.
+ *
+ * Returns [`IShapeStatus::NullPointer`] for a null buffer or a null array with a non-zero
+ * count, [`IShapeStatus::OddCoordinateCount`] for an odd coordinate count, or
+ * [`IShapeStatus::InvalidRange`] when a range does not fit or starts before the previous range
+ * ends. On those errors the buffer is unchanged; after [`IShapeStatus::Panic`] it is cleared.
+ *
+ * # Safety
+ * - `buffer` must be a valid pointer to a `FlatF32ShapesBuffer`.
+ * - Every array must either be null with a zero count or point to the given number of
+ *   consecutive values.
+ * - No array may alias the storage of `buffer`, such as the arrays of
+ *   [`ishape_flat_f32_shapes_view`]; copy them first to set a buffer from its own contents.
+ */
+enum IShapeStatus ishape_flat_f32_shapes_set(struct FlatF32ShapesBuffer *buffer,
+                                             const float *points,
+                                             size_t points_count,
+                                             const struct RangeFFI *contour_ranges,
+                                             size_t contours_count,
+                                             const struct RangeFFI *shape_ranges,
+                                             size_t shapes_count);
+
+/**
+ * Starts a new empty shape in a flat `f32` buffer; contours pushed afterwards are added to it.
+ *
+ * There is no matching end call: the shape ends at the next `begin_shape`. A shape that gets
+ * no contours stays in the buffer as an empty range, which every entry point accepts and
+ * skips.
+ *
+ * Returns [`IShapeStatus::NullPointer`] for a null buffer.
+ *
+ * # Safety
+ * `buffer` must be a valid pointer to a `FlatF32ShapesBuffer`.
+ */
+enum IShapeStatus ishape_flat_f32_shapes_begin_shape(struct FlatF32ShapesBuffer *buffer);
+
+/**
+ * Appends a contour `[x0, y0, x1, y1, ...]` to the last shape of a flat `f32` buffer, starting a
+ * new shape when the buffer has none.
+ *
+ * Returns [`IShapeStatus::NullPointer`] for a null buffer or null coordinates,
+ * [`IShapeStatus::OddCoordinateCount`] for an odd coordinate count, or
+ * [`IShapeStatus::TooFewPoints`] for an empty contour.
+ *
+ * # Safety
+ * - `buffer` must be a valid pointer to a `FlatF32ShapesBuffer`.
+ * - `points` must point to `count` consecutive `f32` values that do not alias the storage of
+ *   `buffer`, since appending may move it.
+ */
+enum IShapeStatus ishape_flat_f32_shapes_push_contour(struct FlatF32ShapesBuffer *buffer,
+                                                      const float *points,
+                                                      size_t count);
+
+/**
+ * Allocates an empty flat `f64` shapes buffer on the heap.
+ *
+ * # Safety
+ * The returned pointer must be released with [`ishape_flat_f64_shapes_free`].
+ */
+struct FlatF64ShapesBuffer *ishape_flat_f64_shapes_create(void);
+
+/**
+ * Allocates a flat `f64` shapes buffer reserving `points`, `contours`, and `shapes` capacity.
+ *
+ * # Safety
+ * The returned pointer must be released with [`ishape_flat_f64_shapes_free`].
+ */
+struct FlatF64ShapesBuffer *ishape_flat_f64_shapes_with_capacity(size_t points,
+                                                                 size_t contours,
+                                                                 size_t shapes);
+
+/**
+ * Clears the buffer contents without releasing the allocation.
+ *
+ * # Safety
+ * Passing a null pointer is a no-op. Otherwise, the pointer must be valid and uniquely owned.
+ */
+void ishape_flat_f64_shapes_clear(struct FlatF64ShapesBuffer *buffer);
+
+/**
+ * Releases a flat `f64` shapes buffer previously allocated with `ishape_flat_f64_shapes_*`.
+ *
+ * # Safety
+ * Passing a null pointer is a no-op. Otherwise the pointer must have been allocated by this crate.
+ */
+void ishape_flat_f64_shapes_free(struct FlatF64ShapesBuffer *buffer);
+
+/**
+ * Returns a pointer to the flattened points buffer.
+ */
+const double *ishape_flat_f64_shapes_points_ptr(const struct FlatF64ShapesBuffer *buffer);
+
+/**
+ * Returns the flattened points length.
+ */
+size_t ishape_flat_f64_shapes_points_len(const struct FlatF64ShapesBuffer *buffer);
+
+/**
+ * Returns a pointer to the contour ranges buffer.
+ */
+const struct RangeFFI *ishape_flat_f64_shapes_contours_ptr(const struct FlatF64ShapesBuffer *buffer);
+
+/**
+ * Returns the number of contour ranges.
+ */
+size_t ishape_flat_f64_shapes_contours_len(const struct FlatF64ShapesBuffer *buffer);
+
+/**
+ * Returns a pointer to the shape ranges buffer.
+ */
+const struct RangeFFI *ishape_flat_f64_shapes_shapes_ptr(const struct FlatF64ShapesBuffer *buffer);
+
+/**
+ * Returns the number of shape ranges stored.
+ */
+size_t ishape_flat_f64_shapes_shapes_len(const struct FlatF64ShapesBuffer *buffer);
+
+/**
+ * Returns the points, contour ranges and shape ranges of a `f64` shapes buffer in one call.
+ *
+ * A null buffer yields a view with null pointers and zero lengths. The view borrows the buffer
+ * and is invalidated by any call that modifies or frees it.
+ */
+struct FlatF64ShapesView ishape_flat_f64_shapes_view(const struct FlatF64ShapesBuffer *buffer);
+
+/**
+ * Replaces the contents of a flat `f64` buffer with copies of caller-provided flat arrays.
+ *
+ * `points` holds `[x0, y0, x1, y1, ...]`, `contour_ranges` index into `points` and
+ * `shape_ranges` index into `contour_ranges`, using the same layout the buffer exposes.
+ *
+ * Returns [`IShapeStatus::NullPointer`] for a null buffer or a null array with a non-zero
+ * count, [`IShapeStatus::OddCoordinateCount`] for an odd coordinate count, or
+ * [`IShapeStatus::InvalidRange`] when a range does not fit or starts before the previous range
+ * ends. On those errors the buffer is unchanged; after [`IShapeStatus::Panic`] it is cleared.
+ *
+ * # Safety
+ * - `buffer` must be a valid pointer to a `FlatF64ShapesBuffer`.
+ * - Every array must either be null with a zero count or point to the given number of
+ *   consecutive values.
+ * - No array may alias the storage of `buffer`, such as the arrays of
+ *   [`ishape_flat_f64_shapes_view`]; copy them first to set a buffer from its own contents.
+ */
+enum IShapeStatus ishape_flat_f64_shapes_set(struct FlatF64ShapesBuffer *buffer,
+                                             const double *points,
+                                             size_t points_count,
+                                             const struct RangeFFI *contour_ranges,
+                                             size_t contours_count,
+                                             const struct RangeFFI *shape_ranges,
+                                             size_t shapes_count);
+
+/**
+ * Starts a new empty shape in a flat `f64` buffer; contours pushed afterwards are added to it.
+ *
+ * There is no matching end call: the shape ends at the next `begin_shape`. A shape that gets
+ * no contours stays in the buffer as an empty range, which every entry point accepts and
+ * skips.
+ *
+ * Returns [`IShapeStatus::NullPointer`] for a null buffer.
+ *
+ * # Safety
+ * `buffer` must be a valid pointer to a `FlatF64ShapesBuffer`.
+ */
+enum IShapeStatus ishape_flat_f64_shapes_begin_shape(struct FlatF64ShapesBuffer *buffer);
+
+/**
+ * Appends a contour `[x0, y0, x1, y1, ...]` to the last shape of a flat `f64` buffer, starting a
+ * new shape when the buffer has none.
+ *
+ * Returns [`IShapeStatus::NullPointer`] for a null buffer or null coordinates,
+ * [`IShapeStatus::OddCoordinateCount`] for an odd coordinate count, or
+ * [`IShapeStatus::TooFewPoints`] for an empty contour.
+ *
+ * # Safety
+ * - `buffer` must be a valid pointer to a `FlatF64ShapesBuffer`.
+ * - `points` must point to `count` consecutive `f64` values that do not alias the storage of
+ *   `buffer`, since appending may move it.
+ */
+enum IShapeStatus ishape_flat_f64_shapes_push_contour(struct FlatF64ShapesBuffer *buffer,
+                                                      const double *points,
+                                                      size_t count);
+
+/**
+ * Creates a new integer overlay pre-allocating space for `capacity` segment endpoints.
+ *
+ * # Safety
+ * The returned pointer must eventually be released with [`ishape_overlay_int_free`] to avoid
+ * leaking resources.
+ */
+struct IntOverlay *ishape_overlay_int_create(size_t capacity, struct IntOverlayOptions options);
+
+/**
+ * Releases an overlay previously created with [`ishape_overlay_int_create`].
+ *
+ * Passing a null pointer is a no-op.
+ *
+ * # Safety
+ * The pointer must have been returned by [`ishape_overlay_int_create`] and not freed already.
+ */
+void ishape_overlay_int_free(struct IntOverlay *handle);
+
+/**
+ * Removes every contour queued in the overlay without releasing its memory, so the handle can
+ * be refilled for the next operation.
+ * Contour numbers in later error messages start again from 0.
+ *
+ * # Safety
+ * Passing a null pointer is a no-op. Otherwise, the pointer must be valid and uniquely owned.
+ */
+void ishape_overlay_int_clear(struct IntOverlay *handle);
+
+/**
+ * Appends a contour to the overlay using a flat coordinate buffer `[x0, y0, x1, y1, ...]`.
+ *
+ * Returns [`IShapeStatus::NullPointer`] for a null handle or null coordinates with non-zero
+ * length and [`IShapeStatus::OddCoordinateCount`] for an odd number of coordinates. On success
+ * the contour is queued inside the overlay.
+ *
+ * # Safety
+ * - `handle` must be a valid pointer obtained from [`ishape_overlay_int_create`].
+ * - `points` must either be null with `count == 0` or point to `count` consecutive `i32` values.
+ */
+enum IShapeStatus ishape_overlay_int_add_contour(struct IntOverlay *handle,
+                                                 const int32_t *points,
+                                                 size_t count,
+                                                 enum IntShapeType shape_type);
+
+/**
+ * Runs the configured boolean operation, writing results into a flat buffer.
+ *
+ * Returns [`IShapeStatus::NullPointer`] if any pointer is null; otherwise the output buffer is
+ * populated and [`IShapeStatus::Ok`] is returned.
+ *
+ * The queued contours are kept, so the same handle can run further operations on them or be
+ * emptied with [`ishape_overlay_int_clear`] and refilled.
+ *
+ * # Safety
+ * All pointers must be valid and uniquely owned for the duration of the call.
+ */
+enum IShapeStatus ishape_overlay_int_overlay_into_flat(struct IntOverlay *handle,
+                                                       enum IntOverlayRule overlay_rule,
+                                                       enum IntFillRule fill_rule,
+                                                       struct FlatShapesBuffer *output);
+
+/**
+ * Runs the configured boolean operation, writing results into caller-provided flat arrays.
+ *
+ * Every `*_len` field of `output` receives the length the result needs. Returns
+ * [`IShapeStatus::InsufficientCapacity`] without writing the arrays when any capacity is too
+ * small, so a first call with zero capacities queries the sizes and a second call fills them.
+ * Returns [`IShapeStatus::NullPointer`] if any pointer is null.
+ *
+ * The queued contours are kept, so the same handle can run further operations on them or be
+ * emptied with [`ishape_overlay_int_clear`] and refilled.
+ *
+ * # Safety
+ * - `handle` must be a valid pointer obtained from [`ishape_overlay_int_create`].
+ * - `output` must be a valid pointer to a `FlatShapesSlices` whose arrays are either null with zero
+ *   capacity or valid for writes of their capacity.
+ */
+enum IShapeStatus ishape_overlay_int_overlay_into_slices(struct IntOverlay *handle,
+                                                         enum IntOverlayRule overlay_rule,
+                                                         enum IntFillRule fill_rule,
+                                                         struct FlatShapesSlices *output);
+
+/**
+ * Creates a new `f64` overlay pre-allocating space for contour metadata.
+ *
+ * # Safety
+ * The returned pointer must eventually be released with [`ishape_overlay_f64_free`] to avoid
+ * leaking resources.
+ */
+struct Float64Overlay *ishape_overlay_f64_create(size_t capacity,
+                                                 struct Float64OverlayOptions options);
+
+/**
+ * Releases an overlay previously created with [`ishape_overlay_f64_create`].
+ *
+ * Passing a null pointer is a no-op.
+ *
+ * # Safety
+ * The pointer must have been returned by [`ishape_overlay_f64_create`] and not freed already.
+ */
+void ishape_overlay_f64_free(struct Float64Overlay *handle);
+
+/**
+ * Removes every contour queued in the overlay without releasing its memory, so the handle can
+ * be refilled for the next operation.
+ * Contour numbers in later error messages start again from 0.
+ *
+ * # Safety
+ * Passing a null pointer is a no-op. Otherwise, the pointer must be valid and uniquely owned.
+ */
+void ishape_overlay_f64_clear(struct Float64Overlay *handle);
+
+/**
+ * Appends a contour to the overlay using a flat coordinate buffer `[x0, y0, x1, y1, ...]`.
+ *
+ * Returns [`IShapeStatus::NullPointer`] for a null handle or null coordinates with non-zero
+ * length and [`IShapeStatus::OddCoordinateCount`] for an odd number of coordinates. Points with
+ * a NaN or infinite coordinate follow the `non_finite` option the overlay was created with:
+ * [`IShapeStatus::NonFiniteValue`] is returned when it rejects them, otherwise they or their
+ * contour are dropped. On success the contour is queued inside the overlay.
+ *
+ * # Safety
+ * - `handle` must be a valid pointer obtained from [`ishape_overlay_f64_create`].
+ * - `points` must either be null with `count == 0` or point to `count` consecutive `f64` values.
+ */
+enum IShapeStatus ishape_overlay_f64_add_contour(struct Float64Overlay *handle,
+                                                 const double *points,
+                                                 size_t count,
+                                                 enum IntShapeType shape_type);
+
+/**
+ * Runs the configured boolean operation, writing results into a flat `f64` shapes buffer.
+ *
+ * Returns [`IShapeStatus::NullPointer`] if any pointer is null; otherwise the output buffer is
+ * populated and [`IShapeStatus::Ok`] is returned.
+ *
+ * The queued contours are kept, so the same handle can run further operations on them or be
+ * emptied with [`ishape_overlay_f64_clear`] and refilled.
+ *
+ * # Safety
+ * All pointers must be valid and uniquely owned for the duration of the call.
+ */
+enum IShapeStatus ishape_overlay_f64_overlay_into_flat(struct Float64Overlay *handle,
+                                                       enum IntOverlayRule overlay_rule,
+                                                       enum IntFillRule fill_rule,
+                                                       struct FlatF64ShapesBuffer *output);
+
+/**
+ * Runs the configured boolean operation, writing results into caller-provided flat `f64` arrays.
+ *
+ * Every `*_len` field of `output` receives the length the result needs. Returns
+ * [`IShapeStatus::InsufficientCapacity`] without writing the arrays when any capacity is too
+ * small, so a first call with zero capacities queries the sizes and a second call fills them.
+ * Returns [`IShapeStatus::NullPointer`] if any pointer is null.
+ *
+ * The queued contours are kept, so the same handle can run further operations on them or be
+ * emptied with [`ishape_overlay_f64_clear`] and refilled.
+ *
+ * # Safety
+ * - `handle` must be a valid pointer obtained from [`ishape_overlay_f64_create`].
+ * - `output` must be a valid pointer to a `FlatF64ShapesSlices` whose arrays are either null with zero
+ *   capacity or valid for writes of their capacity.
+ */
+enum IShapeStatus ishape_overlay_f64_overlay_into_slices(struct Float64Overlay *handle,
+                                                         enum IntOverlayRule overlay_rule,
+                                                         enum IntFillRule fill_rule,
+                                                         struct FlatF64ShapesSlices *output);
+
+/**
+ * Creates a new `f32` overlay pre-allocating space for contour metadata.
+ *
+ * # Safety
+ * The returned pointer must eventually be released with [`ishape_overlay_f32_free`] to avoid
+ * leaking resources.
+ */
+struct Float32Overlay *ishape_overlay_f32_create(size_t capacity,
+                                                 struct Float32OverlayOptions options);
+
+/**
+ * Releases an overlay previously created with [`ishape_overlay_f32_create`].
+ *
+ * Passing a null pointer is a no-op.
+ *
+ * # Safety
+ * The pointer must have been returned by [`ishape_overlay_f32_create`] and not freed already.
+ */
+void ishape_overlay_f32_free(struct Float32Overlay *handle);
+
+/**
+ * Removes every contour queued in the overlay without releasing its memory, so the handle can
+ * be refilled for the next operation.
+ * Contour numbers in later error messages start again from 0.
+ *
+ * # Safety
+ * Passing a null pointer is a no-op. Otherwise, the pointer must be valid and uniquely owned.
+ */
+void ishape_overlay_f32_clear(struct Float32Overlay *handle);
+
+/**
+ * Appends a contour to the overlay using a flat coordinate buffer `[x0, y0, x1, y1, ...]`.
+ *
+ * Returns [`IShapeStatus::NullPointer`] for a null handle or null coordinates with non-zero
+ * length and [`IShapeStatus::OddCoordinateCount`] for an odd number of coordinates. Points with
+ * a NaN or infinite coordinate follow the `non_finite` option the overlay was created with:
+ * [`IShapeStatus::NonFiniteValue`] is returned when it rejects them, otherwise they or their
+ * contour are dropped. On success the contour is queued inside the overlay.
+ *
+ * # Safety
+ * - `handle` must be a valid pointer obtained from [`ishape_overlay_f32_create`].
+ * - `points` must either be null with `count == 0` or point to `count` consecutive `f32` values.
+ */
+enum IShapeStatus ishape_overlay_f32_add_contour(struct Float32Overlay *handle,
+                                                 const float *points,
+                                                 size_t count,
+                                                 enum IntShapeType shape_type);
+
+/**
+ * Runs the configured boolean operation, writing results into a flat `f32` shapes buffer.
+ *
+ * Returns [`IShapeStatus::NullPointer`] if any pointer is null; otherwise the output buffer is
+ * populated and [`IShapeStatus::Ok`] is returned.
+ *
+ * The queued contours are kept, so the same handle can run further operations on them or be
+ * emptied with [`ishape_overlay_f32_clear`] and refilled.
+ *
+ * # Safety
+ * All pointers must be valid and uniquely owned for the duration of the call.
+ */
+enum IShapeStatus ishape_overlay_f32_overlay_into_flat(struct Float32Overlay *handle,
+                                                       enum IntOverlayRule overlay_rule,
+                                                       enum IntFillRule fill_rule,
+                                                       struct FlatF32ShapesBuffer *output);
+
+/**
+ * Runs the configured boolean operation, writing results into caller-provided flat `f32` arrays.
+ *
+ * Every `*_len` field of `output` receives the length the result needs. Returns
+ * [`IShapeStatus::InsufficientCapacity`] without writing the arrays when any capacity is too
+ * small, so a first call with zero capacities queries the sizes and a second call fills them.
+ * Returns [`IShapeStatus::NullPointer`] if any pointer is null.
+ *
+ * The queued contours are kept, so the same handle can run further operations on them or be
+ * emptied with [`ishape_overlay_f32_clear`] and refilled.
+ *
+ * # Safety
+ * - `handle` must be a valid pointer obtained from [`ishape_overlay_f32_create`].
+ * - `output` must be a valid pointer to a `FlatF32ShapesSlices` whose arrays are either null with zero
+ *   capacity or valid for writes of their capacity.
+ */
+enum IShapeStatus ishape_overlay_f32_overlay_into_slices(struct Float32Overlay *handle,
+                                                         enum IntOverlayRule overlay_rule,
+                                                         enum IntFillRule fill_rule,
+                                                         struct FlatF32ShapesSlices *output);
+
+/**
+ * Builds an offset/buffer result from a single contour and writes it into a flat `f64` buffer.
+ *
+ * Input contour is represented as `[x0, y0, x1, y1, ...]`. A positive `offset` deflates the
+ * contour and a negative one inflates it, whatever its winding. Every outline entry point,
+ * `f32` ones included, uses this sign convention.
+ *
+ * Returns [`IShapeStatus::NullPointer`] when pointers are invalid,
+ * [`IShapeStatus::OddCoordinateCount`] when the coordinate count is odd,
+ * [`IShapeStatus::TooFewPoints`] when fewer than 3 points are provided, or
+ * [`IShapeStatus::NonFiniteValue`] when `offset` or a coordinate is NaN or infinite.
+ *
+ * # Safety
+ * - `points` must either be null with `count == 0` or point to `count` consecutive `f64` values.
+ * - `output` must be a valid pointer to a `FlatF64ShapesBuffer`.
+ */
+enum IShapeStatus ishape_outline_f64_contour_to_flat(const double *points,
+                                                     size_t count,
+                                                     double offset,
+                                                     struct FlatF64ShapesBuffer *output);
+
+/**
+ * Builds an offset/buffer result from a single contour using explicit outer and inner offsets
+ * and line join style, writing it into a flat `f64` buffer.
+ *
+ * - `outer_offset` and `inner_offset` follow the sign convention of
+ *   [`ishape_outline_f64_contour_to_flat`].
+ * - `join_kind`: `0=Bevel`, `1=Miter`, `2=Round`
+ *
+ * Returns [`IShapeStatus::InvalidLineJoin`] when the join cannot be decoded, and the same input
+ * errors as [`ishape_outline_f64_contour_to_flat`].
+ *
+ * # Safety
+ * - `points` must either be null with `count == 0` or point to `count` consecutive `f64` values.
+ * - `output` must be a valid pointer to a `FlatF64ShapesBuffer`.
+ */
+enum IShapeStatus ishape_outline_f64_contour_to_flat_styled(const double *points,
+                                                            size_t count,
+                                                            double outer_offset,
+                                                            double inner_offset,
+                                                            uint32_t join_kind,
+                                                            double join_value,
+                                                            struct FlatF64ShapesBuffer *output);
+
+/**
+ * Builds an offset/buffer result from a single contour using the provided [`FfiOutlineStyle`],
+ * writing it into a flat `f64` buffer.
+ *
+ * Returns the same errors as [`ishape_outline_f64_contour_to_flat_styled`], except that points
+ * with a NaN or infinite coordinate follow `style.non_finite`: a contour it drops, or leaves with
+ * fewer than 3 points, produces an empty result.
+ *
+ * # Safety
+ * - `points` must either be null with `count == 0` or point to `count` consecutive `f64` values.
+ * - `output` must be a valid pointer to a `FlatF64ShapesBuffer`.
+ */
+enum IShapeStatus ishape_outline_f64_contour_to_flat_with_style(const double *points,
+                                                                size_t count,
+                                                                struct FfiOutlineStyle style,
+                                                                struct FlatF64ShapesBuffer *output);
+
+/**
+ * Builds an offset/buffer result from every shape of a flat `f64` buffer, holes included.
+ *
+ * Outer contours are expected counterclockwise and holes clockwise, matching overlay output.
+ * Offsets follow [`ishape_outline_f64_contour_to_flat`], so a positive `offset` also grows the
+ * holes. Contours with fewer than 3 points are skipped, together with the holes of a skipped
+ * outer contour.
+ *
+ * Returns [`IShapeStatus::NullPointer`] if any pointer is null, and
+ * [`IShapeStatus::OddCoordinateCount`] or [`IShapeStatus::InvalidRange`] when the `shapes` buffer
+ * layout is malformed. A non-finite `offset` or coordinate yields
+ * [`IShapeStatus::NonFiniteValue`]. An empty input clears `output`.
+ *
+ * # Safety
+ * - `shapes` must be a valid pointer to a `FlatF64ShapesBuffer`.
+ * - `output` must be a valid pointer to a `FlatF64ShapesBuffer` that does not alias `shapes`.
+ */
+enum IShapeStatus ishape_outline_f64_shapes_to_flat(const struct FlatF64ShapesBuffer *shapes,
+                                                    double offset,
+                                                    struct FlatF64ShapesBuffer *output);
+
+/**
+ * Builds an offset/buffer result from every shape of a flat `f64` buffer using explicit outer
+ * and inner offsets and line join style.
+ *
+ * - `outer_offset` moves outer contours and `inner_offset` moves holes; positive values deflate
+ *   the shapes as in [`ishape_outline_f64_shapes_to_flat`].
+ * - `join_kind`: `0=Bevel`, `1=Miter`, `2=Round`
+ *
+ * Returns [`IShapeStatus::InvalidLineJoin`] when the join cannot be decoded, and the same errors
+ * as [`ishape_outline_f64_shapes_to_flat`] otherwise.
+ *
+ * # Safety
+ * - `shapes` must be a valid pointer to a `FlatF64ShapesBuffer`.
+ * - `output` must be a valid pointer to a `FlatF64ShapesBuffer` that does not alias `shapes`.
+ */
+enum IShapeStatus ishape_outline_f64_shapes_to_flat_styled(const struct FlatF64ShapesBuffer *shapes,
+                                                           double outer_offset,
+                                                           double inner_offset,
+                                                           uint32_t join_kind,
+                                                           double join_value,
+                                                           struct FlatF64ShapesBuffer *output);
+
+/**
+ * Builds an offset/buffer result from every shape of a flat `f64` buffer using the provided
+ * [`FfiOutlineStyle`].
+ *
+ * Returns the same errors as [`ishape_outline_f64_shapes_to_flat_styled`], except that points
+ * with a NaN or infinite coordinate follow `style.non_finite`; a shape whose outer contour is
+ * dropped is dropped together with its holes.
+ *
+ * # Safety
+ * - `shapes` must be a valid pointer to a `FlatF64ShapesBuffer`.
+ * - `output` must be a valid pointer to a `FlatF64ShapesBuffer` that does not alias `shapes`.
+ */
+enum IShapeStatus ishape_outline_f64_shapes_to_flat_with_style(const struct FlatF64ShapesBuffer *shapes,
+                                                               struct FfiOutlineStyle style,
+                                                               struct FlatF64ShapesBuffer *output);
+
+/**
+ * Builds an offset/buffer result from a single contour and writes it into caller-provided flat
+ * `f64` arrays.
+ *
+ * Offsets follow [`ishape_outline_f64_contour_to_flat`]. Output sizing follows
+ * [`ishape_overlay_f64_overlay_into_slices`]: every `*_len` field receives the needed length and
+ * [`IShapeStatus::InsufficientCapacity`] is returned when an array is too small.
+ *
+ * # Safety
+ * - `points` must either be null with `count == 0` or point to `count` consecutive `f64` values.
+ * - `output` must be a valid pointer to a `FlatF64ShapesSlices` whose arrays are either null with zero
+ *   capacity or valid for writes of their capacity.
+ */
+enum IShapeStatus ishape_outline_f64_contour_to_slices(const double *points,
+                                                       size_t count,
+                                                       double offset,
+                                                       struct FlatF64ShapesSlices *output);
+
+/**
+ * Builds an offset/buffer result from every shape of a flat `f64` buffer and writes it into
+ * caller-provided flat `f64` arrays.
+ *
+ * Offsets follow [`ishape_outline_f64_shapes_to_flat`] and output sizing follows
+ * [`ishape_outline_f64_contour_to_slices`].
+ *
+ * # Safety
+ * - `shapes` must be a valid pointer to a `FlatF64ShapesBuffer`.
+ * - `output` must be a valid pointer to a `FlatF64ShapesSlices` whose arrays are either null with zero
+ *   capacity or valid for writes of their capacity, and must not alias `shapes`.
+ */
+enum IShapeStatus ishape_outline_f64_shapes_to_slices(const struct FlatF64ShapesBuffer *shapes,
+                                                      double offset,
+                                                      struct FlatF64ShapesSlices *output);
+
+/**
+ * Builds a stroke/buffer result from a single contour and writes it into a flat `f64` buffer,
+ * using explicit line join and line cap styles.
+ *
+ * - `join_kind`: `0=Bevel`, `1=Miter`, `2=Round`
+ * - `start_cap_kind` and `end_cap_kind`: `0=Butt`, `1=Round`, `2=Square`
+ *
+ * Returns [`IShapeStatus::InvalidWidth`], [`IShapeStatus::InvalidLineJoin`] or
+ * [`IShapeStatus::InvalidLineCap`] when the style cannot be decoded, and the same input errors
+ * as [`ishape_outline_f64_contour_to_flat`] when fewer than 2 points are provided.
+ *
+ * # Safety
+ * - `points` must either be null with `count == 0` or point to `count` consecutive `f64` values.
+ * - `output` must be a valid pointer to a `FlatF64ShapesBuffer`.
+ */
+enum IShapeStatus ishape_stroke_f64_contour_to_flat_styled(const double *points,
+                                                           size_t count,
+                                                           double width,
+                                                           bool is_closed_path,
+                                                           uint32_t join_kind,
+                                                           double join_value,
+                                                           uint32_t start_cap_kind,
+                                                           double start_cap_value,
+                                                           uint32_t end_cap_kind,
+                                                           double end_cap_value,
+                                                           struct FlatF64ShapesBuffer *output);
+
+/**
+ * Builds a stroke/buffer result from a single contour and writes it into a flat `f64` buffer,
+ * using the provided [`FfiStrokeStyle`].
+ *
+ * Returns the same errors as [`ishape_stroke_f64_contour_to_flat_styled`], except that points
+ * with a NaN or infinite coordinate follow `style.non_finite`: a path it drops, or leaves with
+ * fewer than 2 points, produces an empty result.
+ *
+ * # Safety
+ * - `points` must either be null with `count == 0` or point to `count` consecutive `f64` values.
+ * - `output` must be a valid pointer to a `FlatF64ShapesBuffer`.
+ */
+enum IShapeStatus ishape_stroke_f64_contour_to_flat(const double *points,
+                                                    size_t count,
+                                                    bool is_closed_path,
+                                                    struct FfiStrokeStyle style,
+                                                    struct FlatF64ShapesBuffer *output);
+
+/**
+ * Builds a stroke/buffer result from a single contour and writes it into a flat `f64` buffer,
+ * using the provided [`FfiStrokeStyle`] with custom start and end cap templates.
+ *
+ * A cap template is a flat `[x0, y0, x1, y1, ...]` point array relative to the path end, in
+ * units of half the stroke width: `+x` points away from the path and the cap runs from the
+ * `y = -1` side to the `y = +1` side, so `[1, -1, 1, 1]` reproduces a square cap. An empty
+ * template keeps the corresponding cap of `style`.
+ *
+ * Returns [`IShapeStatus::OddCoordinateCount`] or [`IShapeStatus::InvalidLineCap`] for a
+ * malformed template, and the same errors as [`ishape_stroke_f64_contour_to_flat_styled`].
+ *
+ * # Safety
+ * - `points` must either be null with `count == 0` or point to `count` consecutive `f64` values.
+ * - `start_cap_points` and `end_cap_points` must either be null with a zero count or point to
+ *   the given number of consecutive `f64` values.
+ * - `output` must be a valid pointer to a `FlatF64ShapesBuffer`.
+ */
+enum IShapeStatus ishape_stroke_f64_contour_to_flat_custom_caps(const double *points,
+                                                                size_t count,
+                                                                bool is_closed_path,
+                                                                struct FfiStrokeStyle style,
+                                                                const double *start_cap_points,
+                                                                size_t start_cap_count,
+                                                                const double *end_cap_points,
+                                                                size_t end_cap_count,
+                                                                struct FlatF64ShapesBuffer *output);
+
+/**
+ * Builds a single unioned stroke/buffer result from many paths and writes it into a flat `f64`
+ * buffer, using explicit line join and line cap styles shared by every path.
+ *
+ * - `points` holds the coordinates of all paths as `[x0, y0, x1, y1, ...]`.
+ * - `path_ranges[i]` is the coordinate span of path `i` inside `points`, using the same layout as
+ *   the contour ranges of [`FlatF64ShapesBuffer`].
+ * - `closed_flags[i]` tells whether path `i` is closed.
+ * - `join_kind`, `start_cap_kind` and `end_cap_kind` are encoded as in
+ *   [`ishape_stroke_f64_contour_to_flat_styled`].
+ *
+ * Returns [`IShapeStatus::InvalidRange`] when a path range is reversed, out of bounds or not
+ * aligned to a point, [`IShapeStatus::TooFewPoints`] when a path has fewer than 2 points, and the
+ * same style errors as [`ishape_stroke_f64_contour_to_flat_styled`]. A NaN or infinite
+ * coordinate yields [`IShapeStatus::NonFiniteValue`]. With `paths_count == 0` the output is
+ * cleared.
+ *
+ * # Safety
+ * - `points` must either be null with `points_count == 0` or point to `points_count`
+ *   consecutive `f64` values.
+ * - `path_ranges` and `closed_flags` must either be null with `paths_count == 0` or point to
+ *   `paths_count` consecutive values.
+ * - `output` must be a valid pointer to a `FlatF64ShapesBuffer`.
+ */
+enum IShapeStatus ishape_stroke_f64_paths_to_flat_styled(const double *points,
+                                                         size_t points_count,
+                                                         const struct RangeFFI *path_ranges,
+                                                         const bool *closed_flags,
+                                                         size_t paths_count,
+                                                         double width,
+                                                         uint32_t join_kind,
+                                                         double join_value,
+                                                         uint32_t start_cap_kind,
+                                                         double start_cap_value,
+                                                         uint32_t end_cap_kind,
+                                                         double end_cap_value,
+                                                         struct FlatF64ShapesBuffer *output);
+
+/**
+ * Builds a single unioned stroke/buffer result from many paths and writes it into a flat `f64`
+ * buffer, using the provided [`FfiStrokeStyle`] for every path.
+ *
+ * Paths are described as in [`ishape_stroke_f64_paths_to_flat_styled`], which also lists the
+ * returned errors. Points with a NaN or infinite coordinate follow `style.non_finite`, and
+ * paths it drops are skipped.
+ *
+ * # Safety
+ * - `points` must either be null with `points_count == 0` or point to `points_count`
+ *   consecutive `f64` values.
+ * - `path_ranges` and `closed_flags` must either be null with `paths_count == 0` or point to
+ *   `paths_count` consecutive values.
+ * - `output` must be a valid pointer to a `FlatF64ShapesBuffer`.
+ */
+enum IShapeStatus ishape_stroke_f64_paths_to_flat(const double *points,
+                                                  size_t points_count,
+                                                  const struct RangeFFI *path_ranges,
+                                                  const bool *closed_flags,
+                                                  size_t paths_count,
+                                                  struct FfiStrokeStyle style,
+                                                  struct FlatF64ShapesBuffer *output);
+
+/**
+ * Builds a single unioned stroke/buffer result from many paths and writes it into a flat `f64`
+ * buffer, using the provided [`FfiStrokeStyle`] with custom start and end cap templates.
+ *
+ * Paths are described as in [`ishape_stroke_f64_paths_to_flat_styled`] and cap templates as in
+ * [`ishape_stroke_f64_contour_to_flat_custom_caps`]; every open path uses the same templates.
+ *
+ * # Safety
+ * - `points` must either be null with `points_count == 0` or point to `points_count`
+ *   consecutive `f64` values.
+ * - `path_ranges` and `closed_flags` must either be null with `paths_count == 0` or point to
+ *   `paths_count` consecutive values.
+ * - `start_cap_points` and `end_cap_points` must either be null with a zero count or point to
+ *   the given number of consecutive `f64` values.
+ * - `output` must be a valid pointer to a `FlatF64ShapesBuffer`.
+ */
+enum IShapeStatus ishape_stroke_f64_paths_to_flat_custom_caps(const double *points,
+                                                              size_t points_count,
+                                                              const struct RangeFFI *path_ranges,
+                                                              const bool *closed_flags,
+                                                              size_t paths_count,
+                                                              struct FfiStrokeStyle style,
+                                                              const double *start_cap_points,
+                                                              size_t start_cap_count,
+                                                              const double *end_cap_points,
+                                                              size_t end_cap_count,
+                                                              struct FlatF64ShapesBuffer *output);
+
+/**
+ * Builds a stroke/buffer result from a single contour and writes it into caller-provided flat
+ * `f64` arrays, using the provided [`FfiStrokeStyle`].
+ *
+ * Input errors follow [`ishape_stroke_f64_contour_to_flat`] and output sizing follows
+ * [`ishape_outline_f64_contour_to_slices`].
+ *
+ * # Safety
+ * - `points` must either be null with `count == 0` or point to `count` consecutive `f64` values.
+ * - `output` must be a valid pointer to a `FlatF64ShapesSlices` whose arrays are either null with zero
+ *   capacity or valid for writes of their capacity.
+ */
+enum IShapeStatus ishape_stroke_f64_contour_to_slices(const double *points,
+                                                      size_t count,
+                                                      bool is_closed_path,
+                                                      struct FfiStrokeStyle style,
+                                                      struct FlatF64ShapesSlices *output);
+
+/**
+ * Builds a single unioned stroke/buffer result from many paths and writes it into
+ * caller-provided flat `f64` arrays, using the provided [`FfiStrokeStyle`] for every path.
+ *
+ * Paths and input errors follow [`ishape_stroke_f64_paths_to_flat`] and output sizing follows
+ * [`ishape_outline_f64_contour_to_slices`].
+ *
+ * # Safety
+ * - `points` must either be null with `points_count == 0` or point to `points_count`
+ *   consecutive `f64` values.
+ * - `path_ranges` and `closed_flags` must either be null with `paths_count == 0` or point to
+ *   `paths_count` consecutive values.
+ * - `output` must be a valid pointer to a `FlatF64ShapesSlices` whose arrays are either null with zero
+ *   capacity or valid for writes of their capacity.
+ */
+enum IShapeStatus ishape_stroke_f64_paths_to_slices(const double *points,
+                                                    size_t points_count,
+                                                    const struct RangeFFI *path_ranges,
+                                                    const bool *closed_flags,
+                                                    size_t paths_count,
+                                                    struct FfiStrokeStyle style,
+                                                    struct FlatF64ShapesSlices *output);
+
+/**
+ * Builds an offset/buffer result from a single contour and writes it into a flat `f32` buffer.
+ *
+ * Input contour is represented as `[x0, y0, x1, y1, ...]`. Offsets follow
+ * [`ishape_outline_f64_contour_to_flat`].
+ *
+ * Returns [`IShapeStatus::NullPointer`] when pointers are invalid,
+ * [`IShapeStatus::OddCoordinateCount`] when the coordinate count is odd,
+ * [`IShapeStatus::TooFewPoints`] when fewer than 3 points are provided, or
+ * [`IShapeStatus::NonFiniteValue`] when `offset` or a coordinate is NaN or infinite.
+ *
+ * # Safety
+ * - `points` must either be null with `count == 0` or point to `count` consecutive `f32` values.
+ * - `output` must be a valid pointer to a `FlatF32ShapesBuffer`.
+ */
+enum IShapeStatus ishape_outline_f32_contour_to_flat(const float *points,
+                                                     size_t count,
+                                                     float offset,
+                                                     struct FlatF32ShapesBuffer *output);
+
+/**
+ * Builds an offset/buffer result from a single contour using explicit outer and inner offsets
+ * and line join style, writing it into a flat `f32` buffer.
+ *
+ * - `outer_offset` and `inner_offset` follow the sign convention of
+ *   [`ishape_outline_f64_contour_to_flat`].
+ * - `join_kind`: `0=Bevel`, `1=Miter`, `2=Round`
+ *
+ * Returns [`IShapeStatus::InvalidLineJoin`] when the join cannot be decoded, and the same input
+ * errors as [`ishape_outline_f32_contour_to_flat`].
+ *
+ * # Safety
+ * - `points` must either be null with `count == 0` or point to `count` consecutive `f32` values.
+ * - `output` must be a valid pointer to a `FlatF32ShapesBuffer`.
+ */
+enum IShapeStatus ishape_outline_f32_contour_to_flat_styled(const float *points,
+                                                            size_t count,
+                                                            float outer_offset,
+                                                            float inner_offset,
+                                                            uint32_t join_kind,
+                                                            float join_value,
+                                                            struct FlatF32ShapesBuffer *output);
+
+/**
+ * Builds an offset/buffer result from a single contour using the provided [`FfiOutlineStyle`],
+ * writing it into a flat `f32` buffer.
+ *
+ * Returns the same errors as [`ishape_outline_f32_contour_to_flat_styled`], except that points
+ * with a NaN or infinite coordinate follow `style.non_finite`: a contour it drops, or leaves with
+ * fewer than 3 points, produces an empty result.
+ *
+ * # Safety
+ * - `points` must either be null with `count == 0` or point to `count` consecutive `f32` values.
+ * - `output` must be a valid pointer to a `FlatF32ShapesBuffer`.
+ */
+enum IShapeStatus ishape_outline_f32_contour_to_flat_with_style(const float *points,
+                                                                size_t count,
+                                                                struct FfiOutlineStyle style,
+                                                                struct FlatF32ShapesBuffer *output);
+
+/**
+ * Builds an offset/buffer result from every shape of a flat `f32` buffer, holes included.
+ *
+ * Outer contours are expected counterclockwise and holes clockwise, matching overlay output.
+ * Offsets follow [`ishape_outline_f64_contour_to_flat`], so a positive `offset` also grows the
+ * holes. Contours with fewer than 3 points are skipped, together with the holes of a skipped
+ * outer contour.
+ *
+ * Returns [`IShapeStatus::NullPointer`] if any pointer is null, and
+ * [`IShapeStatus::OddCoordinateCount`] or [`IShapeStatus::InvalidRange`] when the `shapes` buffer
+ * layout is malformed. A non-finite `offset` or coordinate yields
+ * [`IShapeStatus::NonFiniteValue`]. An empty input clears `output`.
+ *
+ * # Safety
+ * - `shapes` must be a valid pointer to a `FlatF32ShapesBuffer`.
+ * - `output` must be a valid pointer to a `FlatF32ShapesBuffer` that does not alias `shapes`.
+ */
+enum IShapeStatus ishape_outline_f32_shapes_to_flat(const struct FlatF32ShapesBuffer *shapes,
+                                                    float offset,
+                                                    struct FlatF32ShapesBuffer *output);
+
+/**
+ * Builds an offset/buffer result from every shape of a flat `f32` buffer using explicit outer
+ * and inner offsets and line join style.
+ *
+ * - `outer_offset` moves outer contours and `inner_offset` moves holes; positive values deflate
+ *   the shapes as in [`ishape_outline_f32_shapes_to_flat`].
+ * - `join_kind`: `0=Bevel`, `1=Miter`, `2=Round`
+ *
+ * Returns [`IShapeStatus::InvalidLineJoin`] when the join cannot be decoded, and the same errors
+ * as [`ishape_outline_f32_shapes_to_flat`] otherwise.
+ *
+ * # Safety
+ * - `shapes` must be a valid pointer to a `FlatF32ShapesBuffer`.
+ * - `output` must be a valid pointer to a `FlatF32ShapesBuffer` that does not alias `shapes`.
+ */
+enum IShapeStatus ishape_outline_f32_shapes_to_flat_styled(const struct FlatF32ShapesBuffer *shapes,
+                                                           float outer_offset,
+                                                           float inner_offset,
+                                                           uint32_t join_kind,
+                                                           float join_value,
+                                                           struct FlatF32ShapesBuffer *output);
+
+/**
+ * Builds an offset/buffer result from every shape of a flat `f32` buffer using the provided
+ * [`FfiOutlineStyle`].
+ *
+ * Returns the same errors as [`ishape_outline_f32_shapes_to_flat_styled`], except that points
+ * with a NaN or infinite coordinate follow `style.non_finite`; a shape whose outer contour is
+ * dropped is dropped together with its holes.
+ *
+ * # Safety
+ * - `shapes` must be a valid pointer to a `FlatF32ShapesBuffer`.
+ * - `output` must be a valid pointer to a `FlatF32ShapesBuffer` that does not alias `shapes`.
+ */
+enum IShapeStatus ishape_outline_f32_shapes_to_flat_with_style(const struct FlatF32ShapesBuffer *shapes,
+                                                               struct FfiOutlineStyle style,
+                                                               struct FlatF32ShapesBuffer *output);
+
+/**
+ * Builds an offset/buffer result from a single contour and writes it into caller-provided flat
+ * `f32` arrays.
+ *
+ * Offsets follow [`ishape_outline_f32_contour_to_flat`]. Output sizing follows
+ * [`ishape_overlay_f32_overlay_into_slices`]: every `*_len` field receives the needed length and
+ * [`IShapeStatus::InsufficientCapacity`] is returned when an array is too small.
+ *
+ * # Safety
+ * - `points` must either be null with `count == 0` or point to `count` consecutive `f32` values.
+ * - `output` must be a valid pointer to a `FlatF32ShapesSlices` whose arrays are either null with zero
+ *   capacity or valid for writes of their capacity.
+ */
+enum IShapeStatus ishape_outline_f32_contour_to_slices(const float *points,
+                                                       size_t count,
+                                                       float offset,
+                                                       struct FlatF32ShapesSlices *output);
+
+/**
+ * Builds an offset/buffer result from every shape of a flat `f32` buffer and writes it into
+ * caller-provided flat `f32` arrays.
+ *
+ * Offsets follow [`ishape_outline_f32_shapes_to_flat`] and output sizing follows
+ * [`ishape_outline_f32_contour_to_slices`].
+ *
+ * # Safety
+ * - `shapes` must be a valid pointer to a `FlatF32ShapesBuffer`.
+ * - `output` must be a valid pointer to a `FlatF32ShapesSlices` whose arrays are either null with zero
+ *   capacity or valid for writes of their capacity, and must not alias `shapes`.
+ */
+enum IShapeStatus ishape_outline_f32_shapes_to_slices(const struct FlatF32ShapesBuffer *shapes,
+                                                      float offset,
+                                                      struct FlatF32ShapesSlices *output);
+
+/**
+ * Builds a stroke/buffer result from a single contour and writes it into a flat `f32` buffer,
+ * using explicit line join and line cap styles.
+ *
+ * - `join_kind`: `0=Bevel`, `1=Miter`, `2=Round`
+ * - `start_cap_kind` and `end_cap_kind`: `0=Butt`, `1=Round`, `2=Square`
+ *
+ * Returns [`IShapeStatus::InvalidWidth`], [`IShapeStatus::InvalidLineJoin`] or
+ * [`IShapeStatus::InvalidLineCap`] when the style cannot be decoded, and the same input errors
+ * as [`ishape_outline_f32_contour_to_flat`] when fewer than 2 points are provided.
+ *
+ * # Safety
+ * - `points` must either be null with `count == 0` or point to `count` consecutive `f32` values.
+ * - `output` must be a valid pointer to a `FlatF32ShapesBuffer`.
+ */
+enum IShapeStatus ishape_stroke_f32_contour_to_flat_styled(const float *points,
+                                                           size_t count,
+                                                           float width,
+                                                           bool is_closed_path,
+                                                           uint32_t join_kind,
+                                                           float join_value,
+                                                           uint32_t start_cap_kind,
+                                                           float start_cap_value,
+                                                           uint32_t end_cap_kind,
+                                                           float end_cap_value,
+                                                           struct FlatF32ShapesBuffer *output);
+
+/**
+ * Builds a stroke/buffer result from a single contour and writes it into a flat `f32` buffer,
+ * using the provided [`FfiStrokeStyle`].
+ *
+ * Returns the same errors as [`ishape_stroke_f32_contour_to_flat_styled`], except that points
+ * with a NaN or infinite coordinate follow `style.non_finite`: a path it drops, or leaves with
+ * fewer than 2 points, produces an empty result.
+ *
+ * # Safety
+ * - `points` must either be null with `count == 0` or point to `count` consecutive `f32` values.
+ * - `output` must be a valid pointer to a `FlatF32ShapesBuffer`.
+ */
+enum IShapeStatus ishape_stroke_f32_contour_to_flat(const float *points,
+                                                    size_t count,
+                                                    bool is_closed_path,
+                                                    struct FfiStrokeStyle style,
+                                                    struct FlatF32ShapesBuffer *output);
+
+/**
+ * Builds a stroke/buffer result from a single contour and writes it into a flat `f32` buffer,
+ * using the provided [`FfiStrokeStyle`] with custom start and end cap templates.
+ *
+ * A cap template is a flat `[x0, y0, x1, y1, ...]` point array relative to the path end, in
+ * units of half the stroke width: `+x` points away from the path and the cap runs from the
+ * `y = -1` side to the `y = +1` side, so `[1, -1, 1, 1]` reproduces a square cap. An empty
+ * template keeps the corresponding cap of `style`.
+ *
+ * Returns [`IShapeStatus::OddCoordinateCount`] or [`IShapeStatus::InvalidLineCap`] for a
+ * malformed template, and the same errors as [`ishape_stroke_f32_contour_to_flat_styled`].
+ *
+ * # Safety
+ * - `points` must either be null with `count == 0` or point to `count` consecutive `f32` values.
+ * - `start_cap_points` and `end_cap_points` must either be null with a zero count or point to
+ *   the given number of consecutive `f32` values.
+ * - `output` must be a valid pointer to a `FlatF32ShapesBuffer`.
+ */
+enum IShapeStatus ishape_stroke_f32_contour_to_flat_custom_caps(const float *points,
+                                                                size_t count,
+                                                                bool is_closed_path,
+                                                                struct FfiStrokeStyle style,
+                                                                const float *start_cap_points,
+                                                                size_t start_cap_count,
+                                                                const float *end_cap_points,
+                                                                size_t end_cap_count,
+                                                                struct FlatF32ShapesBuffer *output);
+
+/**
+ * Builds a single unioned stroke/buffer result from many paths and writes it into a flat `f32`
+ * buffer, using explicit line join and line cap styles shared by every path.
+ *
+ * - `points` holds the coordinates of all paths as `[x0, y0, x1, y1, ...]`.
+ * - `path_ranges[i]` is the coordinate span of path `i` inside `points`, using the same layout as
+ *   the contour ranges of [`FlatF32ShapesBuffer`].
+ * - `closed_flags[i]` tells whether path `i` is closed.
+ * - `join_kind`, `start_cap_kind` and `end_cap_kind` are encoded as in
+ *   [`ishape_stroke_f32_contour_to_flat_styled`].
+ *
+ * Returns [`IShapeStatus::InvalidRange`] when a path range is reversed, out of bounds or not
+ * aligned to a point, [`IShapeStatus::TooFewPoints`] when a path has fewer than 2 points, and the
+ * same style errors as [`ishape_stroke_f32_contour_to_flat_styled`]. A NaN or infinite
+ * coordinate yields [`IShapeStatus::NonFiniteValue`]. With `paths_count == 0` the output is
+ * cleared.
+ *
+ * # Safety
+ * - `points` must either be null with `points_count == 0` or point to `points_count`
+ *   consecutive `f32` values.
+ * - `path_ranges` and `closed_flags` must either be null with `paths_count == 0` or point to
+ *   `paths_count` consecutive values.
+ * - `output` must be a valid pointer to a `FlatF32ShapesBuffer`.
+ */
+enum IShapeStatus ishape_stroke_f32_paths_to_flat_styled(const float *points,
+                                                         size_t points_count,
+                                                         const struct RangeFFI *path_ranges,
+                                                         const bool *closed_flags,
+                                                         size_t paths_count,
+                                                         float width,
+                                                         uint32_t join_kind,
+                                                         float join_value,
+                                                         uint32_t start_cap_kind,
+                                                         float start_cap_value,
+                                                         uint32_t end_cap_kind,
+                                                         float end_cap_value,
+                                                         struct FlatF32ShapesBuffer *output);
+
+/**
+ * Builds a single unioned stroke/buffer result from many paths and writes it into a flat `f32`
+ * buffer, using the provided [`FfiStrokeStyle`] for every path.
+ *
+ * Paths are described as in [`ishape_stroke_f32_paths_to_flat_styled`], which also lists the
+ * returned errors. Points with a NaN or infinite coordinate follow `style.non_finite`, and
+ * paths it drops are skipped.
+ *
+ * # Safety
+ * - `points` must either be null with `points_count == 0` or point to `points_count`
+ *   consecutive `f32` values.
+ * - `path_ranges` and `closed_flags` must either be null with `paths_count == 0` or point to
+ *   `paths_count` consecutive values.
+ * - `output` must be a valid pointer to a `FlatF32ShapesBuffer`.
+ */
+enum IShapeStatus ishape_stroke_f32_paths_to_flat(const float *points,
+                                                  size_t points_count,
+                                                  const struct RangeFFI *path_ranges,
+                                                  const bool *closed_flags,
+                                                  size_t paths_count,
+                                                  struct FfiStrokeStyle style,
+                                                  struct FlatF32ShapesBuffer *output);
+
+/**
+ * Builds a single unioned stroke/buffer result from many paths and writes it into a flat `f32`
+ * buffer, using the provided [`FfiStrokeStyle`] with custom start and end cap templates.
+ *
+ * Paths are described as in [`ishape_stroke_f32_paths_to_flat_styled`] and cap templates as in
+ * [`ishape_stroke_f32_contour_to_flat_custom_caps`]; every open path uses the same templates.
+ *
+ * # Safety
+ * - `points` must either be null with `points_count == 0` or point to `points_count`
+ *   consecutive `f32` values.
+ * - `path_ranges` and `closed_flags` must either be null with `paths_count == 0` or point to
+ *   `paths_count` consecutive values.
+ * - `start_cap_points` and `end_cap_points` must either be null with a zero count or point to
+ *   the given number of consecutive `f32` values.
+ * - `output` must be a valid pointer to a `FlatF32ShapesBuffer`.
+ */
+enum IShapeStatus ishape_stroke_f32_paths_to_flat_custom_caps(const float *points,
+                                                              size_t points_count,
+                                                              const struct RangeFFI *path_ranges,
+                                                              const bool *closed_flags,
+                                                              size_t paths_count,
+                                                              struct FfiStrokeStyle style,
+                                                              const float *start_cap_points,
+                                                              size_t start_cap_count,
+                                                              const float *end_cap_points,
+                                                              size_t end_cap_count,
+                                                              struct FlatF32ShapesBuffer *output);
+
+/**
+ * Builds a stroke/buffer result from a single contour and writes it into caller-provided flat
+ * `f32` arrays, using the provided [`FfiStrokeStyle`].
+ *
+ * Input errors follow [`ishape_stroke_f32_contour_to_flat`] and output sizing follows
+ * [`ishape_outline_f32_contour_to_slices`].
+ *
+ * # Safety
+ * - `points` must either be null with `count == 0` or point to `count` consecutive `f32` values.
+ * - `output` must be a valid pointer to a `FlatF32ShapesSlices` whose arrays are either null with zero
+ *   capacity or valid for writes of their capacity.
+ */
+enum IShapeStatus ishape_stroke_f32_contour_to_slices(const float *points,
+                                                      size_t count,
+                                                      bool is_closed_path,
+                                                      struct FfiStrokeStyle style,
+                                                      struct FlatF32ShapesSlices *output);
+
+/**
+ * Builds a single unioned stroke/buffer result from many paths and writes it into
+ * caller-provided flat `f32` arrays, using the provided [`FfiStrokeStyle`] for every path.
+ *
+ * Paths and input errors follow [`ishape_stroke_f32_paths_to_flat`] and output sizing follows
+ * [`ishape_outline_f32_contour_to_slices`].
+ *
+ * # Safety
+ * - `points` must either be null with `points_count == 0` or point to `points_count`
+ *   consecutive `f32` values.
+ * - `path_ranges` and `closed_flags` must either be null with `paths_count == 0` or point to
+ *   `paths_count` consecutive values.
+ * - `output` must be a valid pointer to a `FlatF32ShapesSlices` whose arrays are either null with zero
+ *   capacity or valid for writes of their capacity.
+ */
+enum IShapeStatus ishape_stroke_f32_paths_to_slices(const float *points,
+                                                    size_t points_count,
+                                                    const struct RangeFFI *path_ranges,
+                                                    const bool *closed_flags,
+                                                    size_t paths_count,
+                                                    struct FfiStrokeStyle style,
+                                                    struct FlatF32ShapesSlices *output);
+
+/**
+ * Creates a new integer triangulator configured for up to `max_points_count` points.
+ *
+ * # Safety
+ * The returned pointer must be released with [`ishape_triangle_int_triangulator_free`].
+ */
+struct IntTriangulator *ishape_triangle_int_triangulator_create(size_t max_points_count,
+                                                                struct IntTriangulatorValidation validation);
+
+/**
+ * Releases a triangulator previously created with [`ishape_triangle_int_triangulator_create`].
+ *
+ * Passing a null pointer is a no-op.
+ *
+ * # Safety
+ * The pointer must have been returned by [`ishape_triangle_int_triangulator_create`] and not
+ * freed already.
+ */
+void ishape_triangle_int_triangulator_free(struct IntTriangulator *handle);
+
+/**
+ * Runs the configured integer triangulator, writing the result into a flat buffer.
+ *
+ * Returns [`IShapeStatus::NullPointer`] if any pointer is null, and
+ * [`IShapeStatus::OddCoordinateCount`] or [`IShapeStatus::InvalidRange`] when the shapes buffer
+ * layout is malformed; otherwise the output buffer is populated and [`IShapeStatus::Ok`] is
+ * returned.
+ *
+ * # Safety
+ * All pointers must be valid and uniquely owned for the duration of the call.
+ */
+enum IShapeStatus ishape_triangle_int_triangulator_triangulate_flat(struct IntTriangulator *handle,
+                                                                    const struct FlatShapesBuffer *shapes,
+                                                                    struct FlatIntTriangulation *output);
+
+/**
+ * Runs the configured integer triangulator, writing the result into caller-provided arrays.
+ *
+ * Input errors follow [`ishape_triangle_int_triangulator_triangulate_flat`]. Every `*_len`
+ * field of `output` receives the length the result needs, and
+ * [`IShapeStatus::InsufficientCapacity`] is returned without writing the arrays when any
+ * capacity is too small.
+ *
+ * # Safety
+ * - `handle` must be a valid pointer obtained from
+ *   [`ishape_triangle_int_triangulator_create`].
+ * - `shapes` must be a valid pointer to a `FlatShapesBuffer`.
+ * - `output` must be a valid pointer to a `FlatIntTriangulationSlices` whose arrays are either null with zero
+ *   capacity or valid for writes of their capacity.
+ */
+enum IShapeStatus ishape_triangle_int_triangulator_triangulate_into_slices(struct IntTriangulator *handle,
+                                                                           const struct FlatShapesBuffer *shapes,
+                                                                           struct FlatIntTriangulationSlices *output);
+
+/**
+ * Creates a new `f32` triangulator configured for up to `max_points_count` points.
+ *
+ * Input points with a NaN or infinite coordinate are rejected; use
+ * [`ishape_triangle_f32_triangulator_create_with_options`] to choose another policy.
+ *
+ * # Safety
+ * The returned pointer must be released with [`ishape_triangle_f32_triangulator_free`].
+ */
+struct Float32Triangulator *ishape_triangle_f32_triangulator_create(size_t max_points_count,
+                                                                    struct IntTriangulatorValidation validation);
+
+/**
+ * Creates a new `f32` triangulator configured for up to `max_points_count` points, with the
+ * validation and non-finite policy given in `options`.
+ *
+ * # Safety
+ * The returned pointer must be released with [`ishape_triangle_f32_triangulator_free`].
+ */
+struct Float32Triangulator *ishape_triangle_f32_triangulator_create_with_options(size_t max_points_count,
+                                                                                 struct FloatTriangulatorOptions options);
+
+/**
+ * Releases a triangulator previously created with [`ishape_triangle_f32_triangulator_create`]
+ * or [`ishape_triangle_f32_triangulator_create_with_options`].
+ *
+ * Passing a null pointer is a no-op.
+ *
+ * # Safety
+ * The pointer must have been returned by one of those functions and not freed already.
+ */
+void ishape_triangle_f32_triangulator_free(struct Float32Triangulator *handle);
+
+/**
+ * Runs the configured `f32` triangulator, writing the result into a flat buffer.
+ *
+ * Points with a NaN or infinite coordinate follow the `non_finite` option the triangulator was
+ * created with; a shape whose outer contour is dropped is dropped together
+ * with its holes.
+ *
+ * Returns [`IShapeStatus::NullPointer`] if any pointer is null,
+ * [`IShapeStatus::OddCoordinateCount`] or [`IShapeStatus::InvalidRange`] when the shapes buffer
+ * layout is malformed, and [`IShapeStatus::NonFiniteValue`] when a non-finite point is
+ * rejected; otherwise the output buffer is populated and [`IShapeStatus::Ok`] is returned.
+ *
+ * # Safety
+ * All pointers must be valid and uniquely owned for the duration of the call.
+ */
+enum IShapeStatus ishape_triangle_f32_triangulator_triangulate_flat(struct Float32Triangulator *handle,
+                                                                    const struct FlatF32ShapesBuffer *shapes,
+                                                                    struct FlatF32Triangulation *output);
+
+/**
+ * Runs the configured `f32` triangulator, writing the result into caller-provided arrays.
+ *
+ * Input errors follow [`ishape_triangle_f32_triangulator_triangulate_flat`]. Every `*_len`
+ * field of `output` receives the length the result needs, and
+ * [`IShapeStatus::InsufficientCapacity`] is returned without writing the arrays when any
+ * capacity is too small.
+ *
+ * # Safety
+ * - `handle` must be a valid pointer obtained from
+ *   [`ishape_triangle_f32_triangulator_create`].
+ * - `shapes` must be a valid pointer to a `FlatF32ShapesBuffer`.
+ * - `output` must be a valid pointer to a `FlatF32TriangulationSlices` whose arrays are either null with zero
+ *   capacity or valid for writes of their capacity.
+ */
+enum IShapeStatus ishape_triangle_f32_triangulator_triangulate_into_slices(struct Float32Triangulator *handle,
+                                                                           const struct FlatF32ShapesBuffer *shapes,
+                                                                           struct FlatF32TriangulationSlices *output);
+
+/**
+ * Creates a new `f64` triangulator configured for up to `max_points_count` points.
+ *
+ * Input points with a NaN or infinite coordinate are rejected; use
+ * [`ishape_triangle_f64_triangulator_create_with_options`] to choose another policy.
+ *
+ * # Safety
+ * The returned pointer must be released with [`ishape_triangle_f64_triangulator_free`].
+ */
+struct Float64Triangulator *ishape_triangle_f64_triangulator_create(size_t max_points_count,
+                                                                    struct IntTriangulatorValidation validation);
+
+/**
+ * Creates a new `f64` triangulator configured for up to `max_points_count` points, with the
+ * validation and non-finite policy given in `options`.
+ *
+ * # Safety
+ * The returned pointer must be released with [`ishape_triangle_f64_triangulator_free`].
+ */
+struct Float64Triangulator *ishape_triangle_f64_triangulator_create_with_options(size_t max_points_count,
+                                                                                 struct FloatTriangulatorOptions options);
+
+/**
+ * Releases a triangulator previously created with [`ishape_triangle_f64_triangulator_create`]
+ * or [`ishape_triangle_f64_triangulator_create_with_options`].
+ *
+ * Passing a null pointer is a no-op.
+ *
+ * # Safety
+ * The pointer must have been returned by one of those functions and not freed already.
+ */
+void ishape_triangle_f64_triangulator_free(struct Float64Triangulator *handle);
+
+/**
+ * Runs the configured `f64` triangulator, writing the result into a flat buffer.
+ *
+ * Points with a NaN or infinite coordinate follow the `non_finite` option the triangulator was
+ * created with; a shape whose outer contour is dropped is dropped together
+ * with its holes.
+ *
+ * Returns [`IShapeStatus::NullPointer`] if any pointer is null,
+ * [`IShapeStatus::OddCoordinateCount`] or [`IShapeStatus::InvalidRange`] when the shapes buffer
+ * layout is malformed, and [`IShapeStatus::NonFiniteValue`] when a non-finite point is
+ * rejected; otherwise the output buffer is populated and [`IShapeStatus::Ok`] is returned.
+ *
+ * # Safety
+ * All pointers must be valid and uniquely owned for the duration of the call.
+ */
+enum IShapeStatus ishape_triangle_f64_triangulator_triangulate_flat(struct Float64Triangulator *handle,
+                                                                    const struct FlatF64ShapesBuffer *shapes,
+                                                                    struct FlatF64Triangulation *output);
+
+/**
+ * Runs the configured `f64` triangulator, writing the result into caller-provided arrays.
+ *
+ * Input errors follow [`ishape_triangle_f64_triangulator_triangulate_flat`]. Every `*_len`
+ * field of `output` receives the length the result needs, and
+ * [`IShapeStatus::InsufficientCapacity`] is returned without writing the arrays when any
+ * capacity is too small.
+ *
+ * # Safety
+ * - `handle` must be a valid pointer obtained from
+ *   [`ishape_triangle_f64_triangulator_create`].
+ * - `shapes` must be a valid pointer to a `FlatF64ShapesBuffer`.
+ * - `output` must be a valid pointer to a `FlatF64TriangulationSlices` whose arrays are either null with zero
+ *   capacity or valid for writes of their capacity.
+ */
+enum IShapeStatus ishape_triangle_f64_triangulator_triangulate_into_slices(struct Float64Triangulator *handle,
+                                                                           const struct FlatF64ShapesBuffer *shapes,
+                                                                           struct FlatF64TriangulationSlices *output);
+
+#ifdef __cplusplus
+}  // extern "C"
+#endif  // __cplusplus
+
+#endif  /* ISHAPE_H */
@@ -0,0 +1,337 @@
+//! Memory for the handles and buffers handed out to the host.
+//!
+//! Every handle returned by an `ishape_*_create` entry point, and the storage of every flat
+//! buffer, is allocated through this module. With the `host-allocator` feature,
+//! `ishape_set_allocator` installs host memory functions for them. Each handle and buffer keeps
+//! the functions that were installed when it was created and releases its memory through them,
+//! so hooks can be replaced at any time. Scratch memory used by the geometry crates while an
+//! operation runs still comes from the global allocator.
+//!
+//! Without the feature, everything uses the global allocator.
+
+use allocator_api2::alloc::{AllocError, Allocator, Global};
+#[cfg(feature = "host-allocator")]
+use allocator_api2::boxed::Box;
+use core::alloc::Layout;
+#[cfg(feature = "host-allocator")]
+use core::ffi::c_void;
+#[cfg(feature = "host-allocator")]
+use core::ptr;
+use core::ptr::NonNull;
+#[cfg(not(feature = "host-allocator"))]
+use std::boxed::Box;
+#[cfg(feature = "host-allocator")]
+use std::sync::{PoisonError, RwLock};
+
+/// Growable array backing the storage of the flat buffers.
+///
+/// It is the same type with and without the `host-allocator` feature, so enabling the feature
+/// leaves the public fields of the buffers unchanged.
+pub type HostVec<T> = allocator_api2::vec::Vec<T, HostAllocator>;
+
+/// Returns an empty array that allocates through the hooks installed right now.
+#[inline]
+pub(crate) fn host_vec<T>() -> HostVec<T> {
+    HostVec::new_in(HostAllocator::current())
+}
+
+/// Moves `value` into a new allocation and returns it as a handle for the host.
+///
+/// Returns null when the host allocator is out of memory.
+#[inline]
+pub(crate) fn into_handle<T>(value: T) -> *mut T {
+    #[cfg(feature = "host-allocator")]
+    {
+        let allocator = HostAllocator::current();
+        match Box::try_new_in(Handle { value, allocator }, allocator) {
+            Ok(handle) => Box::into_raw(handle).cast(),
+            Err(AllocError) => ptr::null_mut(),
+        }
+    }
+    #[cfg(not(feature = "host-allocator"))]
+    {
+        Box::into_raw(Box::new(value))
+    }
+}
+
+/// Drops a handle created by [`into_handle`] and releases its memory.
+///
+/// # Safety
+/// `handle` must come from [`into_handle`] with the same `T` and not have been freed already.
+#[inline]
+pub(crate) unsafe fn free_handle<T>(handle: *mut T) {
+    #[cfg(feature = "host-allocator")]
+    {
+        let handle = handle.cast::<Handle<T>>();
+        let allocator = unsafe { (*handle).allocator };
+        drop(unsafe { Box::from_raw_in(handle, allocator) });
+    }
+    #[cfg(not(feature = "host-allocator"))]
+    {
+        drop(unsafe { Box::from_raw(handle) });
+    }
+}
+
+/// Allocates `size` bytes aligned to `align`, returning null on failure.
+#[cfg(feature = "host-allocator")]
+pub type IShapeAllocFn =
+    Option<unsafe extern "C" fn(size: usize, align: usize, user_data: *mut c_void) -> *mut c_void>;
+
+/// Resizes a block from `old_size` to `new_size` bytes keeping `align`, returning null on failure.
+#[cfg(feature = "host-allocator")]
+pub type IShapeReallocFn = Option<
+    unsafe extern "C" fn(
+        ptr: *mut c_void,
+        old_size: usize,
+        align: usize,
+        new_size: usize,
+        user_data: *mut c_void,
+    ) -> *mut c_void,
+>;
+
+/// Releases a block previously returned by the matching alloc or realloc function.
+#[cfg(feature = "host-allocator")]
+pub type IShapeFreeFn = Option<
+    unsafe extern "C" fn(ptr: *mut c_void, size: usize, align: usize, user_data: *mut c_void),
+>;
+
+/// Errors that can occur when installing allocator hooks.
+#[cfg(feature = "host-allocator")]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SetAllocatorError {
+    /// Only one of the alloc and free functions is null.
+    MissingFunction,
+}
+
+#[cfg(feature = "host-allocator")]
+#[derive(Debug, Clone, Copy)]
+struct Hooks {
+    alloc: unsafe extern "C" fn(usize, usize, *mut c_void) -> *mut c_void,
+    realloc: IShapeReallocFn,
+    free: unsafe extern "C" fn(*mut c_void, usize, usize, *mut c_void),
+    user_data: *mut c_void,
+}
+
+// SAFETY: `ishape_set_allocator` requires the functions to be callable from any thread with
+// `user_data`.
+#[cfg(feature = "host-allocator")]
+unsafe impl Send for Hooks {}
+#[cfg(feature = "host-allocator")]
+unsafe impl Sync for Hooks {}
+
+#[cfg(feature = "host-allocator")]
+static HOOKS: RwLock<Option<Hooks>> = RwLock::new(None);
+
+/// Installs host memory functions for handles and buffers created from now on, or restores the
+/// global allocator when both `alloc` and `free` are `None`.
+#[cfg(feature = "host-allocator")]
+pub(crate) fn set_allocator(
+    alloc: IShapeAllocFn,
+    realloc: IShapeReallocFn,
+    free: IShapeFreeFn,
+    user_data: *mut c_void,
+) -> Result<(), SetAllocatorError> {
+    let hooks = match (alloc, free) {
+        (Some(alloc), Some(free)) => Some(Hooks {
+            alloc,
+            realloc,
+            free,
+            user_data,
+        }),
+        (None, None) => None,
+        _ => return Err(SetAllocatorError::MissingFunction),
+    };
+
+    *HOOKS.write().unwrap_or_else(PoisonError::into_inner) = hooks;
+
+    Ok(())
+}
+
+/// Allocator that forwards to the host functions captured when it was created, or to the global
+/// allocator when none were installed or the `host-allocator` feature is off.
+#[derive(Debug, Clone, Copy)]
+pub struct HostAllocator {
+    #[cfg(feature = "host-allocator")]
+    hooks: Option<Hooks>,
+}
+
+impl HostAllocator {
+    /// Captures the hooks installed right now.
+    #[inline]
+    pub fn current() -> Self {
+        #[cfg(feature = "host-allocator")]
+        {
+            let hooks = *HOOKS.read().unwrap_or_else(PoisonError::into_inner);
+            Self { hooks }
+        }
+        #[cfg(not(feature = "host-allocator"))]
+        {
+            Self {}
+        }
+    }
+}
+
+#[cfg(feature = "host-allocator")]
+impl HostAllocator {
+    /// Moves a block into a new allocation, for hooks without a realloc function.
+    unsafe fn reallocate(
+        &self,
+        ptr: NonNull<u8>,
+        old_layout: Layout,
+        new_layout: Layout,
+    ) -> Result<NonNull<[u8]>, AllocError> {
+        let new_ptr = self.allocate(new_layout)?;
+        unsafe {
+            let size = old_layout.size().min(new_layout.size());
+            ptr::copy_nonoverlapping(ptr.as_ptr(), new_ptr.cast::<u8>().as_ptr(), size);
+            self.deallocate(ptr, old_layout);
+        }
+        Ok(new_ptr)
+    }
+
+    /// Resizes a non-empty block with the host's realloc function when it has one and the
+    /// alignment is unchanged.
+    #[inline]
+    unsafe fn resize(
+        &self,
+        ptr: NonNull<u8>,
+        old_layout: Layout,
+        new_layout: Layout,
+    ) -> Result<NonNull<[u8]>, AllocError> {
+        let Some(hooks) = self.hooks else {
+            return if new_layout.size() >= old_layout.size() {
+                unsafe { Global.grow(ptr, old_layout, new_layout) }
+            } else {
+                unsafe { Global.shrink(ptr, old_layout, new_layout) }
+            };
+        };
+
+        match hooks.realloc {
+            Some(realloc) if old_layout.size() != 0 && old_layout.align() == new_layout.align() => {
+                let new_ptr = unsafe {
+                    realloc(
+                        ptr.as_ptr().cast(),
+                        old_layout.size(),
+                        old_layout.align(),
+                        new_layout.size(),
+                        hooks.user_data,
+                    )
+                };
+                NonNull::new(new_ptr.cast::<u8>())
+                    .map(|ptr| NonNull::slice_from_raw_parts(ptr, new_layout.size()))
+                    .ok_or(AllocError)
+            }
+            _ => unsafe { self.reallocate(ptr, old_layout, new_layout) },
+        }
+    }
+}
+
+/// A handle and the allocator that owns its memory; `value` comes first so a pointer to the
+/// handle is also a pointer to the value.
+#[cfg(feature = "host-allocator")]
+#[repr(C)]
+struct Handle<T> {
+    value: T,
+    allocator: HostAllocator,
+}
+
+#[cfg(feature = "host-allocator")]
+#[inline]
+fn dangling(layout: Layout) -> NonNull<[u8]> {
+    // SAFETY: alignments are never zero.
+    let ptr = unsafe { NonNull::new_unchecked(ptr::without_provenance_mut(layout.align())) };
+    NonNull::slice_from_raw_parts(ptr, 0)
+}
+
+#[cfg(feature = "host-allocator")]
+unsafe impl Allocator for HostAllocator {
+    #[inline]
+    fn allocate(&self, layout: Layout) -> Result<NonNull<[u8]>, AllocError> {
+        let Some(hooks) = self.hooks else {
+            return Global.allocate(layout);
+        };
+
+        if layout.size() == 0 {
+            return Ok(dangling(layout));
+        }
+
+        let ptr = unsafe { (hooks.alloc)(layout.size(), layout.align(), hooks.user_data) };
+        NonNull::new(ptr.cast::<u8>())
+            .map(|ptr| NonNull::slice_from_raw_parts(ptr, layout.size()))
+            .ok_or(AllocError)
+    }
+
+    #[inline]
+    unsafe fn deallocate(&self, ptr: NonNull<u8>, layout: Layout) {
+        match self.hooks {
+            None => unsafe { Global.deallocate(ptr, layout) },
+            Some(hooks) if layout.size() != 0 => unsafe {
+                (hooks.free)(
+                    ptr.as_ptr().cast(),
+                    layout.size(),
+                    layout.align(),
+                    hooks.user_data,
+                )
+            },
+            Some(_) => {}
+        }
+    }
+
+    #[inline]
+    unsafe fn grow(
+        &self,
+        ptr: NonNull<u8>,
+        old_layout: Layout,
+        new_layout: Layout,
+    ) -> Result<NonNull<[u8]>, AllocError> {
+        unsafe { self.resize(ptr, old_layout, new_layout) }
+    }
+
+    #[inline]
+    unsafe fn shrink(
+        &self,
+        ptr: NonNull<u8>,
+        old_layout: Layout,
+        new_layout: Layout,
+    ) -> Result<NonNull<[u8]>, AllocError> {
+        if new_layout.size() == 0 {
+            unsafe { self.deallocate(ptr, old_layout) };
+            return Ok(dangling(new_layout));
+        }
+
+        unsafe { self.resize(ptr, old_layout, new_layout) }
+    }
+}
+
+#[cfg(not(feature = "host-allocator"))]
+unsafe impl Allocator for HostAllocator {
+    #[inline]
+    fn allocate(&self, layout: Layout) -> Result<NonNull<[u8]>, AllocError> {
+        Global.allocate(layout)
+    }
+
+    #[inline]
+    unsafe fn deallocate(&self, ptr: NonNull<u8>, layout: Layout) {
+        unsafe { Global.deallocate(ptr, layout) }
+    }
+
+    #[inline]
+    unsafe fn grow(
+        &self,
+        ptr: NonNull<u8>,
+        old_layout: Layout,
+        new_layout: Layout,
+    ) -> Result<NonNull<[u8]>, AllocError> {
+        unsafe { Global.grow(ptr, old_layout, new_layout) }
+    }
+
+    #[inline]
+    unsafe fn shrink(
+        &self,
+        ptr: NonNull<u8>,
+        old_layout: Layout,
+        new_layout: Layout,
+    ) -> Result<NonNull<[u8]>, AllocError> {
+        unsafe { Global.shrink(ptr, old_layout, new_layout) }
+    }
+}
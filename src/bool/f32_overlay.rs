@@ -0,0 +1,150 @@
+use super::types::Float32OverlayOptions as FfiOverlayOptions;
+use i_triangle::i_overlay::core::fill_rule::FillRule;
+use i_triangle::i_overlay::core::overlay::{IntOverlayOptions, Overlay, ShapeType};
+use i_triangle::i_overlay::core::overlay_rule::OverlayRule;
+use i_triangle::i_overlay::core::solver::Solver;
+use i_triangle::i_overlay::i_float::adapter::FloatPointAdapter;
+use i_triangle::i_overlay::i_float::float::point::FloatPoint;
+use i_triangle::i_overlay::i_shape::base::data::Shapes;
+use i_triangle::i_overlay::i_shape::float::adapter::ShapesToFloat;
+use i_triangle::i_overlay::i_shape::float::despike::DeSpikeContour;
+use i_triangle::i_overlay::i_shape::float::simple::SimplifyContour;
+use i_triangle::i_overlay::i_shape::source::resource::ShapeResource;
+
+use crate::shape::{FlatContours, NonFiniteError};
+
+type Float32Point = FloatPoint<f32>;
+type Float32Shapes = Shapes<Float32Point>;
+
+/// Wrapper around floating overlay that keeps contours until execution.
+///
+/// The contours and the core overlay are kept between calls, so one handle can run any number
+/// of operations and be cleared and refilled while reusing its memory.
+pub struct Float32Overlay {
+    subject: FlatContours<f32>,
+    clip: FlatContours<f32>,
+    inner: Overlay,
+    options: FfiOverlayOptions,
+    contours_count: usize,
+}
+
+/// Errors that can occur when converting raw coordinate buffers into contours.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum AddContourError {
+    /// The coordinate buffer length is not a multiple of two.
+    OddCoordinateCount,
+    /// A point has a non-finite coordinate and the options reject such input.
+    NonFinite(NonFiniteError),
+}
+
+impl Float32Overlay {
+    /// Constructs a new floating overlay with an optional contour capacity hint.
+    #[inline]
+    pub fn new(capacity: usize, options: FfiOverlayOptions) -> Self {
+        let solver = Solver {
+            multithreading: None,
+            ..Default::default()
+        };
+
+        Self {
+            subject: FlatContours::with_capacity(capacity),
+            clip: FlatContours::with_capacity(capacity),
+            inner: Overlay::new_custom(0, IntOverlayOptions::default(), solver),
+            options,
+            contours_count: 0,
+        }
+    }
+
+    /// Adds a contour described by `points` with the specified shape role.
+    ///
+    /// Non-finite points are handled according to the `non_finite` option, which may drop the
+    /// contour.
+    #[inline]
+    pub fn add_contour(
+        &mut self,
+        points: &[f32],
+        shape_type: ShapeType,
+    ) -> Result<(), AddContourError> {
+        if !points.len().is_multiple_of(2) {
+            return Err(AddContourError::OddCoordinateCount);
+        }
+
+        if !points.is_empty() {
+            let contours = match shape_type {
+                ShapeType::Subject => &mut self.subject,
+                ShapeType::Clip => &mut self.clip,
+            };
+
+            contours
+                .push_coords(points, self.options.non_finite, self.contours_count)
+                .map_err(AddContourError::NonFinite)?;
+        }
+
+        self.contours_count += 1;
+
+        Ok(())
+    }
+
+    /// Returns the number of contours accepted so far, across subject and clip.
+    ///
+    /// Empty contours and contours dropped by the `non_finite` option are counted too, so the
+    /// value matches the number of successful `add_contour` calls.
+    #[inline]
+    pub fn contours_count(&self) -> usize {
+        self.contours_count
+    }
+
+    /// Removes every queued contour while keeping the allocated memory.
+    #[inline]
+    pub fn clear(&mut self) {
+        self.subject.clear();
+        self.clip.clear();
+        self.inner.clear();
+        self.contours_count = 0;
+    }
+
+    /// Executes the boolean operation and returns the resulting shapes.
+    ///
+    /// The queued contours are left in place, so the overlay can run again with another rule.
+    pub fn overlay(&mut self, overlay_rule: OverlayRule, fill_rule: FillRule) -> Float32Shapes {
+        let points = self
+            .subject
+            .iter_paths()
+            .chain(self.clip.iter_paths())
+            .flatten();
+        let adapter = FloatPointAdapter::with_iter(points);
+
+        // Mirrors `FloatOverlay`, which cannot be refilled without losing the scaled options.
+        self.inner.clear();
+        self.inner.options = IntOverlayOptions {
+            preserve_input_collinear: self.options.preserve_input_collinear,
+            output_direction: self.options.output_direction.into(),
+            preserve_output_collinear: self.options.preserve_output_collinear,
+            min_output_area: adapter.sqr_float_to_int(self.options.min_output_area),
+        };
+        for (contours, shape_type) in [
+            (&self.subject, ShapeType::Subject),
+            (&self.clip, ShapeType::Clip),
+        ] {
+            for contour in contours.iter_paths() {
+                let iter = contour.iter().map(|point| adapter.float_to_int(point));
+                self.inner.add_path_iter(iter, shape_type);
+            }
+        }
+
+        let mut shapes = self
+            .inner
+            .overlay(overlay_rule, fill_rule)
+            .to_float(&adapter);
+
+        if self.options.clean_result {
+            if self.options.preserve_output_collinear {
+                shapes.despike_contour(&adapter);
+            } else {
+                shapes.simplify_contour(&adapter);
+            }
+        }
+
+        shapes
+    }
+}
@@ -1,24 +1,31 @@
-use alloc::vec::Vec;
-
 use super::types::Float64OverlayOptions as FfiOverlayOptions;
 use i_triangle::i_overlay::core::fill_rule::FillRule;
-use i_triangle::i_overlay::core::overlay::ShapeType;
+use i_triangle::i_overlay::core::overlay::{IntOverlayOptions, Overlay, ShapeType};
 use i_triangle::i_overlay::core::overlay_rule::OverlayRule;
 use i_triangle::i_overlay::core::solver::Solver;
-use i_triangle::i_overlay::float::overlay::FloatOverlay;
+use i_triangle::i_overlay::i_float::adapter::FloatPointAdapter;
 use i_triangle::i_overlay::i_float::float::point::FloatPoint;
-use i_triangle::i_overlay::i_shape::base::data::{Contour, Shapes};
+use i_triangle::i_overlay::i_shape::base::data::Shapes;
+use i_triangle::i_overlay::i_shape::float::adapter::ShapesToFloat;
+use i_triangle::i_overlay::i_shape::float::despike::DeSpikeContour;
+use i_triangle::i_overlay::i_shape::float::simple::SimplifyContour;
+use i_triangle::i_overlay::i_shape::source::resource::ShapeResource;
+
+use crate::shape::{FlatContours, NonFiniteError};
 
 type Float64Point = FloatPoint<f64>;
-type Float64Contour = Contour<Float64Point>;
-type Float64Contours = Vec<Float64Contour>;
 type Float64Shapes = Shapes<Float64Point>;
 
 /// Wrapper around floating overlay that keeps contours until execution.
+///
+/// The contours and the core overlay are kept between calls, so one handle can run any number
+/// of operations and be cleared and refilled while reusing its memory.
 pub struct Float64Overlay {
-    subject: Float64Contours,
-    clip: Float64Contours,
+    subject: FlatContours<f64>,
+    clip: FlatContours<f64>,
+    inner: Overlay,
     options: FfiOverlayOptions,
+    contours_count: usize,
 }
 
 /// Errors that can occur when converting raw coordinate buffers into contours.
@@ -26,60 +33,118 @@ pub struct Float64Overlay {
 pub enum AddContourError {
     /// The coordinate buffer length is not a multiple of two.
     OddCoordinateCount,
+    /// A point has a non-finite coordinate and the options reject such input.
+    NonFinite(NonFiniteError),
 }
 
 impl Float64Overlay {
     /// Constructs a new floating overlay with an optional contour capacity hint.
     #[inline]
     pub fn new(capacity: usize, options: FfiOverlayOptions) -> Self {
+        let solver = Solver {
+            multithreading: None,
+            ..Default::default()
+        };
+
         Self {
-            subject: Vec::with_capacity(capacity),
-            clip: Vec::with_capacity(capacity),
+            subject: FlatContours::with_capacity(capacity),
+            clip: FlatContours::with_capacity(capacity),
+            inner: Overlay::new_custom(0, IntOverlayOptions::default(), solver),
             options,
+            contours_count: 0,
         }
     }
 
     /// Adds a contour described by `points` with the specified shape role.
+    ///
+    /// Non-finite points are handled according to the `non_finite` option, which may drop the
+    /// contour.
     #[inline]
     pub fn add_contour(
         &mut self,
         points: &[f64],
         shape_type: ShapeType,
     ) -> Result<(), AddContourError> {
-        if points.len() % 2 != 0 {
+        if !points.len().is_multiple_of(2) {
             return Err(AddContourError::OddCoordinateCount);
         }
 
-        if points.is_empty() {
-            return Ok(());
-        }
+        if !points.is_empty() {
+            let contours = match shape_type {
+                ShapeType::Subject => &mut self.subject,
+                ShapeType::Clip => &mut self.clip,
+            };
 
-        let mut contour = Float64Contour::with_capacity(points.len() / 2);
-        for chunk in points.chunks_exact(2) {
-            contour.push(FloatPoint::new(chunk[0], chunk[1]));
+            contours
+                .push_coords(points, self.options.non_finite, self.contours_count)
+                .map_err(AddContourError::NonFinite)?;
         }
 
-        match shape_type {
-            ShapeType::Subject => self.subject.push(contour),
-            ShapeType::Clip => self.clip.push(contour),
-        }
+        self.contours_count += 1;
 
         Ok(())
     }
 
-    /// Executes the boolean operation and returns the resulting shapes.
+    /// Returns the number of contours accepted so far, across subject and clip.
+    ///
+    /// Empty contours and contours dropped by the `non_finite` option are counted too, so the
+    /// value matches the number of successful `add_contour` calls.
+    #[inline]
+    pub fn contours_count(&self) -> usize {
+        self.contours_count
+    }
+
+    /// Removes every queued contour while keeping the allocated memory.
     #[inline]
-    pub fn overlay(&self, overlay_rule: OverlayRule, fill_rule: FillRule) -> Float64Shapes {
-        let mut solver = Solver::default();
-        solver.multithreading = None;
-
-        let mut overlay = FloatOverlay::with_subj_and_clip_custom(
-            &self.subject,
-            &self.clip,
-            self.options.into(),
-            solver,
-        );
-
-        overlay.overlay(overlay_rule, fill_rule)
+    pub fn clear(&mut self) {
+        self.subject.clear();
+        self.clip.clear();
+        self.inner.clear();
+        self.contours_count = 0;
+    }
+
+    /// Executes the boolean operation and returns the resulting shapes.
+    ///
+    /// The queued contours are left in place, so the overlay can run again with another rule.
+    pub fn overlay(&mut self, overlay_rule: OverlayRule, fill_rule: FillRule) -> Float64Shapes {
+        let points = self
+            .subject
+            .iter_paths()
+            .chain(self.clip.iter_paths())
+            .flatten();
+        let adapter = FloatPointAdapter::with_iter(points);
+
+        // Mirrors `FloatOverlay`, which cannot be refilled without losing the scaled options.
+        self.inner.clear();
+        self.inner.options = IntOverlayOptions {
+            preserve_input_collinear: self.options.preserve_input_collinear,
+            output_direction: self.options.output_direction.into(),
+            preserve_output_collinear: self.options.preserve_output_collinear,
+            min_output_area: adapter.sqr_float_to_int(self.options.min_output_area),
+        };
+        for (contours, shape_type) in [
+            (&self.subject, ShapeType::Subject),
+            (&self.clip, ShapeType::Clip),
+        ] {
+            for contour in contours.iter_paths() {
+                let iter = contour.iter().map(|point| adapter.float_to_int(point));
+                self.inner.add_path_iter(iter, shape_type);
+            }
+        }
+
+        let mut shapes = self
+            .inner
+            .overlay(overlay_rule, fill_rule)
+            .to_float(&adapter);
+
+        if self.options.clean_result {
+            if self.options.preserve_output_collinear {
+                shapes.despike_contour(&adapter);
+            } else {
+                shapes.simplify_contour(&adapter);
+            }
+        }
+
+        shapes
     }
 }
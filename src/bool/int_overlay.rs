@@ -7,8 +7,12 @@ use i_triangle::i_overlay::i_float::int::point::IntPoint;
 use i_triangle::i_overlay::i_shape::int::shape::IntShapes;
 
 /// Wrapper around the integer overlay that provides a stable layout for FFI consumers.
+///
+/// The queued segments and the core's scratch buffers are kept between calls, so one handle can
+/// run any number of operations and be cleared and refilled while reusing its memory.
 pub struct IntOverlay {
     inner: Overlay,
+    contours_count: usize,
 }
 
 /// Errors that can occur when converting raw coordinate buffers into contours.
@@ -25,6 +29,7 @@ impl IntOverlay {
         let solver = Solver::default();
         Self {
             inner: Overlay::new_custom(capacity, options.into(), solver),
+            contours_count: 0,
         }
     }
 
@@ -35,7 +40,7 @@ impl IntOverlay {
         points: &[i32],
         shape_type: ShapeType,
     ) -> Result<(), AddContourError> {
-        if points.len() % 2 != 0 {
+        if !points.len().is_multiple_of(2) {
             return Err(AddContourError::OddCoordinateCount);
         }
 
@@ -44,11 +49,27 @@ impl IntOverlay {
             .map(|chunk| IntPoint::new(chunk[0], chunk[1]));
 
         self.inner.add_path_iter(iter, shape_type);
+        self.contours_count += 1;
 
         Ok(())
     }
 
+    /// Returns the number of contours queued so far, across subject and clip.
+    #[inline]
+    pub fn contours_count(&self) -> usize {
+        self.contours_count
+    }
+
+    /// Removes every queued contour while keeping the allocated memory.
+    #[inline]
+    pub fn clear(&mut self) {
+        self.inner.clear();
+        self.contours_count = 0;
+    }
+
     /// Executes the boolean operation and returns the resulting shapes.
+    ///
+    /// The queued contours are left in place, so the overlay can run again with another rule.
     #[inline]
     pub fn overlay(&mut self, overlay_rule: OverlayRule, fill_rule: FillRule) -> IntShapes {
         self.inner.overlay(overlay_rule, fill_rule)
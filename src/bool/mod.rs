@@ -1,10 +1,12 @@
+pub mod f32_overlay;
 pub mod f64_overlay;
 pub mod int_overlay;
 pub mod types;
 
+pub use f32_overlay::Float32Overlay;
 pub use f64_overlay::Float64Overlay;
 pub use int_overlay::IntOverlay;
 pub use types::{
-    Float64OverlayOptions, IntContourDirection, IntFillRule, IntOverlayOptions, IntOverlayRule,
-    IntShapeType,
+    Float32OverlayOptions, Float64OverlayOptions, IntContourDirection, IntFillRule,
+    IntOverlayOptions, IntOverlayRule, IntShapeType,
 };
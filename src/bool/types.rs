@@ -5,6 +5,8 @@ use i_triangle::i_overlay::core::overlay::{
 use i_triangle::i_overlay::core::overlay_rule::OverlayRule;
 use i_triangle::i_overlay::float::overlay::OverlayOptions as CoreFloatOverlayOptions;
 
+use crate::shape::IShapeNonFinitePolicy;
+
 /// Wrapper enum mirroring `i_overlay::core::overlay::ShapeType` for FFI consumers.
 #[repr(C)]
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
@@ -102,7 +104,8 @@ impl Default for IntOverlayOptions {
     }
 }
 
-/// FFI-safe options struct mirroring `i_overlay::float::overlay::OverlayOptions<f64>`.
+/// FFI-safe options struct mirroring `i_overlay::float::overlay::OverlayOptions<f64>`, plus the
+/// policy for non-finite input coordinates.
 #[repr(C)]
 #[derive(Debug, Clone, Copy, PartialEq)]
 pub struct Float64OverlayOptions {
@@ -111,6 +114,8 @@ pub struct Float64OverlayOptions {
     pub preserve_output_collinear: bool,
     pub min_output_area: f64,
     pub clean_result: bool,
+    /// Applied to every contour passed to `add_contour`.
+    pub non_finite: IShapeNonFinitePolicy,
 }
 
 impl From<Float64OverlayOptions> for CoreFloatOverlayOptions<f64> {
@@ -135,6 +140,7 @@ impl From<CoreFloatOverlayOptions<f64>> for Float64OverlayOptions {
             preserve_output_collinear: value.preserve_output_collinear,
             min_output_area: value.min_output_area,
             clean_result: value.clean_result,
+            non_finite: IShapeNonFinitePolicy::default(),
         }
     }
 }
@@ -146,6 +152,54 @@ impl Default for Float64OverlayOptions {
     }
 }
 
+/// FFI-safe options struct mirroring `i_overlay::float::overlay::OverlayOptions<f32>`, plus the
+/// policy for non-finite input coordinates.
+#[repr(C)]
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Float32OverlayOptions {
+    pub preserve_input_collinear: bool,
+    pub output_direction: IntContourDirection,
+    pub preserve_output_collinear: bool,
+    pub min_output_area: f32,
+    pub clean_result: bool,
+    /// Applied to every contour passed to `add_contour`.
+    pub non_finite: IShapeNonFinitePolicy,
+}
+
+impl From<Float32OverlayOptions> for CoreFloatOverlayOptions<f32> {
+    #[inline]
+    fn from(value: Float32OverlayOptions) -> Self {
+        Self {
+            preserve_input_collinear: value.preserve_input_collinear,
+            output_direction: value.output_direction.into(),
+            preserve_output_collinear: value.preserve_output_collinear,
+            min_output_area: value.min_output_area,
+            clean_result: value.clean_result,
+        }
+    }
+}
+
+impl From<CoreFloatOverlayOptions<f32>> for Float32OverlayOptions {
+    #[inline]
+    fn from(value: CoreFloatOverlayOptions<f32>) -> Self {
+        Self {
+            preserve_input_collinear: value.preserve_input_collinear,
+            output_direction: value.output_direction.into(),
+            preserve_output_collinear: value.preserve_output_collinear,
+            min_output_area: value.min_output_area,
+            clean_result: value.clean_result,
+            non_finite: IShapeNonFinitePolicy::default(),
+        }
+    }
+}
+
+impl Default for Float32OverlayOptions {
+    #[inline]
+    fn default() -> Self {
+        CoreFloatOverlayOptions::<f32>::default().into()
+    }
+}
+
 /// FFI-safe enum mirroring `OverlayRule`.
 #[repr(C)]
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
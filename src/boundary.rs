@@ -0,0 +1,161 @@
+//! Panic containment for the `extern "C"` entry points.
+//!
+//! A panic unwinding out of an `extern "C"` function aborts the process, so every entry point that
+//! runs overlay, mesh or triangulation code, or allocates, executes its body through one of the
+//! guards below. Plain pointer and length accessors cannot panic and are left unwrapped.
+
+use alloc::string::String;
+use core::any::Any;
+use std::boxed::Box;
+use std::panic::{self, AssertUnwindSafe};
+
+use crate::shape::{
+    FlatF32ShapesBuffer, FlatF32ShapesSlices, FlatF64ShapesBuffer, FlatF64ShapesSlices,
+    FlatShapesBuffer, FlatShapesSlices,
+};
+use crate::status::{IShapeStatus, set_last_error};
+use crate::triangle::{
+    FlatF32Triangulation, FlatF32TriangulationSlices, FlatF64Triangulation,
+    FlatF64TriangulationSlices, FlatIntTriangulation, FlatIntTriangulationSlices,
+};
+
+/// Output buffer that is cleared when the call writing into it panics.
+pub(crate) trait FfiOutput {
+    fn clear_output(&mut self);
+}
+
+/// Runs `body`, turning a panic into [`IShapeStatus::Panic`].
+#[inline]
+pub(crate) fn guard_status<F>(body: F) -> IShapeStatus
+where
+    F: FnOnce() -> IShapeStatus,
+{
+    panic::catch_unwind(AssertUnwindSafe(body)).unwrap_or_else(record_panic)
+}
+
+/// Runs `body`, turning a panic into [`IShapeStatus::Panic`] and clearing `output` so the caller
+/// never observes a partially written buffer.
+#[inline]
+pub(crate) fn guard_status_into<B, F>(output: *mut B, body: F) -> IShapeStatus
+where
+    B: FfiOutput,
+    F: FnOnce() -> IShapeStatus,
+{
+    panic::catch_unwind(AssertUnwindSafe(body)).unwrap_or_else(|payload| {
+        if let Some(output) = unsafe { output.as_mut() } {
+            output.clear_output();
+        }
+        record_panic(payload)
+    })
+}
+
+/// Runs `body`, returning `fallback` when it panics.
+#[inline]
+pub(crate) fn guard_or<R, F>(fallback: R, body: F) -> R
+where
+    F: FnOnce() -> R,
+{
+    panic::catch_unwind(AssertUnwindSafe(body)).unwrap_or_else(|payload| {
+        record_panic(payload);
+        fallback
+    })
+}
+
+#[cold]
+fn record_panic(payload: Box<dyn Any + Send>) -> IShapeStatus {
+    let message = if let Some(message) = payload.downcast_ref::<&str>() {
+        message
+    } else if let Some(message) = payload.downcast_ref::<String>() {
+        message.as_str()
+    } else {
+        "unknown panic payload"
+    };
+
+    set_last_error(IShapeStatus::Panic, format_args!("{message}"));
+
+    IShapeStatus::Panic
+}
+
+impl FfiOutput for FlatShapesBuffer {
+    #[inline]
+    fn clear_output(&mut self) {
+        self.clear();
+    }
+}
+
+impl FfiOutput for FlatF32ShapesBuffer {
+    #[inline]
+    fn clear_output(&mut self) {
+        self.clear();
+    }
+}
+
+impl FfiOutput for FlatF64ShapesBuffer {
+    #[inline]
+    fn clear_output(&mut self) {
+        self.clear();
+    }
+}
+
+impl FfiOutput for FlatIntTriangulation {
+    #[inline]
+    fn clear_output(&mut self) {
+        self.clear();
+    }
+}
+
+impl FfiOutput for FlatF32Triangulation {
+    #[inline]
+    fn clear_output(&mut self) {
+        self.clear();
+    }
+}
+
+impl FfiOutput for FlatF64Triangulation {
+    #[inline]
+    fn clear_output(&mut self) {
+        self.clear();
+    }
+}
+
+impl FfiOutput for FlatShapesSlices {
+    #[inline]
+    fn clear_output(&mut self) {
+        self.clear();
+    }
+}
+
+impl FfiOutput for FlatF32ShapesSlices {
+    #[inline]
+    fn clear_output(&mut self) {
+        self.clear();
+    }
+}
+
+impl FfiOutput for FlatF64ShapesSlices {
+    #[inline]
+    fn clear_output(&mut self) {
+        self.clear();
+    }
+}
+
+impl FfiOutput for FlatIntTriangulationSlices {
+    #[inline]
+    fn clear_output(&mut self) {
+        self.clear();
+    }
+}
+
+impl FfiOutput for FlatF32TriangulationSlices {
+    #[inline]
+    fn clear_output(&mut self) {
+        self.clear();
+    }
+}
+
+impl FfiOutput for FlatF64TriangulationSlices {
+    #[inline]
+    fn clear_output(&mut self) {
+        self.clear();
+    }
+}
@@ -1,27 +1,173 @@
+#![allow(clippy::not_unsafe_ptr_arg_deref)]
+
 extern crate alloc;
 
-use alloc::vec::Vec;
-use std::boxed::Box;
+#[cfg(feature = "host-allocator")]
+use core::ffi::c_void;
+use core::ffi::{CStr, c_char};
 use core::{ptr, slice};
+use i_triangle::float::triangulation::Triangulation;
 use i_triangle::i_overlay::i_float::float::point::FloatPoint;
-use i_triangle::i_overlay::mesh::outline::offset::OutlineOffset;
-use i_triangle::i_overlay::mesh::style::{LineCap, LineJoin, OutlineStyle};
-use i_triangle::i_overlay::mesh::stroke::offset::StrokeOffset;
-use i_triangle::i_overlay::mesh::style::StrokeStyle;
+use i_triangle::i_overlay::i_shape::base::data::Shapes;
+use i_triangle::i_overlay::mesh::style::OutlineStyle;
+use i_triangle::int::triangulation::IntTriangulation;
 
+pub mod allocator;
 pub mod bool;
+mod boundary;
+pub mod mesh;
 pub mod shape;
+pub mod status;
 pub mod triangle;
+pub mod version;
 
+#[cfg(feature = "host-allocator")]
+pub use crate::allocator::{IShapeAllocFn, IShapeFreeFn, IShapeReallocFn};
 pub use crate::bool::{
-    Float64Overlay, Float64OverlayOptions, IntContourDirection, IntFillRule, IntOverlay,
-    IntOverlayOptions, IntOverlayRule, IntShapeType,
+    Float32Overlay, Float32OverlayOptions, Float64Overlay, Float64OverlayOptions,
+    IntContourDirection, IntFillRule, IntOverlay, IntOverlayOptions, IntOverlayRule, IntShapeType,
+};
+pub use crate::mesh::{
+    FfiLineCap, FfiLineCapKind, FfiLineJoin, FfiLineJoinKind, FfiOutlineStyle, FfiStrokeStyle,
 };
-pub use crate::shape::{FlatF32ShapesBuffer, FlatF64ShapesBuffer, FlatShapesBuffer, RangeFFI};
+pub use crate::shape::{
+    FlatF32ShapesBuffer, FlatF32ShapesSlices, FlatF32ShapesView, FlatF64ShapesBuffer,
+    FlatF64ShapesSlices, FlatF64ShapesView, FlatShapesBuffer, FlatShapesSlices, FlatShapesView,
+    IShapeNonFinitePolicy, RangeFFI,
+};
+pub use crate::status::IShapeStatus;
 pub use crate::triangle::{
-    FlatF32Triangulation, FlatF64Triangulation, FlatIntTriangulation, Float32Triangulator,
-    Float64Triangulator, IntTriangulationIndex, IntTriangulator, IntTriangulatorValidation,
+    FlatF32Triangulation, FlatF32TriangulationSlices, FlatF32TriangulationView,
+    FlatF64Triangulation, FlatF64TriangulationSlices, FlatF64TriangulationView,
+    FlatIntTriangulation, FlatIntTriangulationSlices, FlatIntTriangulationView,
+    Float32Triangulator, Float64Triangulator, FloatTriangulatorOptions, IntTriangulationIndex,
+    IntTriangulator, IntTriangulatorValidation,
+};
+
+use crate::allocator::{free_handle, into_handle};
+use crate::bool::f32_overlay::AddContourError as Float32AddContourError;
+use crate::bool::f64_overlay::AddContourError as Float64AddContourError;
+use crate::boundary::{guard_or, guard_status, guard_status_into};
+use crate::mesh::input::{FlatPaths, read_cap_template};
+use crate::mesh::outline::{
+    decode_ffi_outline_style, offset_style, outline_contour, outline_shapes, outline_style,
+};
+use crate::mesh::stroke::{stroke_contour, stroke_paths};
+use crate::mesh::style::{
+    decode_custom_caps_stroke_style, decode_ffi_stroke_style, decode_stroke_style,
 };
+use crate::shape::slices::OutputSlices;
+use crate::status::{clear_last_error, copy_last_error, fail};
+
+/// Returns a pointer to a static NUL-terminated version string, for example
+/// `0.1.0 (i_triangle 0.38.0, i_overlay 4.1.1)`.
+///
+/// The crate version comes first, followed by the versions of the geometry crates the library
+/// was built against. The returned string lives for the duration of the program and must not be
+/// freed.
+#[unsafe(no_mangle)]
+pub extern "C" fn ishape_version() -> *const c_char {
+    version::VERSION.as_ptr()
+}
+
+/// Returns the version of the C ABI implemented by the library.
+///
+/// Hosts should refuse to run when it differs from the `ISHAPE_ABI_VERSION` of the header they
+/// were built against.
+#[unsafe(no_mangle)]
+pub extern "C" fn ishape_abi_version() -> u32 {
+    version::ISHAPE_ABI_VERSION
+}
+
+/// Returns `true` when the optional feature `name`, such as `"host-allocator"`, was compiled in.
+///
+/// Unknown names and a null `name` return `false`.
+///
+/// # Safety
+/// `name` must either be null or point to a NUL-terminated string.
+#[unsafe(no_mangle)]
+pub extern "C" fn ishape_has_feature(name: *const c_char) -> bool {
+    if name.is_null() {
+        return false;
+    }
+
+    let name = unsafe { CStr::from_ptr(name) };
+    version::has_feature(name.to_bytes())
+}
+
+/// Returns a pointer to a static NUL-terminated description of `status`.
+///
+/// The returned string lives for the duration of the program and must not be freed.
+#[unsafe(no_mangle)]
+pub extern "C" fn ishape_status_message(status: IShapeStatus) -> *const c_char {
+    status.message().as_ptr()
+}
+
+/// Copies the calling thread's last error message into `buffer` as a NUL-terminated string.
+///
+/// The message carries context the status code cannot, such as the offending contour index.
+/// It is only meaningful after an entry point returned a status other than
+/// [`IShapeStatus::Ok`], and stays recorded until the next failure or
+/// [`ishape_last_error_clear`].
+///
+/// Returns the length of the full message including the NUL terminator, or `0` when no error is
+/// recorded. When `len` is smaller than that, the message is truncated; pass a null `buffer` to
+/// query the required size.
+///
+/// # Safety
+/// `buffer` must either be null or point to `len` writable bytes.
+#[unsafe(no_mangle)]
+pub extern "C" fn ishape_last_error_message(buffer: *mut c_char, len: usize) -> usize {
+    let dst: &mut [u8] = if buffer.is_null() || len == 0 {
+        &mut []
+    } else {
+        unsafe { slice::from_raw_parts_mut(buffer as *mut u8, len) }
+    };
+
+    copy_last_error(dst)
+}
+
+/// Forgets the calling thread's last error message.
+#[unsafe(no_mangle)]
+pub extern "C" fn ishape_last_error_clear() {
+    clear_last_error();
+}
+
+/// Routes the memory of handles and buffers created from now on through host-provided
+/// functions.
+///
+/// Covers everything returned by the `ishape_*_create` functions, including the storage the
+/// buffers grow into; scratch memory used while an operation runs still comes from the global
+/// allocator. Each handle and buffer releases its memory through the functions that were
+/// installed when it was created, so hooks can be replaced at any time and passing null for both
+/// `alloc_fn` and `free_fn` restores the global allocator. `realloc_fn` may be null, in which case
+/// growth is done with an allocate, copy and free sequence. `user_data` is passed through to every
+/// call.
+///
+/// Returns [`IShapeStatus::NullPointer`] when only one of `alloc_fn` and `free_fn` is null.
+/// Available when the library is built with the `host-allocator` feature; check with
+/// [`ishape_has_feature`].
+///
+/// # Safety
+/// The functions must be safe to call from any thread, return memory aligned to the requested
+/// alignment, and stay valid together with `user_data` until every handle and buffer created
+/// while they were installed has been freed.
+#[cfg(feature = "host-allocator")]
+#[unsafe(no_mangle)]
+pub extern "C" fn ishape_set_allocator(
+    alloc_fn: IShapeAllocFn,
+    realloc_fn: IShapeReallocFn,
+    free_fn: IShapeFreeFn,
+    user_data: *mut c_void,
+) -> IShapeStatus {
+    match allocator::set_allocator(alloc_fn, realloc_fn, free_fn, user_data) {
+        Ok(()) => IShapeStatus::Ok,
+        Err(err) => fail(
+            err.into(),
+            format_args!("`alloc_fn` and `free_fn` must both be set or both be null"),
+        ),
+    }
+}
 
 /// Allocates an empty flat integer triangulation buffer.
 ///
@@ -29,7 +175,9 @@ pub use crate::triangle::{
 /// The returned pointer must be released with [`ishape_triangle_flat_triangulation_free`].
 #[unsafe(no_mangle)]
 pub extern "C" fn ishape_triangle_flat_triangulation_create() -> *mut FlatIntTriangulation {
-    Box::into_raw(Box::new(FlatIntTriangulation::default()))
+    guard_or(ptr::null_mut(), || {
+        into_handle(FlatIntTriangulation::default())
+    })
 }
 
 /// Allocates a flat triangulation buffer reserving capacity for `points` vertices and `triangles`.
@@ -41,9 +189,9 @@ pub extern "C" fn ishape_triangle_flat_triangulation_with_capacity(
     points: usize,
     triangles: usize,
 ) -> *mut FlatIntTriangulation {
-    Box::into_raw(Box::new(FlatIntTriangulation::with_capacity(
-        points, triangles,
-    )))
+    guard_or(ptr::null_mut(), || {
+        into_handle(FlatIntTriangulation::with_capacity(points, triangles))
+    })
 }
 
 /// Clears the buffer contents without releasing the allocation.
@@ -64,13 +212,15 @@ pub extern "C" fn ishape_triangle_flat_triangulation_clear(buffer: *mut FlatIntT
 /// Passing a null pointer is a no-op. Otherwise the pointer must have been allocated by this crate.
 #[unsafe(no_mangle)]
 pub extern "C" fn ishape_triangle_flat_triangulation_free(buffer: *mut FlatIntTriangulation) {
-    if buffer.is_null() {
-        return;
-    }
+    guard_or((), || {
+        if buffer.is_null() {
+            return;
+        }
 
-    unsafe {
-        drop(Box::from_raw(buffer));
-    }
+        unsafe {
+            free_handle(buffer);
+        }
+    })
 }
 
 /// Returns a pointer to the flattened point buffer.
@@ -113,13 +263,31 @@ pub extern "C" fn ishape_triangle_flat_triangulation_indices_len(
     unsafe { buffer.as_ref().map_or(0, |buffer| buffer.indices.len()) }
 }
 
+/// Returns the points and triangle indices of a triangulation buffer in one call.
+///
+/// A null buffer yields a view with null pointers and zero lengths. The view borrows the buffer
+/// and is invalidated by any call that modifies or frees it.
+#[unsafe(no_mangle)]
+pub extern "C" fn ishape_triangle_flat_triangulation_view(
+    buffer: *const FlatIntTriangulation,
+) -> FlatIntTriangulationView {
+    unsafe {
+        buffer.as_ref().map_or_else(
+            FlatIntTriangulationView::default,
+            FlatIntTriangulation::view,
+        )
+    }
+}
+
 /// Allocates an empty flat `f32` triangulation buffer.
 ///
 /// # Safety
 /// The returned pointer must be released with [`ishape_triangle_flat_f32_triangulation_free`].
 #[unsafe(no_mangle)]
 pub extern "C" fn ishape_triangle_flat_f32_triangulation_create() -> *mut FlatF32Triangulation {
-    Box::into_raw(Box::new(FlatF32Triangulation::default()))
+    guard_or(ptr::null_mut(), || {
+        into_handle(FlatF32Triangulation::default())
+    })
 }
 
 /// Allocates a flat `f32` triangulation buffer reserving capacity for `points` and `triangles`.
@@ -131,9 +299,9 @@ pub extern "C" fn ishape_triangle_flat_f32_triangulation_with_capacity(
     points: usize,
     triangles: usize,
 ) -> *mut FlatF32Triangulation {
-    Box::into_raw(Box::new(FlatF32Triangulation::with_capacity(
-        points, triangles,
-    )))
+    guard_or(ptr::null_mut(), || {
+        into_handle(FlatF32Triangulation::with_capacity(points, triangles))
+    })
 }
 
 /// Clears the contents of a flat `f32` triangulation buffer while keeping its allocation.
@@ -154,13 +322,15 @@ pub extern "C" fn ishape_triangle_flat_f32_triangulation_clear(buffer: *mut Flat
 /// Passing a null pointer is a no-op.
 #[unsafe(no_mangle)]
 pub extern "C" fn ishape_triangle_flat_f32_triangulation_free(buffer: *mut FlatF32Triangulation) {
-    if buffer.is_null() {
-        return;
-    }
+    guard_or((), || {
+        if buffer.is_null() {
+            return;
+        }
 
-    unsafe {
-        drop(Box::from_raw(buffer));
-    }
+        unsafe {
+            free_handle(buffer);
+        }
+    })
 }
 
 /// Returns a pointer to the flattened single-precision point buffer.
@@ -203,13 +373,31 @@ pub extern "C" fn ishape_triangle_flat_f32_triangulation_indices_len(
     unsafe { buffer.as_ref().map_or(0, |buffer| buffer.indices.len()) }
 }
 
+/// Returns the points and triangle indices of a `f32` triangulation buffer in one call.
+///
+/// A null buffer yields a view with null pointers and zero lengths. The view borrows the buffer
+/// and is invalidated by any call that modifies or frees it.
+#[unsafe(no_mangle)]
+pub extern "C" fn ishape_triangle_flat_f32_triangulation_view(
+    buffer: *const FlatF32Triangulation,
+) -> FlatF32TriangulationView {
+    unsafe {
+        buffer.as_ref().map_or_else(
+            FlatF32TriangulationView::default,
+            FlatF32Triangulation::view,
+        )
+    }
+}
+
 /// Allocates an empty flat `f64` triangulation buffer.
 ///
 /// # Safety
 /// The returned pointer must be released with [`ishape_triangle_flat_f64_triangulation_free`].
 #[unsafe(no_mangle)]
 pub extern "C" fn ishape_triangle_flat_f64_triangulation_create() -> *mut FlatF64Triangulation {
-    Box::into_raw(Box::new(FlatF64Triangulation::default()))
+    guard_or(ptr::null_mut(), || {
+        into_handle(FlatF64Triangulation::default())
+    })
 }
 
 /// Allocates a flat `f64` triangulation buffer reserving capacity for `points` and `triangles`.
@@ -221,9 +409,9 @@ pub extern "C" fn ishape_triangle_flat_f64_triangulation_with_capacity(
     points: usize,
     triangles: usize,
 ) -> *mut FlatF64Triangulation {
-    Box::into_raw(Box::new(FlatF64Triangulation::with_capacity(
-        points, triangles,
-    )))
+    guard_or(ptr::null_mut(), || {
+        into_handle(FlatF64Triangulation::with_capacity(points, triangles))
+    })
 }
 
 /// Clears the contents of a flat `f64` triangulation buffer while keeping its allocation.
@@ -244,13 +432,15 @@ pub extern "C" fn ishape_triangle_flat_f64_triangulation_clear(buffer: *mut Flat
 /// Passing a null pointer is a no-op.
 #[unsafe(no_mangle)]
 pub extern "C" fn ishape_triangle_flat_f64_triangulation_free(buffer: *mut FlatF64Triangulation) {
-    if buffer.is_null() {
-        return;
-    }
+    guard_or((), || {
+        if buffer.is_null() {
+            return;
+        }
 
-    unsafe {
-        drop(Box::from_raw(buffer));
-    }
+        unsafe {
+            free_handle(buffer);
+        }
+    })
 }
 
 /// Returns a pointer to the flattened double-precision point buffer.
@@ -293,13 +483,29 @@ pub extern "C" fn ishape_triangle_flat_f64_triangulation_indices_len(
     unsafe { buffer.as_ref().map_or(0, |buffer| buffer.indices.len()) }
 }
 
+/// Returns the points and triangle indices of a `f64` triangulation buffer in one call.
+///
+/// A null buffer yields a view with null pointers and zero lengths. The view borrows the buffer
+/// and is invalidated by any call that modifies or frees it.
+#[unsafe(no_mangle)]
+pub extern "C" fn ishape_triangle_flat_f64_triangulation_view(
+    buffer: *const FlatF64Triangulation,
+) -> FlatF64TriangulationView {
+    unsafe {
+        buffer.as_ref().map_or_else(
+            FlatF64TriangulationView::default,
+            FlatF64Triangulation::view,
+        )
+    }
+}
+
 /// Allocates an empty flat shapes buffer on the heap.
 ///
 /// # Safety
 /// The returned pointer must be released with [`ishape_flat_shapes_free`].
 #[unsafe(no_mangle)]
 pub extern "C" fn ishape_flat_shapes_create() -> *mut FlatShapesBuffer {
-    Box::into_raw(Box::new(FlatShapesBuffer::default()))
+    guard_or(ptr::null_mut(), || into_handle(FlatShapesBuffer::default()))
 }
 
 /// Allocates a flat shapes buffer reserving `points`, `contours`, and `shapes` capacity.
@@ -312,9 +518,9 @@ pub extern "C" fn ishape_flat_shapes_with_capacity(
     contours: usize,
     shapes: usize,
 ) -> *mut FlatShapesBuffer {
-    Box::into_raw(Box::new(FlatShapesBuffer::with_capacity(
-        points, contours, shapes,
-    )))
+    guard_or(ptr::null_mut(), || {
+        into_handle(FlatShapesBuffer::with_capacity(points, contours, shapes))
+    })
 }
 
 /// Clears the buffer contents without releasing the allocation.
@@ -334,14 +540,16 @@ pub extern "C" fn ishape_flat_shapes_clear(buffer: *mut FlatShapesBuffer) {
 /// Passing a null pointer is a no-op. Otherwise the pointer must have been allocated by this crate.
 #[unsafe(no_mangle)]
 pub extern "C" fn ishape_flat_shapes_free(buffer: *mut FlatShapesBuffer) {
-    if buffer.is_null() {
-        return;
-    }
+    guard_or((), || {
+        if buffer.is_null() {
+            return;
+        }
 
-    // SAFETY: Caller guarantees the pointer originated from our allocation routines.
-    unsafe {
-        drop(Box::from_raw(buffer));
-    }
+        // SAFETY: Caller guarantees the pointer originated from our allocation routines.
+        unsafe {
+            free_handle(buffer);
+        }
+    })
 }
 
 /// Returns a pointer to the flattened points buffer.
@@ -404,13 +612,138 @@ pub extern "C" fn ishape_flat_shapes_shapes_len(buffer: *const FlatShapesBuffer)
     }
 }
 
+/// Returns the points, contour ranges and shape ranges of a shapes buffer in one call.
+///
+/// A null buffer yields a view with null pointers and zero lengths. The view borrows the buffer
+/// and is invalidated by any call that modifies or frees it.
+#[unsafe(no_mangle)]
+pub extern "C" fn ishape_flat_shapes_view(buffer: *const FlatShapesBuffer) -> FlatShapesView {
+    unsafe {
+        buffer
+            .as_ref()
+            .map_or_else(FlatShapesView::default, FlatShapesBuffer::view)
+    }
+}
+
+/// Replaces the contents of a flat buffer with copies of caller-provided flat arrays.
+///
+/// `points` holds `[x0, y0, x1, y1, ...]`, `contour_ranges` index into `points` and
+/// `shape_ranges` index into `contour_ranges`, using the same layout the buffer exposes.
+///
+/// Returns [`IShapeStatus::NullPointer`] for a null buffer or a null array with a non-zero
+/// count, [`IShapeStatus::OddCoordinateCount`] for an odd coordinate count, or
+/// [`IShapeStatus::InvalidRange`] when a range does not fit or starts before the previous range
+/// ends. On those errors the buffer is unchanged; after [`IShapeStatus::Panic`] it is cleared.
+///
+/// # Safety
+/// - `buffer` must be a valid pointer to a `FlatShapesBuffer`.
+/// - Every array must either be null with a zero count or point to the given number of
+///   consecutive values.
+/// - No array may alias the storage of `buffer`, such as the arrays of
+///   [`ishape_flat_shapes_view`]; copy them first to set a buffer from its own contents.
+#[unsafe(no_mangle)]
+pub extern "C" fn ishape_flat_shapes_set(
+    buffer: *mut FlatShapesBuffer,
+    points: *const i32,
+    points_count: usize,
+    contour_ranges: *const RangeFFI,
+    contours_count: usize,
+    shape_ranges: *const RangeFFI,
+    shapes_count: usize,
+) -> IShapeStatus {
+    guard_status_into(buffer, || {
+        let Some(buffer) = (unsafe { buffer.as_mut() }) else {
+            return fail(IShapeStatus::NullPointer, format_args!("`buffer` is null"));
+        };
+
+        let arrays = read_slice("points", points, points_count).and_then(|points| {
+            let contours = read_slice("contour_ranges", contour_ranges, contours_count)?;
+            let shapes = read_slice("shape_ranges", shape_ranges, shapes_count)?;
+            Ok((points, contours, shapes))
+        });
+
+        let (points, contours, shapes) = match arrays {
+            Ok(arrays) => arrays,
+            Err(status) => return status,
+        };
+
+        match buffer.set_flat(points, contours, shapes) {
+            Ok(()) => IShapeStatus::Ok,
+            Err(err) => fail(err.into(), format_args!("{err}")),
+        }
+    })
+}
+
+/// Starts a new empty shape in a flat buffer; contours pushed afterwards are added to it.
+///
+/// There is no matching end call: the shape ends at the next `begin_shape`. A shape that gets
+/// no contours stays in the buffer as an empty range, which every entry point accepts and
+/// skips.
+///
+/// Returns [`IShapeStatus::NullPointer`] for a null buffer.
+///
+/// # Safety
+/// `buffer` must be a valid pointer to a `FlatShapesBuffer`.
+#[unsafe(no_mangle)]
+pub extern "C" fn ishape_flat_shapes_begin_shape(buffer: *mut FlatShapesBuffer) -> IShapeStatus {
+    guard_status_into(buffer, || {
+        let Some(buffer) = (unsafe { buffer.as_mut() }) else {
+            return fail(IShapeStatus::NullPointer, format_args!("`buffer` is null"));
+        };
+
+        buffer.begin_shape();
+
+        IShapeStatus::Ok
+    })
+}
+
+/// Appends a contour `[x0, y0, x1, y1, ...]` to the last shape of a flat buffer, starting a
+/// new shape when the buffer has none.
+///
+/// Returns [`IShapeStatus::NullPointer`] for a null buffer or null coordinates,
+/// [`IShapeStatus::OddCoordinateCount`] for an odd coordinate count, or
+/// [`IShapeStatus::TooFewPoints`] for an empty contour.
+///
+/// # Safety
+/// - `buffer` must be a valid pointer to a `FlatShapesBuffer`.
+/// - `points` must point to `count` consecutive `i32` values that do not alias the storage of
+///   `buffer`, since appending may move it.
+#[unsafe(no_mangle)]
+pub extern "C" fn ishape_flat_shapes_push_contour(
+    buffer: *mut FlatShapesBuffer,
+    points: *const i32,
+    count: usize,
+) -> IShapeStatus {
+    guard_status_into(buffer, || {
+        let Some(buffer) = (unsafe { buffer.as_mut() }) else {
+            return fail(IShapeStatus::NullPointer, format_args!("`buffer` is null"));
+        };
+
+        if count == 0 {
+            return fail(IShapeStatus::TooFewPoints, format_args!("contour is empty"));
+        }
+
+        let points = match read_slice("points", points, count) {
+            Ok(points) => points,
+            Err(status) => return status,
+        };
+
+        match buffer.push_contour_coords(points) {
+            Ok(()) => IShapeStatus::Ok,
+            Err(err) => fail(err.into(), format_args!("{err}")),
+        }
+    })
+}
+
 /// Allocates an empty flat `f32` shapes buffer on the heap.
 ///
 /// # Safety
 /// The returned pointer must be released with [`ishape_flat_f32_shapes_free`].
 #[unsafe(no_mangle)]
 pub extern "C" fn ishape_flat_f32_shapes_create() -> *mut FlatF32ShapesBuffer {
-    Box::into_raw(Box::new(FlatF32ShapesBuffer::default()))
+    guard_or(ptr::null_mut(), || {
+        into_handle(FlatF32ShapesBuffer::default())
+    })
 }
 
 /// Allocates a flat `f32` shapes buffer reserving `points`, `contours`, and `shapes` capacity.
@@ -423,9 +756,9 @@ pub extern "C" fn ishape_flat_f32_shapes_with_capacity(
     contours: usize,
     shapes: usize,
 ) -> *mut FlatF32ShapesBuffer {
-    Box::into_raw(Box::new(FlatF32ShapesBuffer::with_capacity(
-        points, contours, shapes,
-    )))
+    guard_or(ptr::null_mut(), || {
+        into_handle(FlatF32ShapesBuffer::with_capacity(points, contours, shapes))
+    })
 }
 
 /// Clears the buffer contents without releasing the allocation.
@@ -445,13 +778,15 @@ pub extern "C" fn ishape_flat_f32_shapes_clear(buffer: *mut FlatF32ShapesBuffer)
 /// Passing a null pointer is a no-op. Otherwise the pointer must have been allocated by this crate.
 #[unsafe(no_mangle)]
 pub extern "C" fn ishape_flat_f32_shapes_free(buffer: *mut FlatF32ShapesBuffer) {
-    if buffer.is_null() {
-        return;
-    }
+    guard_or((), || {
+        if buffer.is_null() {
+            return;
+        }
 
-    unsafe {
-        drop(Box::from_raw(buffer));
-    }
+        unsafe {
+            free_handle(buffer);
+        }
+    })
 }
 
 /// Returns a pointer to the flattened points buffer.
@@ -516,13 +851,142 @@ pub extern "C" fn ishape_flat_f32_shapes_shapes_len(buffer: *const FlatF32Shapes
     }
 }
 
+/// Returns the points, contour ranges and shape ranges of a `f32` shapes buffer in one call.
+///
+/// A null buffer yields a view with null pointers and zero lengths. The view borrows the buffer
+/// and is invalidated by any call that modifies or frees it.
+#[unsafe(no_mangle)]
+pub extern "C" fn ishape_flat_f32_shapes_view(
+    buffer: *const FlatF32ShapesBuffer,
+) -> FlatF32ShapesView {
+    unsafe {
+        buffer
+            .as_ref()
+            .map_or_else(FlatF32ShapesView::default, FlatF32ShapesBuffer::view)
+    }
+}
+
+/// Replaces the contents of a flat `f32` buffer with copies of caller-provided flat arrays.
+///
+/// `points` holds `[x0, y0, x1, y1, ...]`, `contour_ranges` index into `points` and
+/// `shape_ranges` index into `contour_ranges`, using the same layout the buffer exposes.
+///
+/// Returns [`IShapeStatus::NullPointer`] for a null buffer or a null array with a non-zero
+/// count, [`IShapeStatus::OddCoordinateCount`] for an odd coordinate count, or
+/// [`IShapeStatus::InvalidRange`] when a range does not fit or starts before the previous range
+/// ends. On those errors the buffer is unchanged; after [`IShapeStatus::Panic`] it is cleared.
+///
+/// # Safety
+/// - `buffer` must be a valid pointer to a `FlatF32ShapesBuffer`.
+/// - Every array must either be null with a zero count or point to the given number of
+///   consecutive values.
+/// - No array may alias the storage of `buffer`, such as the arrays of
+///   [`ishape_flat_f32_shapes_view`]; copy them first to set a buffer from its own contents.
+#[unsafe(no_mangle)]
+pub extern "C" fn ishape_flat_f32_shapes_set(
+    buffer: *mut FlatF32ShapesBuffer,
+    points: *const f32,
+    points_count: usize,
+    contour_ranges: *const RangeFFI,
+    contours_count: usize,
+    shape_ranges: *const RangeFFI,
+    shapes_count: usize,
+) -> IShapeStatus {
+    guard_status_into(buffer, || {
+        let Some(buffer) = (unsafe { buffer.as_mut() }) else {
+            return fail(IShapeStatus::NullPointer, format_args!("`buffer` is null"));
+        };
+
+        let arrays = read_slice("points", points, points_count).and_then(|points| {
+            let contours = read_slice("contour_ranges", contour_ranges, contours_count)?;
+            let shapes = read_slice("shape_ranges", shape_ranges, shapes_count)?;
+            Ok((points, contours, shapes))
+        });
+
+        let (points, contours, shapes) = match arrays {
+            Ok(arrays) => arrays,
+            Err(status) => return status,
+        };
+
+        match buffer.set_flat(points, contours, shapes) {
+            Ok(()) => IShapeStatus::Ok,
+            Err(err) => fail(err.into(), format_args!("{err}")),
+        }
+    })
+}
+
+/// Starts a new empty shape in a flat `f32` buffer; contours pushed afterwards are added to it.
+///
+/// There is no matching end call: the shape ends at the next `begin_shape`. A shape that gets
+/// no contours stays in the buffer as an empty range, which every entry point accepts and
+/// skips.
+///
+/// Returns [`IShapeStatus::NullPointer`] for a null buffer.
+///
+/// # Safety
+/// `buffer` must be a valid pointer to a `FlatF32ShapesBuffer`.
+#[unsafe(no_mangle)]
+pub extern "C" fn ishape_flat_f32_shapes_begin_shape(
+    buffer: *mut FlatF32ShapesBuffer,
+) -> IShapeStatus {
+    guard_status_into(buffer, || {
+        let Some(buffer) = (unsafe { buffer.as_mut() }) else {
+            return fail(IShapeStatus::NullPointer, format_args!("`buffer` is null"));
+        };
+
+        buffer.begin_shape();
+
+        IShapeStatus::Ok
+    })
+}
+
+/// Appends a contour `[x0, y0, x1, y1, ...]` to the last shape of a flat `f32` buffer, starting a
+/// new shape when the buffer has none.
+///
+/// Returns [`IShapeStatus::NullPointer`] for a null buffer or null coordinates,
+/// [`IShapeStatus::OddCoordinateCount`] for an odd coordinate count, or
+/// [`IShapeStatus::TooFewPoints`] for an empty contour.
+///
+/// # Safety
+/// - `buffer` must be a valid pointer to a `FlatF32ShapesBuffer`.
+/// - `points` must point to `count` consecutive `f32` values that do not alias the storage of
+///   `buffer`, since appending may move it.
+#[unsafe(no_mangle)]
+pub extern "C" fn ishape_flat_f32_shapes_push_contour(
+    buffer: *mut FlatF32ShapesBuffer,
+    points: *const f32,
+    count: usize,
+) -> IShapeStatus {
+    guard_status_into(buffer, || {
+        let Some(buffer) = (unsafe { buffer.as_mut() }) else {
+            return fail(IShapeStatus::NullPointer, format_args!("`buffer` is null"));
+        };
+
+        if count == 0 {
+            return fail(IShapeStatus::TooFewPoints, format_args!("contour is empty"));
+        }
+
+        let points = match read_slice("points", points, count) {
+            Ok(points) => points,
+            Err(status) => return status,
+        };
+
+        match buffer.push_contour_coords(points) {
+            Ok(()) => IShapeStatus::Ok,
+            Err(err) => fail(err.into(), format_args!("{err}")),
+        }
+    })
+}
+
 /// Allocates an empty flat `f64` shapes buffer on the heap.
 ///
 /// # Safety
 /// The returned pointer must be released with [`ishape_flat_f64_shapes_free`].
 #[unsafe(no_mangle)]
 pub extern "C" fn ishape_flat_f64_shapes_create() -> *mut FlatF64ShapesBuffer {
-    Box::into_raw(Box::new(FlatF64ShapesBuffer::default()))
+    guard_or(ptr::null_mut(), || {
+        into_handle(FlatF64ShapesBuffer::default())
+    })
 }
 
 /// Allocates a flat `f64` shapes buffer reserving `points`, `contours`, and `shapes` capacity.
@@ -535,9 +999,9 @@ pub extern "C" fn ishape_flat_f64_shapes_with_capacity(
     contours: usize,
     shapes: usize,
 ) -> *mut FlatF64ShapesBuffer {
-    Box::into_raw(Box::new(FlatF64ShapesBuffer::with_capacity(
-        points, contours, shapes,
-    )))
+    guard_or(ptr::null_mut(), || {
+        into_handle(FlatF64ShapesBuffer::with_capacity(points, contours, shapes))
+    })
 }
 
 /// Clears the buffer contents without releasing the allocation.
@@ -557,13 +1021,15 @@ pub extern "C" fn ishape_flat_f64_shapes_clear(buffer: *mut FlatF64ShapesBuffer)
 /// Passing a null pointer is a no-op. Otherwise the pointer must have been allocated by this crate.
 #[unsafe(no_mangle)]
 pub extern "C" fn ishape_flat_f64_shapes_free(buffer: *mut FlatF64ShapesBuffer) {
-    if buffer.is_null() {
-        return;
-    }
+    guard_or((), || {
+        if buffer.is_null() {
+            return;
+        }
 
-    unsafe {
-        drop(Box::from_raw(buffer));
-    }
+        unsafe {
+            free_handle(buffer);
+        }
+    })
 }
 
 /// Returns a pointer to the flattened points buffer.
@@ -628,6 +1094,229 @@ pub extern "C" fn ishape_flat_f64_shapes_shapes_len(buffer: *const FlatF64Shapes
     }
 }
 
+/// Returns the points, contour ranges and shape ranges of a `f64` shapes buffer in one call.
+///
+/// A null buffer yields a view with null pointers and zero lengths. The view borrows the buffer
+/// and is invalidated by any call that modifies or frees it.
+#[unsafe(no_mangle)]
+pub extern "C" fn ishape_flat_f64_shapes_view(
+    buffer: *const FlatF64ShapesBuffer,
+) -> FlatF64ShapesView {
+    unsafe {
+        buffer
+            .as_ref()
+            .map_or_else(FlatF64ShapesView::default, FlatF64ShapesBuffer::view)
+    }
+}
+
+/// Replaces the contents of a flat `f64` buffer with copies of caller-provided flat arrays.
+///
+/// `points` holds `[x0, y0, x1, y1, ...]`, `contour_ranges` index into `points` and
+/// `shape_ranges` index into `contour_ranges`, using the same layout the buffer exposes.
+///
+/// Returns [`IShapeStatus::NullPointer`] for a null buffer or a null array with a non-zero
+/// count, [`IShapeStatus::OddCoordinateCount`] for an odd coordinate count, or
+/// [`IShapeStatus::InvalidRange`] when a range does not fit or starts before the previous range
+/// ends. On those errors the buffer is unchanged; after [`IShapeStatus::Panic`] it is cleared.
+///
+/// # Safety
+/// - `buffer` must be a valid pointer to a `FlatF64ShapesBuffer`.
+/// - Every array must either be null with a zero count or point to the given number of
+///   consecutive values.
+/// - No array may alias the storage of `buffer`, such as the arrays of
+///   [`ishape_flat_f64_shapes_view`]; copy them first to set a buffer from its own contents.
+#[unsafe(no_mangle)]
+pub extern "C" fn ishape_flat_f64_shapes_set(
+    buffer: *mut FlatF64ShapesBuffer,
+    points: *const f64,
+    points_count: usize,
+    contour_ranges: *const RangeFFI,
+    contours_count: usize,
+    shape_ranges: *const RangeFFI,
+    shapes_count: usize,
+) -> IShapeStatus {
+    guard_status_into(buffer, || {
+        let Some(buffer) = (unsafe { buffer.as_mut() }) else {
+            return fail(IShapeStatus::NullPointer, format_args!("`buffer` is null"));
+        };
+
+        let arrays = read_slice("points", points, points_count).and_then(|points| {
+            let contours = read_slice("contour_ranges", contour_ranges, contours_count)?;
+            let shapes = read_slice("shape_ranges", shape_ranges, shapes_count)?;
+            Ok((points, contours, shapes))
+        });
+
+        let (points, contours, shapes) = match arrays {
+            Ok(arrays) => arrays,
+            Err(status) => return status,
+        };
+
+        match buffer.set_flat(points, contours, shapes) {
+            Ok(()) => IShapeStatus::Ok,
+            Err(err) => fail(err.into(), format_args!("{err}")),
+        }
+    })
+}
+
+/// Starts a new empty shape in a flat `f64` buffer; contours pushed afterwards are added to it.
+///
+/// There is no matching end call: the shape ends at the next `begin_shape`. A shape that gets
+/// no contours stays in the buffer as an empty range, which every entry point accepts and
+/// skips.
+///
+/// Returns [`IShapeStatus::NullPointer`] for a null buffer.
+///
+/// # Safety
+/// `buffer` must be a valid pointer to a `FlatF64ShapesBuffer`.
+#[unsafe(no_mangle)]
+pub extern "C" fn ishape_flat_f64_shapes_begin_shape(
+    buffer: *mut FlatF64ShapesBuffer,
+) -> IShapeStatus {
+    guard_status_into(buffer, || {
+        let Some(buffer) = (unsafe { buffer.as_mut() }) else {
+            return fail(IShapeStatus::NullPointer, format_args!("`buffer` is null"));
+        };
+
+        buffer.begin_shape();
+
+        IShapeStatus::Ok
+    })
+}
+
+/// Appends a contour `[x0, y0, x1, y1, ...]` to the last shape of a flat `f64` buffer, starting a
+/// new shape when the buffer has none.
+///
+/// Returns [`IShapeStatus::NullPointer`] for a null buffer or null coordinates,
+/// [`IShapeStatus::OddCoordinateCount`] for an odd coordinate count, or
+/// [`IShapeStatus::TooFewPoints`] for an empty contour.
+///
+/// # Safety
+/// - `buffer` must be a valid pointer to a `FlatF64ShapesBuffer`.
+/// - `points` must point to `count` consecutive `f64` values that do not alias the storage of
+///   `buffer`, since appending may move it.
+#[unsafe(no_mangle)]
+pub extern "C" fn ishape_flat_f64_shapes_push_contour(
+    buffer: *mut FlatF64ShapesBuffer,
+    points: *const f64,
+    count: usize,
+) -> IShapeStatus {
+    guard_status_into(buffer, || {
+        let Some(buffer) = (unsafe { buffer.as_mut() }) else {
+            return fail(IShapeStatus::NullPointer, format_args!("`buffer` is null"));
+        };
+
+        if count == 0 {
+            return fail(IShapeStatus::TooFewPoints, format_args!("contour is empty"));
+        }
+
+        let points = match read_slice("points", points, count) {
+            Ok(points) => points,
+            Err(status) => return status,
+        };
+
+        match buffer.push_contour_coords(points) {
+            Ok(()) => IShapeStatus::Ok,
+            Err(err) => fail(err.into(), format_args!("{err}")),
+        }
+    })
+}
+
+#[inline]
+fn read_slice<'a, T>(name: &str, ptr: *const T, len: usize) -> Result<&'a [T], IShapeStatus> {
+    if len == 0 {
+        return Ok(&[]);
+    }
+
+    if ptr.is_null() {
+        return Err(fail(
+            IShapeStatus::NullPointer,
+            format_args!("`{name}` is null but its count is {len}"),
+        ));
+    }
+
+    Ok(unsafe { slice::from_raw_parts(ptr, len) })
+}
+
+#[inline]
+fn write_slices<T, S>(slices: &mut S, value: &T) -> IShapeStatus
+where
+    T: ?Sized,
+    S: OutputSlices<T>,
+{
+    match slices.write(value) {
+        Ok(()) => IShapeStatus::Ok,
+        Err(err) => fail(err.into(), format_args!("{err}")),
+    }
+}
+
+#[inline]
+fn read_f32_shapes(
+    buffer: &FlatF32ShapesBuffer,
+    policy: IShapeNonFinitePolicy,
+) -> Result<Shapes<FloatPoint<f32>>, IShapeStatus> {
+    if let Err(err) = buffer.validate() {
+        return Err(fail(err.into(), format_args!("shapes buffer {err}")));
+    }
+
+    buffer
+        .to_finite_shapes(policy)
+        .map_err(|err| fail(err.into(), format_args!("shapes buffer {err}")))
+}
+
+#[inline]
+fn read_f64_shapes(
+    buffer: &FlatF64ShapesBuffer,
+    policy: IShapeNonFinitePolicy,
+) -> Result<Shapes<FloatPoint<f64>>, IShapeStatus> {
+    if let Err(err) = buffer.validate() {
+        return Err(fail(err.into(), format_args!("shapes buffer {err}")));
+    }
+
+    buffer
+        .to_finite_shapes(policy)
+        .map_err(|err| fail(err.into(), format_args!("shapes buffer {err}")))
+}
+
+#[inline]
+fn triangulate_int_shapes<'a>(
+    triangulator: &'a mut IntTriangulator,
+    buffer: &FlatShapesBuffer,
+) -> Result<&'a IntTriangulation<IntTriangulationIndex>, IShapeStatus> {
+    if let Err(err) = buffer.validate() {
+        return Err(fail(err.into(), format_args!("shapes buffer {err}")));
+    }
+
+    Ok(triangulator.triangulate_flat(buffer))
+}
+
+#[inline]
+fn triangulate_f32_shapes<'a>(
+    triangulator: &'a mut Float32Triangulator,
+    buffer: &FlatF32ShapesBuffer,
+) -> Result<&'a Triangulation<FloatPoint<f32>, IntTriangulationIndex>, IShapeStatus> {
+    if let Err(err) = buffer.validate() {
+        return Err(fail(err.into(), format_args!("shapes buffer {err}")));
+    }
+
+    triangulator
+        .triangulate_flat(buffer)
+        .map_err(|err| fail(err.into(), format_args!("shapes buffer {err}")))
+}
+
+#[inline]
+fn triangulate_f64_shapes<'a>(
+    triangulator: &'a mut Float64Triangulator,
+    buffer: &FlatF64ShapesBuffer,
+) -> Result<&'a Triangulation<FloatPoint<f64>, IntTriangulationIndex>, IShapeStatus> {
+    if let Err(err) = buffer.validate() {
+        return Err(fail(err.into(), format_args!("shapes buffer {err}")));
+    }
+
+    triangulator
+        .triangulate_flat(buffer)
+        .map_err(|err| fail(err.into(), format_args!("shapes buffer {err}")))
+}
+
 /// Creates a new integer overlay pre-allocating space for `capacity` segment endpoints.
 ///
 /// # Safety
@@ -638,7 +1327,9 @@ pub extern "C" fn ishape_overlay_int_create(
     capacity: usize,
     options: IntOverlayOptions,
 ) -> *mut IntOverlay {
-    Box::into_raw(Box::new(IntOverlay::new(capacity, options)))
+    guard_or(ptr::null_mut(), || {
+        into_handle(IntOverlay::new(capacity, options))
+    })
 }
 
 /// Releases an overlay previously created with [`ishape_overlay_int_create`].
@@ -649,21 +1340,37 @@ pub extern "C" fn ishape_overlay_int_create(
 /// The pointer must have been returned by [`ishape_overlay_int_create`] and not freed already.
 #[unsafe(no_mangle)]
 pub extern "C" fn ishape_overlay_int_free(handle: *mut IntOverlay) {
-    if handle.is_null() {
-        return;
-    }
+    guard_or((), || {
+        if handle.is_null() {
+            return;
+        }
 
-    // SAFETY: The caller guarantees `handle` came from `ishape_overlay_int_create` and has not
-    // been deallocated yet.
-    unsafe {
-        drop(Box::from_raw(handle));
+        // SAFETY: The caller guarantees `handle` came from `ishape_overlay_int_create` and has not
+        // been deallocated yet.
+        unsafe {
+            free_handle(handle);
+        }
+    })
+}
+
+/// Removes every contour queued in the overlay without releasing its memory, so the handle can
+/// be refilled for the next operation.
+/// Contour numbers in later error messages start again from 0.
+///
+/// # Safety
+/// Passing a null pointer is a no-op. Otherwise, the pointer must be valid and uniquely owned.
+#[unsafe(no_mangle)]
+pub extern "C" fn ishape_overlay_int_clear(handle: *mut IntOverlay) {
+    if let Some(overlay) = unsafe { handle.as_mut() } {
+        overlay.clear();
     }
 }
 
 /// Appends a contour to the overlay using a flat coordinate buffer `[x0, y0, x1, y1, ...]`.
 ///
-/// Returns `false` if the input is invalid (null handle, null coordinates with non-zero length,
-/// or an odd number of coordinates). On success the contour is queued inside the overlay.
+/// Returns [`IShapeStatus::NullPointer`] for a null handle or null coordinates with non-zero
+/// length and [`IShapeStatus::OddCoordinateCount`] for an odd number of coordinates. On success
+/// the contour is queued inside the overlay.
 ///
 /// # Safety
 /// - `handle` must be a valid pointer obtained from [`ishape_overlay_int_create`].
@@ -674,31 +1381,48 @@ pub extern "C" fn ishape_overlay_int_add_contour(
     points: *const i32,
     count: usize,
     shape_type: IntShapeType,
-) -> bool {
-    if handle.is_null() {
-        return false;
-    }
-
-    let points_slice: &[i32] = if count == 0 {
-        &[]
-    } else {
-        if points.is_null() {
-            return false;
+) -> IShapeStatus {
+    guard_status(|| {
+        if handle.is_null() {
+            return fail(IShapeStatus::NullPointer, format_args!("`handle` is null"));
         }
 
-        // SAFETY: The caller guarantees `points` points to `count` elements when `count > 0`.
-        unsafe { slice::from_raw_parts(points, count) }
-    };
-
-    let overlay = unsafe { &mut *handle };
-
-    overlay.add_contour(points_slice, shape_type.into()).is_ok()
+        let points_slice: &[i32] = if count == 0 {
+            &[]
+        } else {
+            if points.is_null() {
+                return fail(
+                    IShapeStatus::NullPointer,
+                    format_args!("`points` is null but `count` is {count}"),
+                );
+            }
+
+            // SAFETY: The caller guarantees `points` points to `count` elements when `count > 0`.
+            unsafe { slice::from_raw_parts(points, count) }
+        };
+
+        let overlay = unsafe { &mut *handle };
+
+        match overlay.add_contour(points_slice, shape_type.into()) {
+            Ok(()) => IShapeStatus::Ok,
+            Err(err) => {
+                let index = overlay.contours_count();
+                fail(
+                    err.into(),
+                    format_args!("contour {index} has {count} coordinates"),
+                )
+            }
+        }
+    })
 }
 
 /// Runs the configured boolean operation, writing results into a flat buffer.
 ///
-/// Returns `false` if any pointer is null; otherwise the output buffer is populated and `true`
-/// is returned.
+/// Returns [`IShapeStatus::NullPointer`] if any pointer is null; otherwise the output buffer is
+/// populated and [`IShapeStatus::Ok`] is returned.
+///
+/// The queued contours are kept, so the same handle can run further operations on them or be
+/// emptied with [`ishape_overlay_int_clear`] and refilled.
 ///
 /// # Safety
 /// All pointers must be valid and uniquely owned for the duration of the call.
@@ -708,251 +1432,1865 @@ pub extern "C" fn ishape_overlay_int_overlay_into_flat(
     overlay_rule: IntOverlayRule,
     fill_rule: IntFillRule,
     output: *mut FlatShapesBuffer,
-) -> bool {
-    if handle.is_null() || output.is_null() {
-        return false;
+) -> IShapeStatus {
+    guard_status_into(output, || {
+        if handle.is_null() || output.is_null() {
+            return fail(
+                IShapeStatus::NullPointer,
+                format_args!("`handle` or `output` is null"),
+            );
+        }
+
+        let overlay = unsafe { &mut *handle };
+        let buffer = unsafe { &mut *output };
+
+        let shapes = overlay.overlay(overlay_rule.into(), fill_rule.into());
+
+        buffer.set_shapes(&shapes);
+
+        IShapeStatus::Ok
+    })
+}
+
+/// Runs the configured boolean operation, writing results into caller-provided flat arrays.
+///
+/// Every `*_len` field of `output` receives the length the result needs. Returns
+/// [`IShapeStatus::InsufficientCapacity`] without writing the arrays when any capacity is too
+/// small, so a first call with zero capacities queries the sizes and a second call fills them.
+/// Returns [`IShapeStatus::NullPointer`] if any pointer is null.
+///
+/// The queued contours are kept, so the same handle can run further operations on them or be
+/// emptied with [`ishape_overlay_int_clear`] and refilled.
+///
+/// # Safety
+/// - `handle` must be a valid pointer obtained from [`ishape_overlay_int_create`].
+/// - `output` must be a valid pointer to a `FlatShapesSlices` whose arrays are either null with zero
+///   capacity or valid for writes of their capacity.
+#[unsafe(no_mangle)]
+pub extern "C" fn ishape_overlay_int_overlay_into_slices(
+    handle: *mut IntOverlay,
+    overlay_rule: IntOverlayRule,
+    fill_rule: IntFillRule,
+    output: *mut FlatShapesSlices,
+) -> IShapeStatus {
+    guard_status_into(output, || {
+        if handle.is_null() || output.is_null() {
+            return fail(
+                IShapeStatus::NullPointer,
+                format_args!("`handle` or `output` is null"),
+            );
+        }
+
+        let overlay = unsafe { &mut *handle };
+        let slices = unsafe { &mut *output };
+
+        let shapes = overlay.overlay(overlay_rule.into(), fill_rule.into());
+
+        write_slices(slices, shapes.as_slice())
+    })
+}
+
+/// Creates a new `f64` overlay pre-allocating space for contour metadata.
+///
+/// # Safety
+/// The returned pointer must eventually be released with [`ishape_overlay_f64_free`] to avoid
+/// leaking resources.
+#[unsafe(no_mangle)]
+pub extern "C" fn ishape_overlay_f64_create(
+    capacity: usize,
+    options: Float64OverlayOptions,
+) -> *mut Float64Overlay {
+    guard_or(ptr::null_mut(), || {
+        into_handle(Float64Overlay::new(capacity, options))
+    })
+}
+
+/// Releases an overlay previously created with [`ishape_overlay_f64_create`].
+///
+/// Passing a null pointer is a no-op.
+///
+/// # Safety
+/// The pointer must have been returned by [`ishape_overlay_f64_create`] and not freed already.
+#[unsafe(no_mangle)]
+pub extern "C" fn ishape_overlay_f64_free(handle: *mut Float64Overlay) {
+    guard_or((), || {
+        if handle.is_null() {
+            return;
+        }
+
+        unsafe {
+            free_handle(handle);
+        }
+    })
+}
+
+/// Removes every contour queued in the overlay without releasing its memory, so the handle can
+/// be refilled for the next operation.
+/// Contour numbers in later error messages start again from 0.
+///
+/// # Safety
+/// Passing a null pointer is a no-op. Otherwise, the pointer must be valid and uniquely owned.
+#[unsafe(no_mangle)]
+pub extern "C" fn ishape_overlay_f64_clear(handle: *mut Float64Overlay) {
+    if let Some(overlay) = unsafe { handle.as_mut() } {
+        overlay.clear();
+    }
+}
+
+/// Appends a contour to the overlay using a flat coordinate buffer `[x0, y0, x1, y1, ...]`.
+///
+/// Returns [`IShapeStatus::NullPointer`] for a null handle or null coordinates with non-zero
+/// length and [`IShapeStatus::OddCoordinateCount`] for an odd number of coordinates. Points with
+/// a NaN or infinite coordinate follow the `non_finite` option the overlay was created with:
+/// [`IShapeStatus::NonFiniteValue`] is returned when it rejects them, otherwise they or their
+/// contour are dropped. On success the contour is queued inside the overlay.
+///
+/// # Safety
+/// - `handle` must be a valid pointer obtained from [`ishape_overlay_f64_create`].
+/// - `points` must either be null with `count == 0` or point to `count` consecutive `f64` values.
+#[unsafe(no_mangle)]
+pub extern "C" fn ishape_overlay_f64_add_contour(
+    handle: *mut Float64Overlay,
+    points: *const f64,
+    count: usize,
+    shape_type: IntShapeType,
+) -> IShapeStatus {
+    guard_status(|| {
+        if handle.is_null() {
+            return fail(IShapeStatus::NullPointer, format_args!("`handle` is null"));
+        }
+
+        let points_slice: &[f64] = if count == 0 {
+            &[]
+        } else {
+            if points.is_null() {
+                return fail(
+                    IShapeStatus::NullPointer,
+                    format_args!("`points` is null but `count` is {count}"),
+                );
+            }
+
+            unsafe { slice::from_raw_parts(points, count) }
+        };
+
+        let overlay = unsafe { &mut *handle };
+
+        match overlay.add_contour(points_slice, shape_type.into()) {
+            Ok(()) => IShapeStatus::Ok,
+            Err(Float64AddContourError::NonFinite(err)) => fail(err.into(), format_args!("{err}")),
+            Err(err) => {
+                let index = overlay.contours_count();
+                fail(
+                    err.into(),
+                    format_args!("contour {index} has {count} coordinates"),
+                )
+            }
+        }
+    })
+}
+
+/// Runs the configured boolean operation, writing results into a flat `f64` shapes buffer.
+///
+/// Returns [`IShapeStatus::NullPointer`] if any pointer is null; otherwise the output buffer is
+/// populated and [`IShapeStatus::Ok`] is returned.
+///
+/// The queued contours are kept, so the same handle can run further operations on them or be
+/// emptied with [`ishape_overlay_f64_clear`] and refilled.
+///
+/// # Safety
+/// All pointers must be valid and uniquely owned for the duration of the call.
+#[unsafe(no_mangle)]
+pub extern "C" fn ishape_overlay_f64_overlay_into_flat(
+    handle: *mut Float64Overlay,
+    overlay_rule: IntOverlayRule,
+    fill_rule: IntFillRule,
+    output: *mut FlatF64ShapesBuffer,
+) -> IShapeStatus {
+    guard_status_into(output, || {
+        if handle.is_null() || output.is_null() {
+            return fail(
+                IShapeStatus::NullPointer,
+                format_args!("`handle` or `output` is null"),
+            );
+        }
+
+        let overlay = unsafe { &mut *handle };
+        let buffer = unsafe { &mut *output };
+
+        let shapes = overlay.overlay(overlay_rule.into(), fill_rule.into());
+
+        buffer.set_shapes(&shapes);
+
+        IShapeStatus::Ok
+    })
+}
+
+/// Runs the configured boolean operation, writing results into caller-provided flat `f64` arrays.
+///
+/// Every `*_len` field of `output` receives the length the result needs. Returns
+/// [`IShapeStatus::InsufficientCapacity`] without writing the arrays when any capacity is too
+/// small, so a first call with zero capacities queries the sizes and a second call fills them.
+/// Returns [`IShapeStatus::NullPointer`] if any pointer is null.
+///
+/// The queued contours are kept, so the same handle can run further operations on them or be
+/// emptied with [`ishape_overlay_f64_clear`] and refilled.
+///
+/// # Safety
+/// - `handle` must be a valid pointer obtained from [`ishape_overlay_f64_create`].
+/// - `output` must be a valid pointer to a `FlatF64ShapesSlices` whose arrays are either null with zero
+///   capacity or valid for writes of their capacity.
+#[unsafe(no_mangle)]
+pub extern "C" fn ishape_overlay_f64_overlay_into_slices(
+    handle: *mut Float64Overlay,
+    overlay_rule: IntOverlayRule,
+    fill_rule: IntFillRule,
+    output: *mut FlatF64ShapesSlices,
+) -> IShapeStatus {
+    guard_status_into(output, || {
+        if handle.is_null() || output.is_null() {
+            return fail(
+                IShapeStatus::NullPointer,
+                format_args!("`handle` or `output` is null"),
+            );
+        }
+
+        let overlay = unsafe { &mut *handle };
+        let slices = unsafe { &mut *output };
+
+        let shapes = overlay.overlay(overlay_rule.into(), fill_rule.into());
+
+        write_slices(slices, shapes.as_slice())
+    })
+}
+
+/// Creates a new `f32` overlay pre-allocating space for contour metadata.
+///
+/// # Safety
+/// The returned pointer must eventually be released with [`ishape_overlay_f32_free`] to avoid
+/// leaking resources.
+#[unsafe(no_mangle)]
+pub extern "C" fn ishape_overlay_f32_create(
+    capacity: usize,
+    options: Float32OverlayOptions,
+) -> *mut Float32Overlay {
+    guard_or(ptr::null_mut(), || {
+        into_handle(Float32Overlay::new(capacity, options))
+    })
+}
+
+/// Releases an overlay previously created with [`ishape_overlay_f32_create`].
+///
+/// Passing a null pointer is a no-op.
+///
+/// # Safety
+/// The pointer must have been returned by [`ishape_overlay_f32_create`] and not freed already.
+#[unsafe(no_mangle)]
+pub extern "C" fn ishape_overlay_f32_free(handle: *mut Float32Overlay) {
+    guard_or((), || {
+        if handle.is_null() {
+            return;
+        }
+
+        unsafe {
+            free_handle(handle);
+        }
+    })
+}
+
+/// Removes every contour queued in the overlay without releasing its memory, so the handle can
+/// be refilled for the next operation.
+/// Contour numbers in later error messages start again from 0.
+///
+/// # Safety
+/// Passing a null pointer is a no-op. Otherwise, the pointer must be valid and uniquely owned.
+#[unsafe(no_mangle)]
+pub extern "C" fn ishape_overlay_f32_clear(handle: *mut Float32Overlay) {
+    if let Some(overlay) = unsafe { handle.as_mut() } {
+        overlay.clear();
+    }
+}
+
+/// Appends a contour to the overlay using a flat coordinate buffer `[x0, y0, x1, y1, ...]`.
+///
+/// Returns [`IShapeStatus::NullPointer`] for a null handle or null coordinates with non-zero
+/// length and [`IShapeStatus::OddCoordinateCount`] for an odd number of coordinates. Points with
+/// a NaN or infinite coordinate follow the `non_finite` option the overlay was created with:
+/// [`IShapeStatus::NonFiniteValue`] is returned when it rejects them, otherwise they or their
+/// contour are dropped. On success the contour is queued inside the overlay.
+///
+/// # Safety
+/// - `handle` must be a valid pointer obtained from [`ishape_overlay_f32_create`].
+/// - `points` must either be null with `count == 0` or point to `count` consecutive `f32` values.
+#[unsafe(no_mangle)]
+pub extern "C" fn ishape_overlay_f32_add_contour(
+    handle: *mut Float32Overlay,
+    points: *const f32,
+    count: usize,
+    shape_type: IntShapeType,
+) -> IShapeStatus {
+    guard_status(|| {
+        if handle.is_null() {
+            return fail(IShapeStatus::NullPointer, format_args!("`handle` is null"));
+        }
+
+        let points_slice: &[f32] = if count == 0 {
+            &[]
+        } else {
+            if points.is_null() {
+                return fail(
+                    IShapeStatus::NullPointer,
+                    format_args!("`points` is null but `count` is {count}"),
+                );
+            }
+
+            unsafe { slice::from_raw_parts(points, count) }
+        };
+
+        let overlay = unsafe { &mut *handle };
+
+        match overlay.add_contour(points_slice, shape_type.into()) {
+            Ok(()) => IShapeStatus::Ok,
+            Err(Float32AddContourError::NonFinite(err)) => fail(err.into(), format_args!("{err}")),
+            Err(err) => {
+                let index = overlay.contours_count();
+                fail(
+                    err.into(),
+                    format_args!("contour {index} has {count} coordinates"),
+                )
+            }
+        }
+    })
+}
+
+/// Runs the configured boolean operation, writing results into a flat `f32` shapes buffer.
+///
+/// Returns [`IShapeStatus::NullPointer`] if any pointer is null; otherwise the output buffer is
+/// populated and [`IShapeStatus::Ok`] is returned.
+///
+/// The queued contours are kept, so the same handle can run further operations on them or be
+/// emptied with [`ishape_overlay_f32_clear`] and refilled.
+///
+/// # Safety
+/// All pointers must be valid and uniquely owned for the duration of the call.
+#[unsafe(no_mangle)]
+pub extern "C" fn ishape_overlay_f32_overlay_into_flat(
+    handle: *mut Float32Overlay,
+    overlay_rule: IntOverlayRule,
+    fill_rule: IntFillRule,
+    output: *mut FlatF32ShapesBuffer,
+) -> IShapeStatus {
+    guard_status_into(output, || {
+        if handle.is_null() || output.is_null() {
+            return fail(
+                IShapeStatus::NullPointer,
+                format_args!("`handle` or `output` is null"),
+            );
+        }
+
+        let overlay = unsafe { &mut *handle };
+        let buffer = unsafe { &mut *output };
+
+        let shapes = overlay.overlay(overlay_rule.into(), fill_rule.into());
+
+        buffer.set_shapes(&shapes);
+
+        IShapeStatus::Ok
+    })
+}
+
+/// Runs the configured boolean operation, writing results into caller-provided flat `f32` arrays.
+///
+/// Every `*_len` field of `output` receives the length the result needs. Returns
+/// [`IShapeStatus::InsufficientCapacity`] without writing the arrays when any capacity is too
+/// small, so a first call with zero capacities queries the sizes and a second call fills them.
+/// Returns [`IShapeStatus::NullPointer`] if any pointer is null.
+///
+/// The queued contours are kept, so the same handle can run further operations on them or be
+/// emptied with [`ishape_overlay_f32_clear`] and refilled.
+///
+/// # Safety
+/// - `handle` must be a valid pointer obtained from [`ishape_overlay_f32_create`].
+/// - `output` must be a valid pointer to a `FlatF32ShapesSlices` whose arrays are either null with zero
+///   capacity or valid for writes of their capacity.
+#[unsafe(no_mangle)]
+pub extern "C" fn ishape_overlay_f32_overlay_into_slices(
+    handle: *mut Float32Overlay,
+    overlay_rule: IntOverlayRule,
+    fill_rule: IntFillRule,
+    output: *mut FlatF32ShapesSlices,
+) -> IShapeStatus {
+    guard_status_into(output, || {
+        if handle.is_null() || output.is_null() {
+            return fail(
+                IShapeStatus::NullPointer,
+                format_args!("`handle` or `output` is null"),
+            );
+        }
+
+        let overlay = unsafe { &mut *handle };
+        let slices = unsafe { &mut *output };
+
+        let shapes = overlay.overlay(overlay_rule.into(), fill_rule.into());
+
+        write_slices(slices, shapes.as_slice())
+    })
+}
+
+/// Builds an offset/buffer result from a single contour and writes it into a flat `f64` buffer.
+///
+/// Input contour is represented as `[x0, y0, x1, y1, ...]`. A positive `offset` deflates the
+/// contour and a negative one inflates it, whatever its winding. Every outline entry point,
+/// `f32` ones included, uses this sign convention.
+///
+/// Returns [`IShapeStatus::NullPointer`] when pointers are invalid,
+/// [`IShapeStatus::OddCoordinateCount`] when the coordinate count is odd,
+/// [`IShapeStatus::TooFewPoints`] when fewer than 3 points are provided, or
+/// [`IShapeStatus::NonFiniteValue`] when `offset` or a coordinate is NaN or infinite.
+///
+/// # Safety
+/// - `points` must either be null with `count == 0` or point to `count` consecutive `f64` values.
+/// - `output` must be a valid pointer to a `FlatF64ShapesBuffer`.
+#[unsafe(no_mangle)]
+pub extern "C" fn ishape_outline_f64_contour_to_flat(
+    points: *const f64,
+    count: usize,
+    offset: f64,
+    output: *mut FlatF64ShapesBuffer,
+) -> IShapeStatus {
+    guard_status_into(output, || {
+        if output.is_null() {
+            return fail(IShapeStatus::NullPointer, format_args!("`output` is null"));
+        }
+
+        let shapes = match offset_style(offset)
+            .and_then(|style| outline_contour(points, count, &style, IShapeNonFinitePolicy::Reject))
+        {
+            Ok(shapes) => shapes,
+            Err(status) => return status,
+        };
+
+        let buffer = unsafe { &mut *output };
+        buffer.set_shapes(&shapes);
+
+        IShapeStatus::Ok
+    })
+}
+
+/// Builds an offset/buffer result from a single contour using explicit outer and inner offsets
+/// and line join style, writing it into a flat `f64` buffer.
+///
+/// - `outer_offset` and `inner_offset` follow the sign convention of
+///   [`ishape_outline_f64_contour_to_flat`].
+/// - `join_kind`: `0=Bevel`, `1=Miter`, `2=Round`
+///
+/// Returns [`IShapeStatus::InvalidLineJoin`] when the join cannot be decoded, and the same input
+/// errors as [`ishape_outline_f64_contour_to_flat`].
+///
+/// # Safety
+/// - `points` must either be null with `count == 0` or point to `count` consecutive `f64` values.
+/// - `output` must be a valid pointer to a `FlatF64ShapesBuffer`.
+#[unsafe(no_mangle)]
+pub extern "C" fn ishape_outline_f64_contour_to_flat_styled(
+    points: *const f64,
+    count: usize,
+    outer_offset: f64,
+    inner_offset: f64,
+    join_kind: u32,
+    join_value: f64,
+    output: *mut FlatF64ShapesBuffer,
+) -> IShapeStatus {
+    guard_status_into(output, || {
+        if output.is_null() {
+            return fail(IShapeStatus::NullPointer, format_args!("`output` is null"));
+        }
+
+        let shapes = match outline_style(outer_offset, inner_offset, join_kind, join_value)
+            .and_then(|style| outline_contour(points, count, &style, IShapeNonFinitePolicy::Reject))
+        {
+            Ok(shapes) => shapes,
+            Err(status) => return status,
+        };
+
+        let buffer = unsafe { &mut *output };
+        buffer.set_shapes(&shapes);
+
+        IShapeStatus::Ok
+    })
+}
+
+/// Builds an offset/buffer result from a single contour using the provided [`FfiOutlineStyle`],
+/// writing it into a flat `f64` buffer.
+///
+/// Returns the same errors as [`ishape_outline_f64_contour_to_flat_styled`], except that points
+/// with a NaN or infinite coordinate follow `style.non_finite`: a contour it drops, or leaves with
+/// fewer than 3 points, produces an empty result.
+///
+/// # Safety
+/// - `points` must either be null with `count == 0` or point to `count` consecutive `f64` values.
+/// - `output` must be a valid pointer to a `FlatF64ShapesBuffer`.
+#[unsafe(no_mangle)]
+pub extern "C" fn ishape_outline_f64_contour_to_flat_with_style(
+    points: *const f64,
+    count: usize,
+    style: FfiOutlineStyle,
+    output: *mut FlatF64ShapesBuffer,
+) -> IShapeStatus {
+    guard_status_into(output, || {
+        if output.is_null() {
+            return fail(IShapeStatus::NullPointer, format_args!("`output` is null"));
+        }
+
+        let shapes = match decode_ffi_outline_style(&style)
+            .and_then(|outline| outline_contour(points, count, &outline, style.non_finite))
+        {
+            Ok(shapes) => shapes,
+            Err(status) => return status,
+        };
+
+        let buffer = unsafe { &mut *output };
+        buffer.set_shapes(&shapes);
+
+        IShapeStatus::Ok
+    })
+}
+
+/// Builds an offset/buffer result from every shape of a flat `f64` buffer, holes included.
+///
+/// Outer contours are expected counterclockwise and holes clockwise, matching overlay output.
+/// Offsets follow [`ishape_outline_f64_contour_to_flat`], so a positive `offset` also grows the
+/// holes. Contours with fewer than 3 points are skipped, together with the holes of a skipped
+/// outer contour.
+///
+/// Returns [`IShapeStatus::NullPointer`] if any pointer is null, and
+/// [`IShapeStatus::OddCoordinateCount`] or [`IShapeStatus::InvalidRange`] when the `shapes` buffer
+/// layout is malformed. A non-finite `offset` or coordinate yields
+/// [`IShapeStatus::NonFiniteValue`]. An empty input clears `output`.
+///
+/// # Safety
+/// - `shapes` must be a valid pointer to a `FlatF64ShapesBuffer`.
+/// - `output` must be a valid pointer to a `FlatF64ShapesBuffer` that does not alias `shapes`.
+#[unsafe(no_mangle)]
+pub extern "C" fn ishape_outline_f64_shapes_to_flat(
+    shapes: *const FlatF64ShapesBuffer,
+    offset: f64,
+    output: *mut FlatF64ShapesBuffer,
+) -> IShapeStatus {
+    guard_status_into(output, || match offset_style(offset) {
+        Ok(style) => outline_f64_shapes(shapes, &style, IShapeNonFinitePolicy::Reject, output),
+        Err(status) => status,
+    })
+}
+
+/// Builds an offset/buffer result from every shape of a flat `f64` buffer using explicit outer
+/// and inner offsets and line join style.
+///
+/// - `outer_offset` moves outer contours and `inner_offset` moves holes; positive values deflate
+///   the shapes as in [`ishape_outline_f64_shapes_to_flat`].
+/// - `join_kind`: `0=Bevel`, `1=Miter`, `2=Round`
+///
+/// Returns [`IShapeStatus::InvalidLineJoin`] when the join cannot be decoded, and the same errors
+/// as [`ishape_outline_f64_shapes_to_flat`] otherwise.
+///
+/// # Safety
+/// - `shapes` must be a valid pointer to a `FlatF64ShapesBuffer`.
+/// - `output` must be a valid pointer to a `FlatF64ShapesBuffer` that does not alias `shapes`.
+#[unsafe(no_mangle)]
+pub extern "C" fn ishape_outline_f64_shapes_to_flat_styled(
+    shapes: *const FlatF64ShapesBuffer,
+    outer_offset: f64,
+    inner_offset: f64,
+    join_kind: u32,
+    join_value: f64,
+    output: *mut FlatF64ShapesBuffer,
+) -> IShapeStatus {
+    guard_status_into(output, || {
+        match outline_style(outer_offset, inner_offset, join_kind, join_value) {
+            Ok(style) => outline_f64_shapes(shapes, &style, IShapeNonFinitePolicy::Reject, output),
+            Err(status) => status,
+        }
+    })
+}
+
+/// Builds an offset/buffer result from every shape of a flat `f64` buffer using the provided
+/// [`FfiOutlineStyle`].
+///
+/// Returns the same errors as [`ishape_outline_f64_shapes_to_flat_styled`], except that points
+/// with a NaN or infinite coordinate follow `style.non_finite`; a shape whose outer contour is
+/// dropped is dropped together with its holes.
+///
+/// # Safety
+/// - `shapes` must be a valid pointer to a `FlatF64ShapesBuffer`.
+/// - `output` must be a valid pointer to a `FlatF64ShapesBuffer` that does not alias `shapes`.
+#[unsafe(no_mangle)]
+pub extern "C" fn ishape_outline_f64_shapes_to_flat_with_style(
+    shapes: *const FlatF64ShapesBuffer,
+    style: FfiOutlineStyle,
+    output: *mut FlatF64ShapesBuffer,
+) -> IShapeStatus {
+    guard_status_into(output, || match decode_ffi_outline_style(&style) {
+        Ok(outline) => outline_f64_shapes(shapes, &outline, style.non_finite, output),
+        Err(status) => status,
+    })
+}
+
+/// Builds an offset/buffer result from a single contour and writes it into caller-provided flat
+/// `f64` arrays.
+///
+/// Offsets follow [`ishape_outline_f64_contour_to_flat`]. Output sizing follows
+/// [`ishape_overlay_f64_overlay_into_slices`]: every `*_len` field receives the needed length and
+/// [`IShapeStatus::InsufficientCapacity`] is returned when an array is too small.
+///
+/// # Safety
+/// - `points` must either be null with `count == 0` or point to `count` consecutive `f64` values.
+/// - `output` must be a valid pointer to a `FlatF64ShapesSlices` whose arrays are either null with zero
+///   capacity or valid for writes of their capacity.
+#[unsafe(no_mangle)]
+pub extern "C" fn ishape_outline_f64_contour_to_slices(
+    points: *const f64,
+    count: usize,
+    offset: f64,
+    output: *mut FlatF64ShapesSlices,
+) -> IShapeStatus {
+    guard_status_into(output, || {
+        if output.is_null() {
+            return fail(IShapeStatus::NullPointer, format_args!("`output` is null"));
+        }
+
+        let shapes = match offset_style(offset)
+            .and_then(|style| outline_contour(points, count, &style, IShapeNonFinitePolicy::Reject))
+        {
+            Ok(shapes) => shapes,
+            Err(status) => return status,
+        };
+
+        let slices = unsafe { &mut *output };
+        write_slices(slices, shapes.as_slice())
+    })
+}
+
+/// Builds an offset/buffer result from every shape of a flat `f64` buffer and writes it into
+/// caller-provided flat `f64` arrays.
+///
+/// Offsets follow [`ishape_outline_f64_shapes_to_flat`] and output sizing follows
+/// [`ishape_outline_f64_contour_to_slices`].
+///
+/// # Safety
+/// - `shapes` must be a valid pointer to a `FlatF64ShapesBuffer`.
+/// - `output` must be a valid pointer to a `FlatF64ShapesSlices` whose arrays are either null with zero
+///   capacity or valid for writes of their capacity, and must not alias `shapes`.
+#[unsafe(no_mangle)]
+pub extern "C" fn ishape_outline_f64_shapes_to_slices(
+    shapes: *const FlatF64ShapesBuffer,
+    offset: f64,
+    output: *mut FlatF64ShapesSlices,
+) -> IShapeStatus {
+    guard_status_into(output, || {
+        if shapes.is_null() || output.is_null() {
+            return fail(
+                IShapeStatus::NullPointer,
+                format_args!("`shapes` or `output` is null"),
+            );
+        }
+
+        let shapes_buffer = unsafe { &*shapes };
+        let shapes_vec = match read_f64_shapes(shapes_buffer, IShapeNonFinitePolicy::Reject) {
+            Ok(shapes) => shapes,
+            Err(status) => return status,
+        };
+
+        let result = match offset_style(offset) {
+            Ok(style) => outline_shapes(shapes_vec, &style),
+            Err(status) => return status,
+        };
+
+        let slices = unsafe { &mut *output };
+        write_slices(slices, result.as_slice())
+    })
+}
+
+#[inline]
+fn outline_f64_shapes(
+    shapes: *const FlatF64ShapesBuffer,
+    style: &OutlineStyle<f64>,
+    non_finite: IShapeNonFinitePolicy,
+    output: *mut FlatF64ShapesBuffer,
+) -> IShapeStatus {
+    if shapes.is_null() || output.is_null() {
+        return fail(
+            IShapeStatus::NullPointer,
+            format_args!("`shapes` or `output` is null"),
+        );
     }
 
-    let overlay = unsafe { &mut *handle };
+    let shapes_buffer = unsafe { &*shapes };
+    let shapes_vec = match read_f64_shapes(shapes_buffer, non_finite) {
+        Ok(shapes) => shapes,
+        Err(status) => return status,
+    };
+
     let buffer = unsafe { &mut *output };
 
-    let shapes = overlay.overlay(overlay_rule.into(), fill_rule.into());
+    if shapes_vec.is_empty() {
+        buffer.clear();
+        return IShapeStatus::Ok;
+    }
+
+    let result = outline_shapes(shapes_vec, style);
+
+    buffer.set_shapes(&result);
+
+    IShapeStatus::Ok
+}
+
+/// Builds a stroke/buffer result from a single contour and writes it into a flat `f64` buffer,
+/// using explicit line join and line cap styles.
+///
+/// - `join_kind`: `0=Bevel`, `1=Miter`, `2=Round`
+/// - `start_cap_kind` and `end_cap_kind`: `0=Butt`, `1=Round`, `2=Square`
+///
+/// Returns [`IShapeStatus::InvalidWidth`], [`IShapeStatus::InvalidLineJoin`] or
+/// [`IShapeStatus::InvalidLineCap`] when the style cannot be decoded, and the same input errors
+/// as [`ishape_outline_f64_contour_to_flat`] when fewer than 2 points are provided.
+///
+/// # Safety
+/// - `points` must either be null with `count == 0` or point to `count` consecutive `f64` values.
+/// - `output` must be a valid pointer to a `FlatF64ShapesBuffer`.
+#[unsafe(no_mangle)]
+pub extern "C" fn ishape_stroke_f64_contour_to_flat_styled(
+    points: *const f64,
+    count: usize,
+    width: f64,
+    is_closed_path: bool,
+    join_kind: u32,
+    join_value: f64,
+    start_cap_kind: u32,
+    start_cap_value: f64,
+    end_cap_kind: u32,
+    end_cap_value: f64,
+    output: *mut FlatF64ShapesBuffer,
+) -> IShapeStatus {
+    guard_status_into(output, || {
+        if output.is_null() {
+            return fail(IShapeStatus::NullPointer, format_args!("`output` is null"));
+        }
+
+        let shapes = match stroke_contour(
+            points,
+            count,
+            is_closed_path,
+            IShapeNonFinitePolicy::Reject,
+            || {
+                decode_stroke_style(
+                    width,
+                    join_kind,
+                    join_value,
+                    start_cap_kind,
+                    start_cap_value,
+                    end_cap_kind,
+                    end_cap_value,
+                )
+            },
+        ) {
+            Ok(shapes) => shapes,
+            Err(status) => return status,
+        };
+
+        let buffer = unsafe { &mut *output };
+        buffer.set_shapes(&shapes);
+
+        IShapeStatus::Ok
+    })
+}
+
+/// Builds a stroke/buffer result from a single contour and writes it into a flat `f64` buffer,
+/// using the provided [`FfiStrokeStyle`].
+///
+/// Returns the same errors as [`ishape_stroke_f64_contour_to_flat_styled`], except that points
+/// with a NaN or infinite coordinate follow `style.non_finite`: a path it drops, or leaves with
+/// fewer than 2 points, produces an empty result.
+///
+/// # Safety
+/// - `points` must either be null with `count == 0` or point to `count` consecutive `f64` values.
+/// - `output` must be a valid pointer to a `FlatF64ShapesBuffer`.
+#[unsafe(no_mangle)]
+pub extern "C" fn ishape_stroke_f64_contour_to_flat(
+    points: *const f64,
+    count: usize,
+    is_closed_path: bool,
+    style: FfiStrokeStyle,
+    output: *mut FlatF64ShapesBuffer,
+) -> IShapeStatus {
+    guard_status_into(output, || {
+        if output.is_null() {
+            return fail(IShapeStatus::NullPointer, format_args!("`output` is null"));
+        }
+
+        let shapes = match stroke_contour(points, count, is_closed_path, style.non_finite, || {
+            decode_ffi_stroke_style(&style)
+        }) {
+            Ok(shapes) => shapes,
+            Err(status) => return status,
+        };
+
+        let buffer = unsafe { &mut *output };
+        buffer.set_shapes(&shapes);
 
-    buffer.set_shapes(&shapes);
+        IShapeStatus::Ok
+    })
+}
 
-    true
+/// Builds a stroke/buffer result from a single contour and writes it into a flat `f64` buffer,
+/// using the provided [`FfiStrokeStyle`] with custom start and end cap templates.
+///
+/// A cap template is a flat `[x0, y0, x1, y1, ...]` point array relative to the path end, in
+/// units of half the stroke width: `+x` points away from the path and the cap runs from the
+/// `y = -1` side to the `y = +1` side, so `[1, -1, 1, 1]` reproduces a square cap. An empty
+/// template keeps the corresponding cap of `style`.
+///
+/// Returns [`IShapeStatus::OddCoordinateCount`] or [`IShapeStatus::InvalidLineCap`] for a
+/// malformed template, and the same errors as [`ishape_stroke_f64_contour_to_flat_styled`].
+///
+/// # Safety
+/// - `points` must either be null with `count == 0` or point to `count` consecutive `f64` values.
+/// - `start_cap_points` and `end_cap_points` must either be null with a zero count or point to
+///   the given number of consecutive `f64` values.
+/// - `output` must be a valid pointer to a `FlatF64ShapesBuffer`.
+#[unsafe(no_mangle)]
+pub extern "C" fn ishape_stroke_f64_contour_to_flat_custom_caps(
+    points: *const f64,
+    count: usize,
+    is_closed_path: bool,
+    style: FfiStrokeStyle,
+    start_cap_points: *const f64,
+    start_cap_count: usize,
+    end_cap_points: *const f64,
+    end_cap_count: usize,
+    output: *mut FlatF64ShapesBuffer,
+) -> IShapeStatus {
+    guard_status_into(output, || {
+        if output.is_null() {
+            return fail(IShapeStatus::NullPointer, format_args!("`output` is null"));
+        }
+
+        let start_template = match read_cap_template("start", start_cap_points, start_cap_count) {
+            Ok(template) => template,
+            Err(status) => return status,
+        };
+
+        let end_template = match read_cap_template("end", end_cap_points, end_cap_count) {
+            Ok(template) => template,
+            Err(status) => return status,
+        };
+
+        let shapes = match stroke_contour(points, count, is_closed_path, style.non_finite, || {
+            decode_custom_caps_stroke_style(&style, &start_template, &end_template)
+        }) {
+            Ok(shapes) => shapes,
+            Err(status) => return status,
+        };
+
+        let buffer = unsafe { &mut *output };
+        buffer.set_shapes(&shapes);
+
+        IShapeStatus::Ok
+    })
+}
+
+/// Builds a single unioned stroke/buffer result from many paths and writes it into a flat `f64`
+/// buffer, using explicit line join and line cap styles shared by every path.
+///
+/// - `points` holds the coordinates of all paths as `[x0, y0, x1, y1, ...]`.
+/// - `path_ranges[i]` is the coordinate span of path `i` inside `points`, using the same layout as
+///   the contour ranges of [`FlatF64ShapesBuffer`].
+/// - `closed_flags[i]` tells whether path `i` is closed.
+/// - `join_kind`, `start_cap_kind` and `end_cap_kind` are encoded as in
+///   [`ishape_stroke_f64_contour_to_flat_styled`].
+///
+/// Returns [`IShapeStatus::InvalidRange`] when a path range is reversed, out of bounds or not
+/// aligned to a point, [`IShapeStatus::TooFewPoints`] when a path has fewer than 2 points, and the
+/// same style errors as [`ishape_stroke_f64_contour_to_flat_styled`]. A NaN or infinite
+/// coordinate yields [`IShapeStatus::NonFiniteValue`]. With `paths_count == 0` the output is
+/// cleared.
+///
+/// # Safety
+/// - `points` must either be null with `points_count == 0` or point to `points_count`
+///   consecutive `f64` values.
+/// - `path_ranges` and `closed_flags` must either be null with `paths_count == 0` or point to
+///   `paths_count` consecutive values.
+/// - `output` must be a valid pointer to a `FlatF64ShapesBuffer`.
+#[unsafe(no_mangle)]
+pub extern "C" fn ishape_stroke_f64_paths_to_flat_styled(
+    points: *const f64,
+    points_count: usize,
+    path_ranges: *const RangeFFI,
+    closed_flags: *const bool,
+    paths_count: usize,
+    width: f64,
+    join_kind: u32,
+    join_value: f64,
+    start_cap_kind: u32,
+    start_cap_value: f64,
+    end_cap_kind: u32,
+    end_cap_value: f64,
+    output: *mut FlatF64ShapesBuffer,
+) -> IShapeStatus {
+    guard_status_into(output, || {
+        if output.is_null() {
+            return fail(IShapeStatus::NullPointer, format_args!("`output` is null"));
+        }
+
+        let paths = FlatPaths {
+            points,
+            points_count,
+            path_ranges,
+            closed_flags,
+            paths_count,
+        };
+
+        let shapes = match stroke_paths(&paths, IShapeNonFinitePolicy::Reject, || {
+            decode_stroke_style(
+                width,
+                join_kind,
+                join_value,
+                start_cap_kind,
+                start_cap_value,
+                end_cap_kind,
+                end_cap_value,
+            )
+        }) {
+            Ok(shapes) => shapes,
+            Err(status) => return status,
+        };
+
+        let buffer = unsafe { &mut *output };
+        buffer.set_shapes(&shapes);
+
+        IShapeStatus::Ok
+    })
+}
+
+/// Builds a single unioned stroke/buffer result from many paths and writes it into a flat `f64`
+/// buffer, using the provided [`FfiStrokeStyle`] for every path.
+///
+/// Paths are described as in [`ishape_stroke_f64_paths_to_flat_styled`], which also lists the
+/// returned errors. Points with a NaN or infinite coordinate follow `style.non_finite`, and
+/// paths it drops are skipped.
+///
+/// # Safety
+/// - `points` must either be null with `points_count == 0` or point to `points_count`
+///   consecutive `f64` values.
+/// - `path_ranges` and `closed_flags` must either be null with `paths_count == 0` or point to
+///   `paths_count` consecutive values.
+/// - `output` must be a valid pointer to a `FlatF64ShapesBuffer`.
+#[unsafe(no_mangle)]
+pub extern "C" fn ishape_stroke_f64_paths_to_flat(
+    points: *const f64,
+    points_count: usize,
+    path_ranges: *const RangeFFI,
+    closed_flags: *const bool,
+    paths_count: usize,
+    style: FfiStrokeStyle,
+    output: *mut FlatF64ShapesBuffer,
+) -> IShapeStatus {
+    guard_status_into(output, || {
+        if output.is_null() {
+            return fail(IShapeStatus::NullPointer, format_args!("`output` is null"));
+        }
+
+        let paths = FlatPaths {
+            points,
+            points_count,
+            path_ranges,
+            closed_flags,
+            paths_count,
+        };
+
+        let shapes =
+            match stroke_paths(&paths, style.non_finite, || decode_ffi_stroke_style(&style)) {
+                Ok(shapes) => shapes,
+                Err(status) => return status,
+            };
+
+        let buffer = unsafe { &mut *output };
+        buffer.set_shapes(&shapes);
+
+        IShapeStatus::Ok
+    })
+}
+
+/// Builds a single unioned stroke/buffer result from many paths and writes it into a flat `f64`
+/// buffer, using the provided [`FfiStrokeStyle`] with custom start and end cap templates.
+///
+/// Paths are described as in [`ishape_stroke_f64_paths_to_flat_styled`] and cap templates as in
+/// [`ishape_stroke_f64_contour_to_flat_custom_caps`]; every open path uses the same templates.
+///
+/// # Safety
+/// - `points` must either be null with `points_count == 0` or point to `points_count`
+///   consecutive `f64` values.
+/// - `path_ranges` and `closed_flags` must either be null with `paths_count == 0` or point to
+///   `paths_count` consecutive values.
+/// - `start_cap_points` and `end_cap_points` must either be null with a zero count or point to
+///   the given number of consecutive `f64` values.
+/// - `output` must be a valid pointer to a `FlatF64ShapesBuffer`.
+#[unsafe(no_mangle)]
+pub extern "C" fn ishape_stroke_f64_paths_to_flat_custom_caps(
+    points: *const f64,
+    points_count: usize,
+    path_ranges: *const RangeFFI,
+    closed_flags: *const bool,
+    paths_count: usize,
+    style: FfiStrokeStyle,
+    start_cap_points: *const f64,
+    start_cap_count: usize,
+    end_cap_points: *const f64,
+    end_cap_count: usize,
+    output: *mut FlatF64ShapesBuffer,
+) -> IShapeStatus {
+    guard_status_into(output, || {
+        if output.is_null() {
+            return fail(IShapeStatus::NullPointer, format_args!("`output` is null"));
+        }
+
+        let start_template = match read_cap_template("start", start_cap_points, start_cap_count) {
+            Ok(template) => template,
+            Err(status) => return status,
+        };
+
+        let end_template = match read_cap_template("end", end_cap_points, end_cap_count) {
+            Ok(template) => template,
+            Err(status) => return status,
+        };
+
+        let paths = FlatPaths {
+            points,
+            points_count,
+            path_ranges,
+            closed_flags,
+            paths_count,
+        };
+
+        let shapes = match stroke_paths(&paths, style.non_finite, || {
+            decode_custom_caps_stroke_style(&style, &start_template, &end_template)
+        }) {
+            Ok(shapes) => shapes,
+            Err(status) => return status,
+        };
+
+        let buffer = unsafe { &mut *output };
+        buffer.set_shapes(&shapes);
+
+        IShapeStatus::Ok
+    })
+}
+
+/// Builds a stroke/buffer result from a single contour and writes it into caller-provided flat
+/// `f64` arrays, using the provided [`FfiStrokeStyle`].
+///
+/// Input errors follow [`ishape_stroke_f64_contour_to_flat`] and output sizing follows
+/// [`ishape_outline_f64_contour_to_slices`].
+///
+/// # Safety
+/// - `points` must either be null with `count == 0` or point to `count` consecutive `f64` values.
+/// - `output` must be a valid pointer to a `FlatF64ShapesSlices` whose arrays are either null with zero
+///   capacity or valid for writes of their capacity.
+#[unsafe(no_mangle)]
+pub extern "C" fn ishape_stroke_f64_contour_to_slices(
+    points: *const f64,
+    count: usize,
+    is_closed_path: bool,
+    style: FfiStrokeStyle,
+    output: *mut FlatF64ShapesSlices,
+) -> IShapeStatus {
+    guard_status_into(output, || {
+        if output.is_null() {
+            return fail(IShapeStatus::NullPointer, format_args!("`output` is null"));
+        }
+
+        let shapes = match stroke_contour(points, count, is_closed_path, style.non_finite, || {
+            decode_ffi_stroke_style(&style)
+        }) {
+            Ok(shapes) => shapes,
+            Err(status) => return status,
+        };
+
+        let slices = unsafe { &mut *output };
+        write_slices(slices, shapes.as_slice())
+    })
+}
+
+/// Builds a single unioned stroke/buffer result from many paths and writes it into
+/// caller-provided flat `f64` arrays, using the provided [`FfiStrokeStyle`] for every path.
+///
+/// Paths and input errors follow [`ishape_stroke_f64_paths_to_flat`] and output sizing follows
+/// [`ishape_outline_f64_contour_to_slices`].
+///
+/// # Safety
+/// - `points` must either be null with `points_count == 0` or point to `points_count`
+///   consecutive `f64` values.
+/// - `path_ranges` and `closed_flags` must either be null with `paths_count == 0` or point to
+///   `paths_count` consecutive values.
+/// - `output` must be a valid pointer to a `FlatF64ShapesSlices` whose arrays are either null with zero
+///   capacity or valid for writes of their capacity.
+#[unsafe(no_mangle)]
+pub extern "C" fn ishape_stroke_f64_paths_to_slices(
+    points: *const f64,
+    points_count: usize,
+    path_ranges: *const RangeFFI,
+    closed_flags: *const bool,
+    paths_count: usize,
+    style: FfiStrokeStyle,
+    output: *mut FlatF64ShapesSlices,
+) -> IShapeStatus {
+    guard_status_into(output, || {
+        if output.is_null() {
+            return fail(IShapeStatus::NullPointer, format_args!("`output` is null"));
+        }
+
+        let paths = FlatPaths {
+            points,
+            points_count,
+            path_ranges,
+            closed_flags,
+            paths_count,
+        };
+
+        let shapes =
+            match stroke_paths(&paths, style.non_finite, || decode_ffi_stroke_style(&style)) {
+                Ok(shapes) => shapes,
+                Err(status) => return status,
+            };
+
+        let slices = unsafe { &mut *output };
+        write_slices(slices, shapes.as_slice())
+    })
+}
+
+/// Builds an offset/buffer result from a single contour and writes it into a flat `f32` buffer.
+///
+/// Input contour is represented as `[x0, y0, x1, y1, ...]`. Offsets follow
+/// [`ishape_outline_f64_contour_to_flat`].
+///
+/// Returns [`IShapeStatus::NullPointer`] when pointers are invalid,
+/// [`IShapeStatus::OddCoordinateCount`] when the coordinate count is odd,
+/// [`IShapeStatus::TooFewPoints`] when fewer than 3 points are provided, or
+/// [`IShapeStatus::NonFiniteValue`] when `offset` or a coordinate is NaN or infinite.
+///
+/// # Safety
+/// - `points` must either be null with `count == 0` or point to `count` consecutive `f32` values.
+/// - `output` must be a valid pointer to a `FlatF32ShapesBuffer`.
+#[unsafe(no_mangle)]
+pub extern "C" fn ishape_outline_f32_contour_to_flat(
+    points: *const f32,
+    count: usize,
+    offset: f32,
+    output: *mut FlatF32ShapesBuffer,
+) -> IShapeStatus {
+    guard_status_into(output, || {
+        if output.is_null() {
+            return fail(IShapeStatus::NullPointer, format_args!("`output` is null"));
+        }
+
+        let shapes = match offset_style(offset)
+            .and_then(|style| outline_contour(points, count, &style, IShapeNonFinitePolicy::Reject))
+        {
+            Ok(shapes) => shapes,
+            Err(status) => return status,
+        };
+
+        let buffer = unsafe { &mut *output };
+        buffer.set_shapes(&shapes);
+
+        IShapeStatus::Ok
+    })
+}
+
+/// Builds an offset/buffer result from a single contour using explicit outer and inner offsets
+/// and line join style, writing it into a flat `f32` buffer.
+///
+/// - `outer_offset` and `inner_offset` follow the sign convention of
+///   [`ishape_outline_f64_contour_to_flat`].
+/// - `join_kind`: `0=Bevel`, `1=Miter`, `2=Round`
+///
+/// Returns [`IShapeStatus::InvalidLineJoin`] when the join cannot be decoded, and the same input
+/// errors as [`ishape_outline_f32_contour_to_flat`].
+///
+/// # Safety
+/// - `points` must either be null with `count == 0` or point to `count` consecutive `f32` values.
+/// - `output` must be a valid pointer to a `FlatF32ShapesBuffer`.
+#[unsafe(no_mangle)]
+pub extern "C" fn ishape_outline_f32_contour_to_flat_styled(
+    points: *const f32,
+    count: usize,
+    outer_offset: f32,
+    inner_offset: f32,
+    join_kind: u32,
+    join_value: f32,
+    output: *mut FlatF32ShapesBuffer,
+) -> IShapeStatus {
+    guard_status_into(output, || {
+        if output.is_null() {
+            return fail(IShapeStatus::NullPointer, format_args!("`output` is null"));
+        }
+
+        let shapes = match outline_style(outer_offset, inner_offset, join_kind, join_value)
+            .and_then(|style| outline_contour(points, count, &style, IShapeNonFinitePolicy::Reject))
+        {
+            Ok(shapes) => shapes,
+            Err(status) => return status,
+        };
+
+        let buffer = unsafe { &mut *output };
+        buffer.set_shapes(&shapes);
+
+        IShapeStatus::Ok
+    })
+}
+
+/// Builds an offset/buffer result from a single contour using the provided [`FfiOutlineStyle`],
+/// writing it into a flat `f32` buffer.
+///
+/// Returns the same errors as [`ishape_outline_f32_contour_to_flat_styled`], except that points
+/// with a NaN or infinite coordinate follow `style.non_finite`: a contour it drops, or leaves with
+/// fewer than 3 points, produces an empty result.
+///
+/// # Safety
+/// - `points` must either be null with `count == 0` or point to `count` consecutive `f32` values.
+/// - `output` must be a valid pointer to a `FlatF32ShapesBuffer`.
+#[unsafe(no_mangle)]
+pub extern "C" fn ishape_outline_f32_contour_to_flat_with_style(
+    points: *const f32,
+    count: usize,
+    style: FfiOutlineStyle,
+    output: *mut FlatF32ShapesBuffer,
+) -> IShapeStatus {
+    guard_status_into(output, || {
+        if output.is_null() {
+            return fail(IShapeStatus::NullPointer, format_args!("`output` is null"));
+        }
+
+        let shapes = match decode_ffi_outline_style(&style)
+            .and_then(|outline| outline_contour(points, count, &outline, style.non_finite))
+        {
+            Ok(shapes) => shapes,
+            Err(status) => return status,
+        };
+
+        let buffer = unsafe { &mut *output };
+        buffer.set_shapes(&shapes);
+
+        IShapeStatus::Ok
+    })
+}
+
+/// Builds an offset/buffer result from every shape of a flat `f32` buffer, holes included.
+///
+/// Outer contours are expected counterclockwise and holes clockwise, matching overlay output.
+/// Offsets follow [`ishape_outline_f64_contour_to_flat`], so a positive `offset` also grows the
+/// holes. Contours with fewer than 3 points are skipped, together with the holes of a skipped
+/// outer contour.
+///
+/// Returns [`IShapeStatus::NullPointer`] if any pointer is null, and
+/// [`IShapeStatus::OddCoordinateCount`] or [`IShapeStatus::InvalidRange`] when the `shapes` buffer
+/// layout is malformed. A non-finite `offset` or coordinate yields
+/// [`IShapeStatus::NonFiniteValue`]. An empty input clears `output`.
+///
+/// # Safety
+/// - `shapes` must be a valid pointer to a `FlatF32ShapesBuffer`.
+/// - `output` must be a valid pointer to a `FlatF32ShapesBuffer` that does not alias `shapes`.
+#[unsafe(no_mangle)]
+pub extern "C" fn ishape_outline_f32_shapes_to_flat(
+    shapes: *const FlatF32ShapesBuffer,
+    offset: f32,
+    output: *mut FlatF32ShapesBuffer,
+) -> IShapeStatus {
+    guard_status_into(output, || match offset_style(offset) {
+        Ok(style) => outline_f32_shapes(shapes, &style, IShapeNonFinitePolicy::Reject, output),
+        Err(status) => status,
+    })
+}
+
+/// Builds an offset/buffer result from every shape of a flat `f32` buffer using explicit outer
+/// and inner offsets and line join style.
+///
+/// - `outer_offset` moves outer contours and `inner_offset` moves holes; positive values deflate
+///   the shapes as in [`ishape_outline_f32_shapes_to_flat`].
+/// - `join_kind`: `0=Bevel`, `1=Miter`, `2=Round`
+///
+/// Returns [`IShapeStatus::InvalidLineJoin`] when the join cannot be decoded, and the same errors
+/// as [`ishape_outline_f32_shapes_to_flat`] otherwise.
+///
+/// # Safety
+/// - `shapes` must be a valid pointer to a `FlatF32ShapesBuffer`.
+/// - `output` must be a valid pointer to a `FlatF32ShapesBuffer` that does not alias `shapes`.
+#[unsafe(no_mangle)]
+pub extern "C" fn ishape_outline_f32_shapes_to_flat_styled(
+    shapes: *const FlatF32ShapesBuffer,
+    outer_offset: f32,
+    inner_offset: f32,
+    join_kind: u32,
+    join_value: f32,
+    output: *mut FlatF32ShapesBuffer,
+) -> IShapeStatus {
+    guard_status_into(output, || {
+        match outline_style(outer_offset, inner_offset, join_kind, join_value) {
+            Ok(style) => outline_f32_shapes(shapes, &style, IShapeNonFinitePolicy::Reject, output),
+            Err(status) => status,
+        }
+    })
+}
+
+/// Builds an offset/buffer result from every shape of a flat `f32` buffer using the provided
+/// [`FfiOutlineStyle`].
+///
+/// Returns the same errors as [`ishape_outline_f32_shapes_to_flat_styled`], except that points
+/// with a NaN or infinite coordinate follow `style.non_finite`; a shape whose outer contour is
+/// dropped is dropped together with its holes.
+///
+/// # Safety
+/// - `shapes` must be a valid pointer to a `FlatF32ShapesBuffer`.
+/// - `output` must be a valid pointer to a `FlatF32ShapesBuffer` that does not alias `shapes`.
+#[unsafe(no_mangle)]
+pub extern "C" fn ishape_outline_f32_shapes_to_flat_with_style(
+    shapes: *const FlatF32ShapesBuffer,
+    style: FfiOutlineStyle,
+    output: *mut FlatF32ShapesBuffer,
+) -> IShapeStatus {
+    guard_status_into(output, || match decode_ffi_outline_style(&style) {
+        Ok(outline) => outline_f32_shapes(shapes, &outline, style.non_finite, output),
+        Err(status) => status,
+    })
 }
 
-/// Creates a new `f64` overlay pre-allocating space for contour metadata.
+/// Builds an offset/buffer result from a single contour and writes it into caller-provided flat
+/// `f32` arrays.
+///
+/// Offsets follow [`ishape_outline_f32_contour_to_flat`]. Output sizing follows
+/// [`ishape_overlay_f32_overlay_into_slices`]: every `*_len` field receives the needed length and
+/// [`IShapeStatus::InsufficientCapacity`] is returned when an array is too small.
 ///
 /// # Safety
-/// The returned pointer must eventually be released with [`ishape_overlay_f64_free`] to avoid
-/// leaking resources.
+/// - `points` must either be null with `count == 0` or point to `count` consecutive `f32` values.
+/// - `output` must be a valid pointer to a `FlatF32ShapesSlices` whose arrays are either null with zero
+///   capacity or valid for writes of their capacity.
 #[unsafe(no_mangle)]
-pub extern "C" fn ishape_overlay_f64_create(
-    capacity: usize,
-    options: Float64OverlayOptions,
-) -> *mut Float64Overlay {
-    Box::into_raw(Box::new(Float64Overlay::new(capacity, options)))
+pub extern "C" fn ishape_outline_f32_contour_to_slices(
+    points: *const f32,
+    count: usize,
+    offset: f32,
+    output: *mut FlatF32ShapesSlices,
+) -> IShapeStatus {
+    guard_status_into(output, || {
+        if output.is_null() {
+            return fail(IShapeStatus::NullPointer, format_args!("`output` is null"));
+        }
+
+        let shapes = match offset_style(offset)
+            .and_then(|style| outline_contour(points, count, &style, IShapeNonFinitePolicy::Reject))
+        {
+            Ok(shapes) => shapes,
+            Err(status) => return status,
+        };
+
+        let slices = unsafe { &mut *output };
+        write_slices(slices, shapes.as_slice())
+    })
 }
 
-/// Releases an overlay previously created with [`ishape_overlay_f64_create`].
+/// Builds an offset/buffer result from every shape of a flat `f32` buffer and writes it into
+/// caller-provided flat `f32` arrays.
 ///
-/// Passing a null pointer is a no-op.
+/// Offsets follow [`ishape_outline_f32_shapes_to_flat`] and output sizing follows
+/// [`ishape_outline_f32_contour_to_slices`].
 ///
 /// # Safety
-/// The pointer must have been returned by [`ishape_overlay_f64_create`] and not freed already.
+/// - `shapes` must be a valid pointer to a `FlatF32ShapesBuffer`.
+/// - `output` must be a valid pointer to a `FlatF32ShapesSlices` whose arrays are either null with zero
+///   capacity or valid for writes of their capacity, and must not alias `shapes`.
 #[unsafe(no_mangle)]
-pub extern "C" fn ishape_overlay_f64_free(handle: *mut Float64Overlay) {
-    if handle.is_null() {
-        return;
+pub extern "C" fn ishape_outline_f32_shapes_to_slices(
+    shapes: *const FlatF32ShapesBuffer,
+    offset: f32,
+    output: *mut FlatF32ShapesSlices,
+) -> IShapeStatus {
+    guard_status_into(output, || {
+        if shapes.is_null() || output.is_null() {
+            return fail(
+                IShapeStatus::NullPointer,
+                format_args!("`shapes` or `output` is null"),
+            );
+        }
+
+        let shapes_buffer = unsafe { &*shapes };
+        let shapes_vec = match read_f32_shapes(shapes_buffer, IShapeNonFinitePolicy::Reject) {
+            Ok(shapes) => shapes,
+            Err(status) => return status,
+        };
+
+        let result = match offset_style(offset) {
+            Ok(style) => outline_shapes(shapes_vec, &style),
+            Err(status) => return status,
+        };
+
+        let slices = unsafe { &mut *output };
+        write_slices(slices, result.as_slice())
+    })
+}
+
+#[inline]
+fn outline_f32_shapes(
+    shapes: *const FlatF32ShapesBuffer,
+    style: &OutlineStyle<f32>,
+    non_finite: IShapeNonFinitePolicy,
+    output: *mut FlatF32ShapesBuffer,
+) -> IShapeStatus {
+    if shapes.is_null() || output.is_null() {
+        return fail(
+            IShapeStatus::NullPointer,
+            format_args!("`shapes` or `output` is null"),
+        );
     }
 
-    unsafe {
-        drop(Box::from_raw(handle));
+    let shapes_buffer = unsafe { &*shapes };
+    let shapes_vec = match read_f32_shapes(shapes_buffer, non_finite) {
+        Ok(shapes) => shapes,
+        Err(status) => return status,
+    };
+
+    let buffer = unsafe { &mut *output };
+
+    if shapes_vec.is_empty() {
+        buffer.clear();
+        return IShapeStatus::Ok;
     }
+
+    let result = outline_shapes(shapes_vec, style);
+
+    buffer.set_shapes(&result);
+
+    IShapeStatus::Ok
 }
 
-/// Appends a contour to the overlay using a flat coordinate buffer `[x0, y0, x1, y1, ...]`.
+/// Builds a stroke/buffer result from a single contour and writes it into a flat `f32` buffer,
+/// using explicit line join and line cap styles.
+///
+/// - `join_kind`: `0=Bevel`, `1=Miter`, `2=Round`
+/// - `start_cap_kind` and `end_cap_kind`: `0=Butt`, `1=Round`, `2=Square`
 ///
-/// Returns `false` if the input is invalid (null handle, null coordinates with non-zero length,
-/// or an odd number of coordinates). On success the contour is queued inside the overlay.
+/// Returns [`IShapeStatus::InvalidWidth`], [`IShapeStatus::InvalidLineJoin`] or
+/// [`IShapeStatus::InvalidLineCap`] when the style cannot be decoded, and the same input errors
+/// as [`ishape_outline_f32_contour_to_flat`] when fewer than 2 points are provided.
 ///
 /// # Safety
-/// - `handle` must be a valid pointer obtained from [`ishape_overlay_f64_create`].
-/// - `points` must either be null with `count == 0` or point to `count` consecutive `f64` values.
+/// - `points` must either be null with `count == 0` or point to `count` consecutive `f32` values.
+/// - `output` must be a valid pointer to a `FlatF32ShapesBuffer`.
 #[unsafe(no_mangle)]
-pub extern "C" fn ishape_overlay_f64_add_contour(
-    handle: *mut Float64Overlay,
-    points: *const f64,
+pub extern "C" fn ishape_stroke_f32_contour_to_flat_styled(
+    points: *const f32,
     count: usize,
-    shape_type: IntShapeType,
-) -> bool {
-    if handle.is_null() {
-        return false;
-    }
+    width: f32,
+    is_closed_path: bool,
+    join_kind: u32,
+    join_value: f32,
+    start_cap_kind: u32,
+    start_cap_value: f32,
+    end_cap_kind: u32,
+    end_cap_value: f32,
+    output: *mut FlatF32ShapesBuffer,
+) -> IShapeStatus {
+    guard_status_into(output, || {
+        if output.is_null() {
+            return fail(IShapeStatus::NullPointer, format_args!("`output` is null"));
+        }
 
-    let points_slice: &[f64] = if count == 0 {
-        &[]
-    } else {
-        if points.is_null() {
-            return false;
+        let shapes = match stroke_contour(
+            points,
+            count,
+            is_closed_path,
+            IShapeNonFinitePolicy::Reject,
+            || {
+                decode_stroke_style(
+                    width,
+                    join_kind,
+                    join_value,
+                    start_cap_kind,
+                    start_cap_value,
+                    end_cap_kind,
+                    end_cap_value,
+                )
+            },
+        ) {
+            Ok(shapes) => shapes,
+            Err(status) => return status,
+        };
+
+        let buffer = unsafe { &mut *output };
+        buffer.set_shapes(&shapes);
+
+        IShapeStatus::Ok
+    })
+}
+
+/// Builds a stroke/buffer result from a single contour and writes it into a flat `f32` buffer,
+/// using the provided [`FfiStrokeStyle`].
+///
+/// Returns the same errors as [`ishape_stroke_f32_contour_to_flat_styled`], except that points
+/// with a NaN or infinite coordinate follow `style.non_finite`: a path it drops, or leaves with
+/// fewer than 2 points, produces an empty result.
+///
+/// # Safety
+/// - `points` must either be null with `count == 0` or point to `count` consecutive `f32` values.
+/// - `output` must be a valid pointer to a `FlatF32ShapesBuffer`.
+#[unsafe(no_mangle)]
+pub extern "C" fn ishape_stroke_f32_contour_to_flat(
+    points: *const f32,
+    count: usize,
+    is_closed_path: bool,
+    style: FfiStrokeStyle,
+    output: *mut FlatF32ShapesBuffer,
+) -> IShapeStatus {
+    guard_status_into(output, || {
+        if output.is_null() {
+            return fail(IShapeStatus::NullPointer, format_args!("`output` is null"));
         }
 
-        unsafe { slice::from_raw_parts(points, count) }
-    };
+        let shapes = match stroke_contour(points, count, is_closed_path, style.non_finite, || {
+            decode_ffi_stroke_style(&style)
+        }) {
+            Ok(shapes) => shapes,
+            Err(status) => return status,
+        };
 
-    let overlay = unsafe { &mut *handle };
+        let buffer = unsafe { &mut *output };
+        buffer.set_shapes(&shapes);
 
-    overlay.add_contour(points_slice, shape_type.into()).is_ok()
+        IShapeStatus::Ok
+    })
 }
 
-/// Runs the configured boolean operation, writing results into a flat `f64` shapes buffer.
+/// Builds a stroke/buffer result from a single contour and writes it into a flat `f32` buffer,
+/// using the provided [`FfiStrokeStyle`] with custom start and end cap templates.
+///
+/// A cap template is a flat `[x0, y0, x1, y1, ...]` point array relative to the path end, in
+/// units of half the stroke width: `+x` points away from the path and the cap runs from the
+/// `y = -1` side to the `y = +1` side, so `[1, -1, 1, 1]` reproduces a square cap. An empty
+/// template keeps the corresponding cap of `style`.
 ///
-/// Returns `false` if any pointer is null; otherwise the output buffer is populated and `true`
-/// is returned.
+/// Returns [`IShapeStatus::OddCoordinateCount`] or [`IShapeStatus::InvalidLineCap`] for a
+/// malformed template, and the same errors as [`ishape_stroke_f32_contour_to_flat_styled`].
 ///
 /// # Safety
-/// All pointers must be valid and uniquely owned for the duration of the call.
+/// - `points` must either be null with `count == 0` or point to `count` consecutive `f32` values.
+/// - `start_cap_points` and `end_cap_points` must either be null with a zero count or point to
+///   the given number of consecutive `f32` values.
+/// - `output` must be a valid pointer to a `FlatF32ShapesBuffer`.
 #[unsafe(no_mangle)]
-pub extern "C" fn ishape_overlay_f64_overlay_into_flat(
-    handle: *mut Float64Overlay,
-    overlay_rule: IntOverlayRule,
-    fill_rule: IntFillRule,
-    output: *mut FlatF64ShapesBuffer,
-) -> bool {
-    if handle.is_null() || output.is_null() {
-        return false;
-    }
+pub extern "C" fn ishape_stroke_f32_contour_to_flat_custom_caps(
+    points: *const f32,
+    count: usize,
+    is_closed_path: bool,
+    style: FfiStrokeStyle,
+    start_cap_points: *const f32,
+    start_cap_count: usize,
+    end_cap_points: *const f32,
+    end_cap_count: usize,
+    output: *mut FlatF32ShapesBuffer,
+) -> IShapeStatus {
+    guard_status_into(output, || {
+        if output.is_null() {
+            return fail(IShapeStatus::NullPointer, format_args!("`output` is null"));
+        }
 
-    let overlay = unsafe { &*handle };
-    let buffer = unsafe { &mut *output };
+        let start_template = match read_cap_template("start", start_cap_points, start_cap_count) {
+            Ok(template) => template,
+            Err(status) => return status,
+        };
 
-    let shapes = overlay.overlay(overlay_rule.into(), fill_rule.into());
+        let end_template = match read_cap_template("end", end_cap_points, end_cap_count) {
+            Ok(template) => template,
+            Err(status) => return status,
+        };
 
-    buffer.set_shapes(&shapes);
+        let shapes = match stroke_contour(points, count, is_closed_path, style.non_finite, || {
+            decode_custom_caps_stroke_style(&style, &start_template, &end_template)
+        }) {
+            Ok(shapes) => shapes,
+            Err(status) => return status,
+        };
 
-    true
+        let buffer = unsafe { &mut *output };
+        buffer.set_shapes(&shapes);
+
+        IShapeStatus::Ok
+    })
 }
 
-/// Builds an offset/buffer result from a single contour and writes it into a flat `f64` buffer.
+/// Builds a single unioned stroke/buffer result from many paths and writes it into a flat `f32`
+/// buffer, using explicit line join and line cap styles shared by every path.
 ///
-/// Input contour is represented as `[x0, y0, x1, y1, ...]`.
+/// - `points` holds the coordinates of all paths as `[x0, y0, x1, y1, ...]`.
+/// - `path_ranges[i]` is the coordinate span of path `i` inside `points`, using the same layout as
+///   the contour ranges of [`FlatF32ShapesBuffer`].
+/// - `closed_flags[i]` tells whether path `i` is closed.
+/// - `join_kind`, `start_cap_kind` and `end_cap_kind` are encoded as in
+///   [`ishape_stroke_f32_contour_to_flat_styled`].
 ///
-/// Returns `false` when pointers are invalid, coordinate count is odd, or fewer than 3 points are
-/// provided.
+/// Returns [`IShapeStatus::InvalidRange`] when a path range is reversed, out of bounds or not
+/// aligned to a point, [`IShapeStatus::TooFewPoints`] when a path has fewer than 2 points, and the
+/// same style errors as [`ishape_stroke_f32_contour_to_flat_styled`]. A NaN or infinite
+/// coordinate yields [`IShapeStatus::NonFiniteValue`]. With `paths_count == 0` the output is
+/// cleared.
 ///
 /// # Safety
-/// - `points` must either be null with `count == 0` or point to `count` consecutive `f64` values.
-/// - `output` must be a valid pointer to a `FlatF64ShapesBuffer`.
-#[unsafe(no_mangle)]
-pub extern "C" fn ishape_outline_f64_contour_to_flat(
-    points: *const f64,
-    count: usize,
-    offset: f64,
-    output: *mut FlatF64ShapesBuffer,
-) -> bool {
-    if output.is_null() {
-        return false;
-    }
+/// - `points` must either be null with `points_count == 0` or point to `points_count`
+///   consecutive `f32` values.
+/// - `path_ranges` and `closed_flags` must either be null with `paths_count == 0` or point to
+///   `paths_count` consecutive values.
+/// - `output` must be a valid pointer to a `FlatF32ShapesBuffer`.
+#[unsafe(no_mangle)]
+pub extern "C" fn ishape_stroke_f32_paths_to_flat_styled(
+    points: *const f32,
+    points_count: usize,
+    path_ranges: *const RangeFFI,
+    closed_flags: *const bool,
+    paths_count: usize,
+    width: f32,
+    join_kind: u32,
+    join_value: f32,
+    start_cap_kind: u32,
+    start_cap_value: f32,
+    end_cap_kind: u32,
+    end_cap_value: f32,
+    output: *mut FlatF32ShapesBuffer,
+) -> IShapeStatus {
+    guard_status_into(output, || {
+        if output.is_null() {
+            return fail(IShapeStatus::NullPointer, format_args!("`output` is null"));
+        }
 
-    if count == 0 || count % 2 != 0 || points.is_null() {
-        return false;
-    }
+        let paths = FlatPaths {
+            points,
+            points_count,
+            path_ranges,
+            closed_flags,
+            paths_count,
+        };
+
+        let shapes = match stroke_paths(&paths, IShapeNonFinitePolicy::Reject, || {
+            decode_stroke_style(
+                width,
+                join_kind,
+                join_value,
+                start_cap_kind,
+                start_cap_value,
+                end_cap_kind,
+                end_cap_value,
+            )
+        }) {
+            Ok(shapes) => shapes,
+            Err(status) => return status,
+        };
+
+        let buffer = unsafe { &mut *output };
+        buffer.set_shapes(&shapes);
+
+        IShapeStatus::Ok
+    })
+}
+
+/// Builds a single unioned stroke/buffer result from many paths and writes it into a flat `f32`
+/// buffer, using the provided [`FfiStrokeStyle`] for every path.
+///
+/// Paths are described as in [`ishape_stroke_f32_paths_to_flat_styled`], which also lists the
+/// returned errors. Points with a NaN or infinite coordinate follow `style.non_finite`, and
+/// paths it drops are skipped.
+///
+/// # Safety
+/// - `points` must either be null with `points_count == 0` or point to `points_count`
+///   consecutive `f32` values.
+/// - `path_ranges` and `closed_flags` must either be null with `paths_count == 0` or point to
+///   `paths_count` consecutive values.
+/// - `output` must be a valid pointer to a `FlatF32ShapesBuffer`.
+#[unsafe(no_mangle)]
+pub extern "C" fn ishape_stroke_f32_paths_to_flat(
+    points: *const f32,
+    points_count: usize,
+    path_ranges: *const RangeFFI,
+    closed_flags: *const bool,
+    paths_count: usize,
+    style: FfiStrokeStyle,
+    output: *mut FlatF32ShapesBuffer,
+) -> IShapeStatus {
+    guard_status_into(output, || {
+        if output.is_null() {
+            return fail(IShapeStatus::NullPointer, format_args!("`output` is null"));
+        }
 
-    let points_slice = unsafe { slice::from_raw_parts(points, count) };
-    let point_count = points_slice.len() / 2;
-    if point_count < 3 {
-        return false;
-    }
+        let paths = FlatPaths {
+            points,
+            points_count,
+            path_ranges,
+            closed_flags,
+            paths_count,
+        };
 
-    let mut contour: Vec<FloatPoint<f64>> = Vec::with_capacity(point_count);
-    for chunk in points_slice.chunks_exact(2) {
-        contour.push(FloatPoint::new(chunk[0], chunk[1]));
-    }
+        let shapes =
+            match stroke_paths(&paths, style.non_finite, || decode_ffi_stroke_style(&style)) {
+                Ok(shapes) => shapes,
+                Err(status) => return status,
+            };
 
-    // Public API uses positive distance as outward buffer.
-    let style = OutlineStyle::new(-offset);
-    let mut shapes = contour.outline(&style);
+        let buffer = unsafe { &mut *output };
+        buffer.set_shapes(&shapes);
 
-    // Single-contour fast-path in i_overlay is sensitive to winding; retry in reverse order.
-    if shapes.is_empty() {
-        contour.reverse();
-        shapes = contour.outline(&style);
-    }
+        IShapeStatus::Ok
+    })
+}
 
-    let buffer = unsafe { &mut *output };
-    buffer.set_shapes(&shapes);
+/// Builds a single unioned stroke/buffer result from many paths and writes it into a flat `f32`
+/// buffer, using the provided [`FfiStrokeStyle`] with custom start and end cap templates.
+///
+/// Paths are described as in [`ishape_stroke_f32_paths_to_flat_styled`] and cap templates as in
+/// [`ishape_stroke_f32_contour_to_flat_custom_caps`]; every open path uses the same templates.
+///
+/// # Safety
+/// - `points` must either be null with `points_count == 0` or point to `points_count`
+///   consecutive `f32` values.
+/// - `path_ranges` and `closed_flags` must either be null with `paths_count == 0` or point to
+///   `paths_count` consecutive values.
+/// - `start_cap_points` and `end_cap_points` must either be null with a zero count or point to
+///   the given number of consecutive `f32` values.
+/// - `output` must be a valid pointer to a `FlatF32ShapesBuffer`.
+#[unsafe(no_mangle)]
+pub extern "C" fn ishape_stroke_f32_paths_to_flat_custom_caps(
+    points: *const f32,
+    points_count: usize,
+    path_ranges: *const RangeFFI,
+    closed_flags: *const bool,
+    paths_count: usize,
+    style: FfiStrokeStyle,
+    start_cap_points: *const f32,
+    start_cap_count: usize,
+    end_cap_points: *const f32,
+    end_cap_count: usize,
+    output: *mut FlatF32ShapesBuffer,
+) -> IShapeStatus {
+    guard_status_into(output, || {
+        if output.is_null() {
+            return fail(IShapeStatus::NullPointer, format_args!("`output` is null"));
+        }
 
-    true
-}
+        let start_template = match read_cap_template("start", start_cap_points, start_cap_count) {
+            Ok(template) => template,
+            Err(status) => return status,
+        };
 
-#[inline]
-fn decode_line_join(kind: u32, value: f64) -> Option<LineJoin<f64>> {
-    match kind {
-        0 => Some(LineJoin::Bevel),
-        1 => value.is_finite().then_some(LineJoin::Miter(value)),
-        2 => value.is_finite().then_some(LineJoin::Round(value)),
-        _ => None,
-    }
-}
+        let end_template = match read_cap_template("end", end_cap_points, end_cap_count) {
+            Ok(template) => template,
+            Err(status) => return status,
+        };
 
-#[inline]
-fn decode_line_cap(kind: u32, value: f64) -> Option<LineCap<FloatPoint<f64>, f64>> {
-    match kind {
-        0 => Some(LineCap::Butt),
-        1 => value.is_finite().then_some(LineCap::Round(value)),
-        2 => Some(LineCap::Square),
-        _ => None,
-    }
+        let paths = FlatPaths {
+            points,
+            points_count,
+            path_ranges,
+            closed_flags,
+            paths_count,
+        };
+
+        let shapes = match stroke_paths(&paths, style.non_finite, || {
+            decode_custom_caps_stroke_style(&style, &start_template, &end_template)
+        }) {
+            Ok(shapes) => shapes,
+            Err(status) => return status,
+        };
+
+        let buffer = unsafe { &mut *output };
+        buffer.set_shapes(&shapes);
+
+        IShapeStatus::Ok
+    })
 }
 
-/// Builds a stroke/buffer result from a single contour and writes it into a flat `f64` buffer,
-/// using explicit line join and line cap styles.
+/// Builds a stroke/buffer result from a single contour and writes it into caller-provided flat
+/// `f32` arrays, using the provided [`FfiStrokeStyle`].
 ///
-/// - `join_kind`: `0=Bevel`, `1=Miter`, `2=Round`
-/// - `start_cap_kind` and `end_cap_kind`: `0=Butt`, `1=Round`, `2=Square`
+/// Input errors follow [`ishape_stroke_f32_contour_to_flat`] and output sizing follows
+/// [`ishape_outline_f32_contour_to_slices`].
+///
+/// # Safety
+/// - `points` must either be null with `count == 0` or point to `count` consecutive `f32` values.
+/// - `output` must be a valid pointer to a `FlatF32ShapesSlices` whose arrays are either null with zero
+///   capacity or valid for writes of their capacity.
 #[unsafe(no_mangle)]
-pub extern "C" fn ishape_stroke_f64_contour_to_flat_styled(
-    points: *const f64,
+pub extern "C" fn ishape_stroke_f32_contour_to_slices(
+    points: *const f32,
     count: usize,
-    width: f64,
     is_closed_path: bool,
-    join_kind: u32,
-    join_value: f64,
-    start_cap_kind: u32,
-    start_cap_value: f64,
-    end_cap_kind: u32,
-    end_cap_value: f64,
-    output: *mut FlatF64ShapesBuffer,
-) -> bool {
-    if output.is_null() || width <= 0.0 {
-        return false;
-    }
-
-    if count == 0 || count % 2 != 0 || points.is_null() {
-        return false;
-    }
-
-    let points_slice = unsafe { slice::from_raw_parts(points, count) };
-    let point_count = points_slice.len() / 2;
-    if point_count < 2 {
-        return false;
-    }
-
-    let mut contour: Vec<FloatPoint<f64>> = Vec::with_capacity(point_count);
-    for chunk in points_slice.chunks_exact(2) {
-        contour.push(FloatPoint::new(chunk[0], chunk[1]));
-    }
+    style: FfiStrokeStyle,
+    output: *mut FlatF32ShapesSlices,
+) -> IShapeStatus {
+    guard_status_into(output, || {
+        if output.is_null() {
+            return fail(IShapeStatus::NullPointer, format_args!("`output` is null"));
+        }
 
-    let join = if let Some(join) = decode_line_join(join_kind, join_value) {
-        join
-    } else {
-        return false;
-    };
+        let shapes = match stroke_contour(points, count, is_closed_path, style.non_finite, || {
+            decode_ffi_stroke_style(&style)
+        }) {
+            Ok(shapes) => shapes,
+            Err(status) => return status,
+        };
 
-    let start_cap = if let Some(cap) = decode_line_cap(start_cap_kind, start_cap_value) {
-        cap
-    } else {
-        return false;
-    };
+        let slices = unsafe { &mut *output };
+        write_slices(slices, shapes.as_slice())
+    })
+}
 
-    let end_cap = if let Some(cap) = decode_line_cap(end_cap_kind, end_cap_value) {
-        cap
-    } else {
-        return false;
-    };
+/// Builds a single unioned stroke/buffer result from many paths and writes it into
+/// caller-provided flat `f32` arrays, using the provided [`FfiStrokeStyle`] for every path.
+///
+/// Paths and input errors follow [`ishape_stroke_f32_paths_to_flat`] and output sizing follows
+/// [`ishape_outline_f32_contour_to_slices`].
+///
+/// # Safety
+/// - `points` must either be null with `points_count == 0` or point to `points_count`
+///   consecutive `f32` values.
+/// - `path_ranges` and `closed_flags` must either be null with `paths_count == 0` or point to
+///   `paths_count` consecutive values.
+/// - `output` must be a valid pointer to a `FlatF32ShapesSlices` whose arrays are either null with zero
+///   capacity or valid for writes of their capacity.
+#[unsafe(no_mangle)]
+pub extern "C" fn ishape_stroke_f32_paths_to_slices(
+    points: *const f32,
+    points_count: usize,
+    path_ranges: *const RangeFFI,
+    closed_flags: *const bool,
+    paths_count: usize,
+    style: FfiStrokeStyle,
+    output: *mut FlatF32ShapesSlices,
+) -> IShapeStatus {
+    guard_status_into(output, || {
+        if output.is_null() {
+            return fail(IShapeStatus::NullPointer, format_args!("`output` is null"));
+        }
 
-    let style = StrokeStyle::new(width)
-        .line_join(join)
-        .start_cap(start_cap)
-        .end_cap(end_cap);
-    let shapes = contour.stroke(style, is_closed_path);
+        let paths = FlatPaths {
+            points,
+            points_count,
+            path_ranges,
+            closed_flags,
+            paths_count,
+        };
 
-    let buffer = unsafe { &mut *output };
-    buffer.set_shapes(&shapes);
+        let shapes =
+            match stroke_paths(&paths, style.non_finite, || decode_ffi_stroke_style(&style)) {
+                Ok(shapes) => shapes,
+                Err(status) => return status,
+            };
 
-    true
+        let slices = unsafe { &mut *output };
+        write_slices(slices, shapes.as_slice())
+    })
 }
 
 /// Creates a new integer triangulator configured for up to `max_points_count` points.
@@ -964,7 +3302,9 @@ pub extern "C" fn ishape_triangle_int_triangulator_create(
     max_points_count: usize,
     validation: IntTriangulatorValidation,
 ) -> *mut IntTriangulator {
-    Box::into_raw(Box::new(IntTriangulator::new(max_points_count, validation)))
+    guard_or(ptr::null_mut(), || {
+        into_handle(IntTriangulator::new(max_points_count, validation))
+    })
 }
 
 /// Releases a triangulator previously created with [`ishape_triangle_int_triangulator_create`].
@@ -976,17 +3316,97 @@ pub extern "C" fn ishape_triangle_int_triangulator_create(
 /// freed already.
 #[unsafe(no_mangle)]
 pub extern "C" fn ishape_triangle_int_triangulator_free(handle: *mut IntTriangulator) {
-    if handle.is_null() {
-        return;
-    }
+    guard_or((), || {
+        if handle.is_null() {
+            return;
+        }
 
-    unsafe {
-        drop(Box::from_raw(handle));
-    }
+        unsafe {
+            free_handle(handle);
+        }
+    })
+}
+
+/// Runs the configured integer triangulator, writing the result into a flat buffer.
+///
+/// Returns [`IShapeStatus::NullPointer`] if any pointer is null, and
+/// [`IShapeStatus::OddCoordinateCount`] or [`IShapeStatus::InvalidRange`] when the shapes buffer
+/// layout is malformed; otherwise the output buffer is populated and [`IShapeStatus::Ok`] is
+/// returned.
+///
+/// # Safety
+/// All pointers must be valid and uniquely owned for the duration of the call.
+#[unsafe(no_mangle)]
+pub extern "C" fn ishape_triangle_int_triangulator_triangulate_flat(
+    handle: *mut IntTriangulator,
+    shapes: *const FlatShapesBuffer,
+    output: *mut FlatIntTriangulation,
+) -> IShapeStatus {
+    guard_status_into(output, || {
+        if handle.is_null() || shapes.is_null() || output.is_null() {
+            return fail(
+                IShapeStatus::NullPointer,
+                format_args!("`handle`, `shapes` or `output` is null"),
+            );
+        }
+
+        let triangulator = unsafe { &mut *handle };
+        let shapes_buffer = unsafe { &*shapes };
+        let buffer = unsafe { &mut *output };
+
+        match triangulate_int_shapes(triangulator, shapes_buffer) {
+            Ok(triangulation) => {
+                buffer.set_triangulation(triangulation);
+                IShapeStatus::Ok
+            }
+            Err(status) => status,
+        }
+    })
+}
+
+/// Runs the configured integer triangulator, writing the result into caller-provided arrays.
+///
+/// Input errors follow [`ishape_triangle_int_triangulator_triangulate_flat`]. Every `*_len`
+/// field of `output` receives the length the result needs, and
+/// [`IShapeStatus::InsufficientCapacity`] is returned without writing the arrays when any
+/// capacity is too small.
+///
+/// # Safety
+/// - `handle` must be a valid pointer obtained from
+///   [`ishape_triangle_int_triangulator_create`].
+/// - `shapes` must be a valid pointer to a `FlatShapesBuffer`.
+/// - `output` must be a valid pointer to a `FlatIntTriangulationSlices` whose arrays are either null with zero
+///   capacity or valid for writes of their capacity.
+#[unsafe(no_mangle)]
+pub extern "C" fn ishape_triangle_int_triangulator_triangulate_into_slices(
+    handle: *mut IntTriangulator,
+    shapes: *const FlatShapesBuffer,
+    output: *mut FlatIntTriangulationSlices,
+) -> IShapeStatus {
+    guard_status_into(output, || {
+        if handle.is_null() || shapes.is_null() || output.is_null() {
+            return fail(
+                IShapeStatus::NullPointer,
+                format_args!("`handle`, `shapes` or `output` is null"),
+            );
+        }
+
+        let triangulator = unsafe { &mut *handle };
+        let shapes_buffer = unsafe { &*shapes };
+        let slices = unsafe { &mut *output };
+
+        match triangulate_int_shapes(triangulator, shapes_buffer) {
+            Ok(triangulation) => write_slices(slices, triangulation),
+            Err(status) => status,
+        }
+    })
 }
 
 /// Creates a new `f32` triangulator configured for up to `max_points_count` points.
 ///
+/// Input points with a NaN or infinite coordinate are rejected; use
+/// [`ishape_triangle_f32_triangulator_create_with_options`] to choose another policy.
+///
 /// # Safety
 /// The returned pointer must be released with [`ishape_triangle_f32_triangulator_free`].
 #[unsafe(no_mangle)]
@@ -994,34 +3414,56 @@ pub extern "C" fn ishape_triangle_f32_triangulator_create(
     max_points_count: usize,
     validation: IntTriangulatorValidation,
 ) -> *mut Float32Triangulator {
-    Box::into_raw(Box::new(Float32Triangulator::new(
-        max_points_count,
-        validation,
-    )))
+    guard_or(ptr::null_mut(), || {
+        into_handle(Float32Triangulator::new(max_points_count, validation))
+    })
+}
+
+/// Creates a new `f32` triangulator configured for up to `max_points_count` points, with the
+/// validation and non-finite policy given in `options`.
+///
+/// # Safety
+/// The returned pointer must be released with [`ishape_triangle_f32_triangulator_free`].
+#[unsafe(no_mangle)]
+pub extern "C" fn ishape_triangle_f32_triangulator_create_with_options(
+    max_points_count: usize,
+    options: FloatTriangulatorOptions,
+) -> *mut Float32Triangulator {
+    guard_or(ptr::null_mut(), || {
+        into_handle(Float32Triangulator::with_options(max_points_count, options))
+    })
 }
 
-/// Releases a triangulator previously created with [`ishape_triangle_f32_triangulator_create`].
+/// Releases a triangulator previously created with [`ishape_triangle_f32_triangulator_create`]
+/// or [`ishape_triangle_f32_triangulator_create_with_options`].
 ///
 /// Passing a null pointer is a no-op.
 ///
 /// # Safety
-/// The pointer must have been returned by [`ishape_triangle_f32_triangulator_create`] and not
-/// freed already.
+/// The pointer must have been returned by one of those functions and not freed already.
 #[unsafe(no_mangle)]
 pub extern "C" fn ishape_triangle_f32_triangulator_free(handle: *mut Float32Triangulator) {
-    if handle.is_null() {
-        return;
-    }
+    guard_or((), || {
+        if handle.is_null() {
+            return;
+        }
 
-    unsafe {
-        drop(Box::from_raw(handle));
-    }
+        unsafe {
+            free_handle(handle);
+        }
+    })
 }
 
 /// Runs the configured `f32` triangulator, writing the result into a flat buffer.
 ///
-/// Returns `false` if any pointer is null; otherwise the output buffer is populated and `true`
-/// is returned.
+/// Points with a NaN or infinite coordinate follow the `non_finite` option the triangulator was
+/// created with; a shape whose outer contour is dropped is dropped together
+/// with its holes.
+///
+/// Returns [`IShapeStatus::NullPointer`] if any pointer is null,
+/// [`IShapeStatus::OddCoordinateCount`] or [`IShapeStatus::InvalidRange`] when the shapes buffer
+/// layout is malformed, and [`IShapeStatus::NonFiniteValue`] when a non-finite point is
+/// rejected; otherwise the output buffer is populated and [`IShapeStatus::Ok`] is returned.
 ///
 /// # Safety
 /// All pointers must be valid and uniquely owned for the duration of the call.
@@ -1030,34 +3472,72 @@ pub extern "C" fn ishape_triangle_f32_triangulator_triangulate_flat(
     handle: *mut Float32Triangulator,
     shapes: *const FlatF32ShapesBuffer,
     output: *mut FlatF32Triangulation,
-) -> bool {
-    if handle.is_null() || shapes.is_null() || output.is_null() {
-        return false;
-    }
-
-    let triangulator = unsafe { &mut *handle };
-    let shapes_buffer = unsafe { &*shapes };
-    let mut shapes_vec = shapes_buffer.to_shapes();
-
-    let buffer = unsafe { &mut *output };
+) -> IShapeStatus {
+    guard_status_into(output, || {
+        if handle.is_null() || shapes.is_null() || output.is_null() {
+            return fail(
+                IShapeStatus::NullPointer,
+                format_args!("`handle`, `shapes` or `output` is null"),
+            );
+        }
 
-    if shapes_vec.is_empty() {
-        buffer.clear();
-        return true;
-    }
+        let triangulator = unsafe { &mut *handle };
+        let shapes_buffer = unsafe { &*shapes };
+        let buffer = unsafe { &mut *output };
 
-    let triangulation = triangulator.inner.triangulate(&shapes_vec);
+        match triangulate_f32_shapes(triangulator, shapes_buffer) {
+            Ok(triangulation) => {
+                buffer.set_triangulation(triangulation);
+                IShapeStatus::Ok
+            }
+            Err(status) => status,
+        }
+    })
+}
 
-    buffer.set_triangulation(&triangulation);
+/// Runs the configured `f32` triangulator, writing the result into caller-provided arrays.
+///
+/// Input errors follow [`ishape_triangle_f32_triangulator_triangulate_flat`]. Every `*_len`
+/// field of `output` receives the length the result needs, and
+/// [`IShapeStatus::InsufficientCapacity`] is returned without writing the arrays when any
+/// capacity is too small.
+///
+/// # Safety
+/// - `handle` must be a valid pointer obtained from
+///   [`ishape_triangle_f32_triangulator_create`].
+/// - `shapes` must be a valid pointer to a `FlatF32ShapesBuffer`.
+/// - `output` must be a valid pointer to a `FlatF32TriangulationSlices` whose arrays are either null with zero
+///   capacity or valid for writes of their capacity.
+#[unsafe(no_mangle)]
+pub extern "C" fn ishape_triangle_f32_triangulator_triangulate_into_slices(
+    handle: *mut Float32Triangulator,
+    shapes: *const FlatF32ShapesBuffer,
+    output: *mut FlatF32TriangulationSlices,
+) -> IShapeStatus {
+    guard_status_into(output, || {
+        if handle.is_null() || shapes.is_null() || output.is_null() {
+            return fail(
+                IShapeStatus::NullPointer,
+                format_args!("`handle`, `shapes` or `output` is null"),
+            );
+        }
 
-    // Release memory early.
-    shapes_vec.clear();
+        let triangulator = unsafe { &mut *handle };
+        let shapes_buffer = unsafe { &*shapes };
+        let slices = unsafe { &mut *output };
 
-    true
+        match triangulate_f32_shapes(triangulator, shapes_buffer) {
+            Ok(triangulation) => write_slices(slices, triangulation),
+            Err(status) => status,
+        }
+    })
 }
 
 /// Creates a new `f64` triangulator configured for up to `max_points_count` points.
 ///
+/// Input points with a NaN or infinite coordinate are rejected; use
+/// [`ishape_triangle_f64_triangulator_create_with_options`] to choose another policy.
+///
 /// # Safety
 /// The returned pointer must be released with [`ishape_triangle_f64_triangulator_free`].
 #[unsafe(no_mangle)]
@@ -1065,34 +3545,56 @@ pub extern "C" fn ishape_triangle_f64_triangulator_create(
     max_points_count: usize,
     validation: IntTriangulatorValidation,
 ) -> *mut Float64Triangulator {
-    Box::into_raw(Box::new(Float64Triangulator::new(
-        max_points_count,
-        validation,
-    )))
+    guard_or(ptr::null_mut(), || {
+        into_handle(Float64Triangulator::new(max_points_count, validation))
+    })
+}
+
+/// Creates a new `f64` triangulator configured for up to `max_points_count` points, with the
+/// validation and non-finite policy given in `options`.
+///
+/// # Safety
+/// The returned pointer must be released with [`ishape_triangle_f64_triangulator_free`].
+#[unsafe(no_mangle)]
+pub extern "C" fn ishape_triangle_f64_triangulator_create_with_options(
+    max_points_count: usize,
+    options: FloatTriangulatorOptions,
+) -> *mut Float64Triangulator {
+    guard_or(ptr::null_mut(), || {
+        into_handle(Float64Triangulator::with_options(max_points_count, options))
+    })
 }
 
-/// Releases a triangulator previously created with [`ishape_triangle_f64_triangulator_create`].
+/// Releases a triangulator previously created with [`ishape_triangle_f64_triangulator_create`]
+/// or [`ishape_triangle_f64_triangulator_create_with_options`].
 ///
 /// Passing a null pointer is a no-op.
 ///
 /// # Safety
-/// The pointer must have been returned by [`ishape_triangle_f64_triangulator_create`] and not
-/// freed already.
+/// The pointer must have been returned by one of those functions and not freed already.
 #[unsafe(no_mangle)]
 pub extern "C" fn ishape_triangle_f64_triangulator_free(handle: *mut Float64Triangulator) {
-    if handle.is_null() {
-        return;
-    }
+    guard_or((), || {
+        if handle.is_null() {
+            return;
+        }
 
-    unsafe {
-        drop(Box::from_raw(handle));
-    }
+        unsafe {
+            free_handle(handle);
+        }
+    })
 }
 
 /// Runs the configured `f64` triangulator, writing the result into a flat buffer.
 ///
-/// Returns `false` if any pointer is null; otherwise the output buffer is populated and `true`
-/// is returned.
+/// Points with a NaN or infinite coordinate follow the `non_finite` option the triangulator was
+/// created with; a shape whose outer contour is dropped is dropped together
+/// with its holes.
+///
+/// Returns [`IShapeStatus::NullPointer`] if any pointer is null,
+/// [`IShapeStatus::OddCoordinateCount`] or [`IShapeStatus::InvalidRange`] when the shapes buffer
+/// layout is malformed, and [`IShapeStatus::NonFiniteValue`] when a non-finite point is
+/// rejected; otherwise the output buffer is populated and [`IShapeStatus::Ok`] is returned.
 ///
 /// # Safety
 /// All pointers must be valid and uniquely owned for the duration of the call.
@@ -1101,27 +3603,63 @@ pub extern "C" fn ishape_triangle_f64_triangulator_triangulate_flat(
     handle: *mut Float64Triangulator,
     shapes: *const FlatF64ShapesBuffer,
     output: *mut FlatF64Triangulation,
-) -> bool {
-    if handle.is_null() || shapes.is_null() || output.is_null() {
-        return false;
-    }
-
-    let triangulator = unsafe { &mut *handle };
-    let shapes_buffer = unsafe { &*shapes };
-    let mut shapes_vec = shapes_buffer.to_shapes();
-
-    let buffer = unsafe { &mut *output };
+) -> IShapeStatus {
+    guard_status_into(output, || {
+        if handle.is_null() || shapes.is_null() || output.is_null() {
+            return fail(
+                IShapeStatus::NullPointer,
+                format_args!("`handle`, `shapes` or `output` is null"),
+            );
+        }
 
-    if shapes_vec.is_empty() {
-        buffer.clear();
-        return true;
-    }
+        let triangulator = unsafe { &mut *handle };
+        let shapes_buffer = unsafe { &*shapes };
+        let buffer = unsafe { &mut *output };
 
-    let triangulation = triangulator.inner.triangulate(&shapes_vec);
+        match triangulate_f64_shapes(triangulator, shapes_buffer) {
+            Ok(triangulation) => {
+                buffer.set_triangulation(triangulation);
+                IShapeStatus::Ok
+            }
+            Err(status) => status,
+        }
+    })
+}
 
-    buffer.set_triangulation(&triangulation);
+/// Runs the configured `f64` triangulator, writing the result into caller-provided arrays.
+///
+/// Input errors follow [`ishape_triangle_f64_triangulator_triangulate_flat`]. Every `*_len`
+/// field of `output` receives the length the result needs, and
+/// [`IShapeStatus::InsufficientCapacity`] is returned without writing the arrays when any
+/// capacity is too small.
+///
+/// # Safety
+/// - `handle` must be a valid pointer obtained from
+///   [`ishape_triangle_f64_triangulator_create`].
+/// - `shapes` must be a valid pointer to a `FlatF64ShapesBuffer`.
+/// - `output` must be a valid pointer to a `FlatF64TriangulationSlices` whose arrays are either null with zero
+///   capacity or valid for writes of their capacity.
+#[unsafe(no_mangle)]
+pub extern "C" fn ishape_triangle_f64_triangulator_triangulate_into_slices(
+    handle: *mut Float64Triangulator,
+    shapes: *const FlatF64ShapesBuffer,
+    output: *mut FlatF64TriangulationSlices,
+) -> IShapeStatus {
+    guard_status_into(output, || {
+        if handle.is_null() || shapes.is_null() || output.is_null() {
+            return fail(
+                IShapeStatus::NullPointer,
+                format_args!("`handle`, `shapes` or `output` is null"),
+            );
+        }
 
-    shapes_vec.clear();
+        let triangulator = unsafe { &mut *handle };
+        let shapes_buffer = unsafe { &*shapes };
+        let slices = unsafe { &mut *output };
 
-    true
+        match triangulate_f64_shapes(triangulator, shapes_buffer) {
+            Ok(triangulation) => write_slices(slices, triangulation),
+            Err(status) => status,
+        }
+    })
 }
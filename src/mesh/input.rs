@@ -0,0 +1,223 @@
+use alloc::vec::Vec;
+use core::slice;
+use i_triangle::i_overlay::i_float::float::number::FloatNumber;
+use i_triangle::i_overlay::i_float::float::point::FloatPoint;
+use i_triangle::i_overlay::i_shape::base::data::{Contour, Path};
+
+use crate::shape::{IShapeNonFinitePolicy, RangeFFI};
+use crate::status::{IShapeStatus, fail};
+
+/// Reads a flat `[x0, y0, x1, y1, ...]` contour holding at least `min_points` points.
+///
+/// Returns `None` when `policy` drops the contour or leaves it with fewer than `min_points`
+/// points.
+#[inline]
+pub(crate) fn read_contour<T: FloatNumber>(
+    points: *const T,
+    count: usize,
+    min_points: usize,
+    policy: IShapeNonFinitePolicy,
+) -> Result<Option<Contour<FloatPoint<T>>>, IShapeStatus> {
+    if count == 0 {
+        return Err(fail(
+            IShapeStatus::TooFewPoints,
+            format_args!("contour is empty, at least {min_points} points are required"),
+        ));
+    }
+
+    if !count.is_multiple_of(2) {
+        return Err(fail(
+            IShapeStatus::OddCoordinateCount,
+            format_args!("contour has {count} coordinates"),
+        ));
+    }
+
+    if points.is_null() {
+        return Err(fail(
+            IShapeStatus::NullPointer,
+            format_args!("`points` is null but `count` is {count}"),
+        ));
+    }
+
+    let points_slice = unsafe { slice::from_raw_parts(points, count) };
+    let point_count = points_slice.len() / 2;
+    if point_count < min_points {
+        return Err(fail(
+            IShapeStatus::TooFewPoints,
+            format_args!("contour has {point_count} points, at least {min_points} are required"),
+        ));
+    }
+
+    let mut contour = slice_to_path(points_slice);
+    let is_kept = policy
+        .apply(&mut contour, 0)
+        .map_err(|err| fail(err.into(), format_args!("{err}")))?;
+
+    Ok((is_kept && contour.len() >= min_points).then_some(contour))
+}
+
+/// Reads a custom cap template, returning `None` for an empty one.
+#[inline]
+pub(crate) fn read_cap_template<T: FloatNumber>(
+    name: &str,
+    points: *const T,
+    count: usize,
+) -> Result<Option<Vec<FloatPoint<T>>>, IShapeStatus> {
+    if count == 0 {
+        return Ok(None);
+    }
+
+    if !count.is_multiple_of(2) {
+        return Err(fail(
+            IShapeStatus::OddCoordinateCount,
+            format_args!("{name} cap template has {count} coordinates"),
+        ));
+    }
+
+    if points.is_null() {
+        return Err(fail(
+            IShapeStatus::NullPointer,
+            format_args!("{name} cap template is null but its count is {count}"),
+        ));
+    }
+
+    let points_slice = unsafe { slice::from_raw_parts(points, count) };
+
+    let mut template: Vec<FloatPoint<T>> = Vec::with_capacity(count / 2);
+    for (index, chunk) in points_slice.chunks_exact(2).enumerate() {
+        if !chunk[0].to_f64().is_finite() || !chunk[1].to_f64().is_finite() {
+            return Err(fail(
+                IShapeStatus::InvalidLineCap,
+                format_args!("{name} cap template point {index} is not finite"),
+            ));
+        }
+
+        template.push(FloatPoint::new(chunk[0], chunk[1]));
+    }
+
+    Ok(Some(template))
+}
+
+/// Raw path collection arguments shared by the multi-path stroke entry points.
+pub(crate) struct FlatPaths<T> {
+    pub(crate) points: *const T,
+    pub(crate) points_count: usize,
+    pub(crate) path_ranges: *const RangeFFI,
+    pub(crate) closed_flags: *const bool,
+    pub(crate) paths_count: usize,
+}
+
+/// Paths split by their closed flag.
+pub(crate) struct SplitPaths<T: FloatNumber> {
+    pub(crate) closed: Vec<Path<FloatPoint<T>>>,
+    pub(crate) open: Vec<Path<FloatPoint<T>>>,
+}
+
+impl<T: FloatNumber> FlatPaths<T> {
+    /// Validates every path range and splits the paths into closed and open groups.
+    ///
+    /// Paths that `policy` drops or leaves with fewer than `min_points` points are skipped.
+    #[inline]
+    pub(crate) fn read(
+        &self,
+        min_points: usize,
+        policy: IShapeNonFinitePolicy,
+    ) -> Result<SplitPaths<T>, IShapeStatus> {
+        let mut split = SplitPaths {
+            closed: Vec::new(),
+            open: Vec::new(),
+        };
+
+        let paths_count = self.paths_count;
+        if paths_count == 0 {
+            return Ok(split);
+        }
+
+        if self.path_ranges.is_null() || self.closed_flags.is_null() {
+            return Err(fail(
+                IShapeStatus::NullPointer,
+                format_args!(
+                    "`path_ranges` or `closed_flags` is null but `paths_count` is {paths_count}"
+                ),
+            ));
+        }
+
+        let points_count = self.points_count;
+        if !points_count.is_multiple_of(2) {
+            return Err(fail(
+                IShapeStatus::OddCoordinateCount,
+                format_args!("paths have {points_count} coordinates"),
+            ));
+        }
+
+        let points_slice: &[T] = if points_count == 0 {
+            &[]
+        } else {
+            if self.points.is_null() {
+                return Err(fail(
+                    IShapeStatus::NullPointer,
+                    format_args!("`points` is null but `points_count` is {points_count}"),
+                ));
+            }
+
+            unsafe { slice::from_raw_parts(self.points, points_count) }
+        };
+
+        let ranges = unsafe { slice::from_raw_parts(self.path_ranges, paths_count) };
+        let flags = unsafe { slice::from_raw_parts(self.closed_flags, paths_count) };
+
+        for (index, (range, &is_closed)) in ranges.iter().zip(flags).enumerate() {
+            let Some(point_range) = range.to_point_range(points_count) else {
+                return Err(fail(
+                    IShapeStatus::InvalidRange,
+                    format_args!(
+                        "path {index} range {}..{} does not fit {points_count} coordinates",
+                        range.start, range.end
+                    ),
+                ));
+            };
+
+            let path_slice = &points_slice[point_range];
+            let point_count = path_slice.len() / 2;
+            if point_count < min_points {
+                return Err(fail(
+                    IShapeStatus::TooFewPoints,
+                    format_args!(
+                        "path {index} has {point_count} points, at least {min_points} are required"
+                    ),
+                ));
+            }
+
+            let mut path = slice_to_path(path_slice);
+            let is_kept = policy.apply(&mut path, index).map_err(|err| {
+                fail(
+                    err.into(),
+                    format_args!(
+                        "path {index} point {} has a non-finite coordinate",
+                        err.point
+                    ),
+                )
+            })?;
+            if !is_kept || path.len() < min_points {
+                continue;
+            }
+
+            if is_closed {
+                split.closed.push(path);
+            } else {
+                split.open.push(path);
+            }
+        }
+
+        Ok(split)
+    }
+}
+
+#[inline]
+fn slice_to_path<T: FloatNumber>(slice: &[T]) -> Path<FloatPoint<T>> {
+    let mut path = Vec::with_capacity(slice.len() / 2);
+    for chunk in slice.chunks_exact(2) {
+        path.push(FloatPoint::new(chunk[0], chunk[1]));
+    }
+    path
+}
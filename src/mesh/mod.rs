@@ -0,0 +1,9 @@
+pub(crate) mod input;
+pub(crate) mod outline;
+pub(crate) mod stroke;
+pub(crate) mod style;
+pub mod types;
+
+pub use types::{
+    FfiLineCap, FfiLineCapKind, FfiLineJoin, FfiLineJoinKind, FfiOutlineStyle, FfiStrokeStyle,
+};
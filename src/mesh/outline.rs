@@ -0,0 +1,115 @@
+use alloc::vec::Vec;
+use i_triangle::i_overlay::i_float::float::number::FloatNumber;
+use i_triangle::i_overlay::i_float::float::point::FloatPoint;
+use i_triangle::i_overlay::i_shape::base::data::Shapes;
+use i_triangle::i_overlay::mesh::outline::offset::OutlineOffset;
+use i_triangle::i_overlay::mesh::style::OutlineStyle;
+
+use super::input::read_contour;
+use super::style::{decode_ffi_line_join, decode_line_join};
+use super::types::FfiOutlineStyle;
+use crate::shape::IShapeNonFinitePolicy;
+use crate::status::{IShapeStatus, fail};
+
+/// Offsets a single flat contour holding at least 3 points.
+///
+/// Returns no shapes when `policy` drops the contour.
+#[inline]
+pub(crate) fn outline_contour<T: FloatNumber + 'static>(
+    points: *const T,
+    count: usize,
+    style: &OutlineStyle<T>,
+    policy: IShapeNonFinitePolicy,
+) -> Result<Shapes<FloatPoint<T>>, IShapeStatus> {
+    let Some(mut contour) = read_contour(points, count, 3, policy)? else {
+        return Ok(Vec::new());
+    };
+
+    let mut shapes = contour.outline(style);
+
+    // Single-contour fast-path in i_overlay is sensitive to winding; retry in reverse order.
+    if shapes.is_empty() {
+        contour.reverse();
+        shapes = contour.outline(style);
+    }
+
+    Ok(shapes)
+}
+
+/// Offsets every shape, holes included.
+///
+/// Contours with fewer than 3 points enclose no area and are skipped, as the core outline builder
+/// does not accept them; a shape whose outer contour is skipped is dropped together with its holes.
+#[inline]
+pub(crate) fn outline_shapes<T: FloatNumber + 'static>(
+    mut shapes: Shapes<FloatPoint<T>>,
+    style: &OutlineStyle<T>,
+) -> Shapes<FloatPoint<T>> {
+    shapes.retain_mut(|shape| {
+        if shape.first().is_none_or(|outer| outer.len() < 3) {
+            return false;
+        }
+        shape.retain(|contour| contour.len() >= 3);
+        true
+    });
+
+    if shapes.is_empty() {
+        return shapes;
+    }
+
+    shapes.outline(style)
+}
+
+/// Builds an outline style from a single offset, which must be finite.
+///
+/// FFI offsets are positive inwards, the reverse of the core, so they are negated here.
+#[inline]
+pub(crate) fn offset_style<T: FloatNumber>(offset: T) -> Result<OutlineStyle<T>, IShapeStatus> {
+    check_offset("offset", offset)?;
+
+    Ok(OutlineStyle::new(-offset))
+}
+
+/// Builds an outline style from explicit outer and inner offsets and a line join, negating the
+/// offsets as [`offset_style`] does.
+#[inline]
+pub(crate) fn outline_style<T: FloatNumber>(
+    outer_offset: T,
+    inner_offset: T,
+    join_kind: u32,
+    join_value: T,
+) -> Result<OutlineStyle<T>, IShapeStatus> {
+    check_offset("outer offset", outer_offset)?;
+    check_offset("inner offset", inner_offset)?;
+    let join = decode_line_join(join_kind, join_value)?;
+
+    Ok(OutlineStyle::new(-outer_offset)
+        .inner_offset(-inner_offset)
+        .line_join(join))
+}
+
+/// Validates an [`FfiOutlineStyle`] and converts it into a core outline style.
+#[inline]
+pub(crate) fn decode_ffi_outline_style<T: FloatNumber>(
+    style: &FfiOutlineStyle,
+) -> Result<OutlineStyle<T>, IShapeStatus> {
+    // Values are checked after the conversion, which can overflow to infinity for `f32`.
+    let outline_style = style.to_outline_style::<T>();
+    check_offset("outer offset", outline_style.outer_offset)?;
+    check_offset("inner offset", outline_style.inner_offset)?;
+    decode_ffi_line_join::<T>(style.join)?;
+
+    Ok(outline_style)
+}
+
+#[inline]
+fn check_offset<T: FloatNumber>(name: &str, offset: T) -> Result<(), IShapeStatus> {
+    if !offset.to_f64().is_finite() {
+        return Err(fail(
+            IShapeStatus::NonFiniteValue,
+            format_args!("{name} is {offset}"),
+        ));
+    }
+
+    Ok(())
+}
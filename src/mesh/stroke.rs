@@ -0,0 +1,76 @@
+use alloc::vec::Vec;
+use i_triangle::i_overlay::core::fill_rule::FillRule;
+use i_triangle::i_overlay::core::overlay_rule::OverlayRule;
+use i_triangle::i_overlay::core::solver::Solver;
+use i_triangle::i_overlay::float::overlay::FloatOverlay;
+use i_triangle::i_overlay::i_float::float::number::FloatNumber;
+use i_triangle::i_overlay::i_float::float::point::FloatPoint;
+use i_triangle::i_overlay::i_shape::base::data::Shapes;
+use i_triangle::i_overlay::mesh::stroke::offset::StrokeOffset;
+use i_triangle::i_overlay::mesh::style::StrokeStyle;
+
+use super::input::{FlatPaths, read_contour};
+use crate::shape::IShapeNonFinitePolicy;
+use crate::status::IShapeStatus;
+
+/// Strokes a single flat path holding at least 2 points.
+///
+/// Returns no shapes when `policy` drops the path.
+#[inline]
+pub(crate) fn stroke_contour<T, F>(
+    points: *const T,
+    count: usize,
+    is_closed_path: bool,
+    policy: IShapeNonFinitePolicy,
+    make_style: F,
+) -> Result<Shapes<FloatPoint<T>>, IShapeStatus>
+where
+    T: FloatNumber + 'static,
+    F: Fn() -> Result<StrokeStyle<FloatPoint<T>, T>, IShapeStatus>,
+{
+    let contour = read_contour(points, count, 2, policy)?;
+    let style = make_style()?;
+
+    Ok(contour.map_or_else(Vec::new, |contour| contour.stroke(style, is_closed_path)))
+}
+
+/// Strokes every path of a flat path collection and unions the result.
+///
+/// `make_style` is called once per closed/open group because core stroke styles are not `Clone`.
+#[inline]
+pub(crate) fn stroke_paths<T, F>(
+    paths: &FlatPaths<T>,
+    policy: IShapeNonFinitePolicy,
+    make_style: F,
+) -> Result<Shapes<FloatPoint<T>>, IShapeStatus>
+where
+    T: FloatNumber + 'static,
+    F: Fn() -> Result<StrokeStyle<FloatPoint<T>, T>, IShapeStatus>,
+{
+    let style = make_style()?;
+    let split = paths.read(2, policy)?;
+
+    let shapes = if split.open.is_empty() {
+        split.closed.stroke(style, true)
+    } else if split.closed.is_empty() {
+        split.open.stroke(style, false)
+    } else {
+        let closed_shapes = split.closed.stroke(make_style()?, true);
+        let open_shapes = split.open.stroke(style, false);
+
+        let solver = Solver {
+            multithreading: None,
+            ..Default::default()
+        };
+
+        FloatOverlay::with_subj_and_clip_custom(
+            &closed_shapes,
+            &open_shapes,
+            Default::default(),
+            solver,
+        )
+        .overlay(OverlayRule::Union, FillRule::NonZero)
+    };
+
+    Ok(shapes)
+}
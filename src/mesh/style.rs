@@ -0,0 +1,174 @@
+use alloc::vec::Vec;
+use i_triangle::i_overlay::i_float::float::number::FloatNumber;
+use i_triangle::i_overlay::i_float::float::point::FloatPoint;
+use i_triangle::i_overlay::mesh::style::{LineCap, LineJoin, StrokeStyle};
+
+use super::types::{FfiLineCap, FfiLineCapKind, FfiLineJoin, FfiLineJoinKind, FfiStrokeStyle};
+use crate::status::{IShapeStatus, fail};
+
+/// Decodes a loose `join_kind`/`join_value` pair: `0=Bevel`, `1=Miter`, `2=Round`.
+#[inline]
+pub(crate) fn decode_line_join<T: FloatNumber>(
+    kind: u32,
+    value: T,
+) -> Result<LineJoin<T>, IShapeStatus> {
+    let is_finite = value.to_f64().is_finite();
+    let join = match kind {
+        0 => Some(LineJoin::Bevel),
+        1 => is_finite.then_some(LineJoin::Miter(value)),
+        2 => is_finite.then_some(LineJoin::Round(value)),
+        _ => {
+            return Err(fail(
+                IShapeStatus::InvalidLineJoin,
+                format_args!("unknown join kind {kind}"),
+            ));
+        }
+    };
+
+    join.ok_or_else(|| {
+        fail(
+            IShapeStatus::InvalidLineJoin,
+            format_args!("join kind {kind} rejects non-finite value {value}"),
+        )
+    })
+}
+
+/// Converts an [`FfiLineJoin`] to `T`, whose value must stay finite unless the join is a bevel.
+#[inline]
+pub(crate) fn decode_ffi_line_join<T: FloatNumber>(
+    join: FfiLineJoin,
+) -> Result<LineJoin<T>, IShapeStatus> {
+    let value = T::from_float(join.value);
+    if join.kind != FfiLineJoinKind::Bevel && !value.to_f64().is_finite() {
+        return Err(fail(
+            IShapeStatus::InvalidLineJoin,
+            format_args!("join {:?} rejects non-finite value {value}", join.kind),
+        ));
+    }
+
+    Ok(join.to_line_join())
+}
+
+/// Decodes a loose `cap_kind`/`cap_value` pair: `0=Butt`, `1=Round`, `2=Square`.
+#[inline]
+pub(crate) fn decode_line_cap<T: FloatNumber>(
+    kind: u32,
+    value: T,
+) -> Result<LineCap<FloatPoint<T>, T>, IShapeStatus> {
+    let cap = match kind {
+        0 => Some(LineCap::Butt),
+        1 => value.to_f64().is_finite().then_some(LineCap::Round(value)),
+        2 => Some(LineCap::Square),
+        _ => {
+            return Err(fail(
+                IShapeStatus::InvalidLineCap,
+                format_args!("unknown cap kind {kind}"),
+            ));
+        }
+    };
+
+    cap.ok_or_else(|| {
+        fail(
+            IShapeStatus::InvalidLineCap,
+            format_args!("cap kind {kind} rejects non-finite value {value}"),
+        )
+    })
+}
+
+/// Decodes the loose scalar stroke style parameters.
+#[inline]
+pub(crate) fn decode_stroke_style<T: FloatNumber>(
+    width: T,
+    join_kind: u32,
+    join_value: T,
+    start_cap_kind: u32,
+    start_cap_value: T,
+    end_cap_kind: u32,
+    end_cap_value: T,
+) -> Result<StrokeStyle<FloatPoint<T>, T>, IShapeStatus> {
+    check_stroke_width(width.to_f64())?;
+
+    let join = decode_line_join(join_kind, join_value)?;
+    let start_cap = decode_line_cap(start_cap_kind, start_cap_value)?;
+    let end_cap = decode_line_cap(end_cap_kind, end_cap_value)?;
+
+    Ok(StrokeStyle::new(width)
+        .line_join(join)
+        .start_cap(start_cap)
+        .end_cap(end_cap))
+}
+
+/// Validates an [`FfiStrokeStyle`] and converts it into a core stroke style.
+///
+/// Values are checked after the conversion, which can overflow to infinity for `f32`.
+#[inline]
+pub(crate) fn decode_ffi_stroke_style<T: FloatNumber>(
+    style: &FfiStrokeStyle,
+) -> Result<StrokeStyle<FloatPoint<T>, T>, IShapeStatus> {
+    let width = T::from_float(style.width);
+    check_stroke_width(width.to_f64())?;
+    let join = decode_ffi_line_join(style.join)?;
+    let start_cap = decode_ffi_line_cap("start", style.start_cap)?;
+    let end_cap = decode_ffi_line_cap("end", style.end_cap)?;
+
+    Ok(StrokeStyle::new(width)
+        .line_join(join)
+        .start_cap(start_cap)
+        .end_cap(end_cap))
+}
+
+/// Converts an [`FfiLineCap`] to `T`, whose value must stay finite for a round cap.
+#[inline]
+fn decode_ffi_line_cap<T: FloatNumber>(
+    name: &str,
+    cap: FfiLineCap,
+) -> Result<LineCap<FloatPoint<T>, T>, IShapeStatus> {
+    let value = T::from_float(cap.value);
+    if cap.kind == FfiLineCapKind::Round && !value.to_f64().is_finite() {
+        return Err(fail(
+            IShapeStatus::InvalidLineCap,
+            format_args!("{name} cap {:?} rejects non-finite value {value}", cap.kind),
+        ));
+    }
+
+    Ok(cap.to_line_cap())
+}
+
+/// Decodes `style`, replacing its caps with the provided custom templates.
+#[inline]
+pub(crate) fn decode_custom_caps_stroke_style<T: FloatNumber>(
+    style: &FfiStrokeStyle,
+    start_template: &Option<Vec<FloatPoint<T>>>,
+    end_template: &Option<Vec<FloatPoint<T>>>,
+) -> Result<StrokeStyle<FloatPoint<T>, T>, IShapeStatus> {
+    // Caps replaced by a template are not read, so they must not fail validation.
+    let mut base = *style;
+    if start_template.is_some() {
+        base.start_cap = FfiLineCap::default();
+    }
+    if end_template.is_some() {
+        base.end_cap = FfiLineCap::default();
+    }
+
+    let mut stroke_style = decode_ffi_stroke_style(&base)?;
+    if let Some(template) = start_template {
+        stroke_style = stroke_style.start_cap(LineCap::Custom(template.clone()));
+    }
+    if let Some(template) = end_template {
+        stroke_style = stroke_style.end_cap(LineCap::Custom(template.clone()));
+    }
+
+    Ok(stroke_style)
+}
+
+#[inline]
+fn check_stroke_width(width: f64) -> Result<(), IShapeStatus> {
+    if !width.is_finite() || width <= 0.0 {
+        return Err(fail(
+            IShapeStatus::InvalidWidth,
+            format_args!("width is {width}"),
+        ));
+    }
+
+    Ok(())
+}
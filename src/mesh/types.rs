@@ -0,0 +1,223 @@
+use i_triangle::i_overlay::i_float::float::number::FloatNumber;
+use i_triangle::i_overlay::i_float::float::point::FloatPoint;
+use i_triangle::i_overlay::mesh::style::{LineCap, LineJoin, OutlineStyle, StrokeStyle};
+
+use crate::shape::IShapeNonFinitePolicy;
+
+/// FFI-safe enum mirroring the variants of `i_overlay::mesh::style::LineJoin`.
+#[repr(C)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum FfiLineJoinKind {
+    #[default]
+    Bevel = 0,
+    Miter = 1,
+    Round = 2,
+}
+
+/// FFI-safe representation of `LineJoin<f64>`.
+///
+/// `value` is the miter limit angle for `Miter` and the segment ratio for `Round`; it is ignored
+/// for `Bevel`.
+#[repr(C)]
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub struct FfiLineJoin {
+    pub kind: FfiLineJoinKind,
+    pub value: f64,
+}
+
+impl FfiLineJoin {
+    /// Converts the join into a core `LineJoin` with the requested float precision.
+    #[inline]
+    pub fn to_line_join<T: FloatNumber>(&self) -> LineJoin<T> {
+        match self.kind {
+            FfiLineJoinKind::Bevel => LineJoin::Bevel,
+            FfiLineJoinKind::Miter => LineJoin::Miter(T::from_float(self.value)),
+            FfiLineJoinKind::Round => LineJoin::Round(T::from_float(self.value)),
+        }
+    }
+}
+
+impl From<FfiLineJoin> for LineJoin<f64> {
+    #[inline]
+    fn from(value: FfiLineJoin) -> Self {
+        value.to_line_join()
+    }
+}
+
+impl From<FfiLineJoin> for LineJoin<f32> {
+    #[inline]
+    fn from(value: FfiLineJoin) -> Self {
+        value.to_line_join()
+    }
+}
+
+impl From<LineJoin<f64>> for FfiLineJoin {
+    #[inline]
+    fn from(value: LineJoin<f64>) -> Self {
+        match value {
+            LineJoin::Bevel => Self {
+                kind: FfiLineJoinKind::Bevel,
+                value: 0.0,
+            },
+            LineJoin::Miter(value) => Self {
+                kind: FfiLineJoinKind::Miter,
+                value,
+            },
+            LineJoin::Round(value) => Self {
+                kind: FfiLineJoinKind::Round,
+                value,
+            },
+        }
+    }
+}
+
+/// FFI-safe enum mirroring the variants of `i_overlay::mesh::style::LineCap`.
+#[repr(C)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum FfiLineCapKind {
+    #[default]
+    Butt = 0,
+    Round = 1,
+    Square = 2,
+}
+
+/// FFI-safe representation of `LineCap<FloatPoint<f64>, f64>`.
+///
+/// `value` is the segment angle for `Round`; it is ignored for `Butt` and `Square`.
+#[repr(C)]
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub struct FfiLineCap {
+    pub kind: FfiLineCapKind,
+    pub value: f64,
+}
+
+impl FfiLineCap {
+    /// Converts the cap into a core `LineCap` with the requested float precision.
+    #[inline]
+    pub fn to_line_cap<T: FloatNumber>(&self) -> LineCap<FloatPoint<T>, T> {
+        match self.kind {
+            FfiLineCapKind::Butt => LineCap::Butt,
+            FfiLineCapKind::Round => LineCap::Round(T::from_float(self.value)),
+            FfiLineCapKind::Square => LineCap::Square,
+        }
+    }
+}
+
+impl From<FfiLineCap> for LineCap<FloatPoint<f64>, f64> {
+    #[inline]
+    fn from(value: FfiLineCap) -> Self {
+        value.to_line_cap()
+    }
+}
+
+impl From<FfiLineCap> for LineCap<FloatPoint<f32>, f32> {
+    #[inline]
+    fn from(value: FfiLineCap) -> Self {
+        value.to_line_cap()
+    }
+}
+
+/// FFI-safe options struct mirroring `i_overlay::mesh::style::StrokeStyle<FloatPoint<f64>, f64>`,
+/// plus the policy for non-finite input coordinates.
+#[repr(C)]
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct FfiStrokeStyle {
+    pub width: f64,
+    pub join: FfiLineJoin,
+    pub start_cap: FfiLineCap,
+    pub end_cap: FfiLineCap,
+    /// Applied to every stroked path.
+    pub non_finite: IShapeNonFinitePolicy,
+}
+
+impl FfiStrokeStyle {
+    /// Converts the style into a core `StrokeStyle` with the requested float precision.
+    #[inline]
+    pub fn to_stroke_style<T: FloatNumber>(&self) -> StrokeStyle<FloatPoint<T>, T> {
+        StrokeStyle::new(T::from_float(self.width))
+            .line_join(self.join.to_line_join())
+            .start_cap(self.start_cap.to_line_cap())
+            .end_cap(self.end_cap.to_line_cap())
+    }
+}
+
+impl From<FfiStrokeStyle> for StrokeStyle<FloatPoint<f64>, f64> {
+    #[inline]
+    fn from(value: FfiStrokeStyle) -> Self {
+        value.to_stroke_style()
+    }
+}
+
+impl From<FfiStrokeStyle> for StrokeStyle<FloatPoint<f32>, f32> {
+    #[inline]
+    fn from(value: FfiStrokeStyle) -> Self {
+        value.to_stroke_style()
+    }
+}
+
+impl Default for FfiStrokeStyle {
+    #[inline]
+    fn default() -> Self {
+        let core = StrokeStyle::<FloatPoint<f64>, f64>::default();
+        Self {
+            width: core.width,
+            join: core.join.into(),
+            start_cap: FfiLineCap::default(),
+            end_cap: FfiLineCap::default(),
+            non_finite: IShapeNonFinitePolicy::default(),
+        }
+    }
+}
+
+/// FFI-safe options struct mirroring `i_overlay::mesh::style::OutlineStyle<f64>`, plus the policy
+/// for non-finite input coordinates.
+///
+/// Positive offsets deflate, as in `ishape_outline_f64_contour_to_flat`, which is the reverse of
+/// the core style.
+#[repr(C)]
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct FfiOutlineStyle {
+    pub outer_offset: f64,
+    pub inner_offset: f64,
+    pub join: FfiLineJoin,
+    /// Applied to every offset contour.
+    pub non_finite: IShapeNonFinitePolicy,
+}
+
+impl FfiOutlineStyle {
+    /// Converts the style into a core `OutlineStyle` with the requested float precision, negating
+    /// the offsets.
+    #[inline]
+    pub fn to_outline_style<T: FloatNumber>(&self) -> OutlineStyle<T> {
+        OutlineStyle::new(T::from_float(-self.outer_offset))
+            .inner_offset(T::from_float(-self.inner_offset))
+            .line_join(self.join.to_line_join())
+    }
+}
+
+impl From<FfiOutlineStyle> for OutlineStyle<f64> {
+    #[inline]
+    fn from(value: FfiOutlineStyle) -> Self {
+        value.to_outline_style()
+    }
+}
+
+impl From<FfiOutlineStyle> for OutlineStyle<f32> {
+    #[inline]
+    fn from(value: FfiOutlineStyle) -> Self {
+        value.to_outline_style()
+    }
+}
+
+impl Default for FfiOutlineStyle {
+    #[inline]
+    fn default() -> Self {
+        let core = OutlineStyle::<f64>::default();
+        Self {
+            outer_offset: -core.outer_offset,
+            inner_offset: -core.inner_offset,
+            join: core.join.into(),
+            non_finite: IShapeNonFinitePolicy::default(),
+        }
+    }
+}
@@ -1,9 +1,14 @@
 use alloc::vec::Vec;
+use core::ptr;
 use i_triangle::i_overlay::i_float::float::point::FloatPoint;
 use i_triangle::i_overlay::i_shape::base::data::{Contour, Shape, Shapes};
 use i_triangle::i_overlay::i_shape::float::count::PointsCount as FloatPointsCount;
 
+use super::flat_contours::FlatContours;
 use super::int_shapes_buffer::RangeFFI;
+use super::layout::{FlatLayoutError, check_layout};
+use super::non_finite::{IShapeNonFinitePolicy, NonFiniteError};
+use crate::allocator::{HostVec, host_vec};
 
 type Float32Point = FloatPoint<f32>;
 type Float32Contour = Contour<Float32Point>;
@@ -11,12 +16,50 @@ type Float32Shape = Shape<Float32Point>;
 type Float32Shapes = Shapes<Float32Point>;
 
 /// Flattened container for `Float32Shapes` data that is easy to consume from Swift.
-#[repr(C)]
-#[derive(Debug, Clone, Default)]
+#[derive(Debug, Clone)]
 pub struct FlatF32ShapesBuffer {
-    pub flat_points: Vec<f32>,
-    pub contour_ranges: Vec<RangeFFI>,
-    pub shape_ranges: Vec<RangeFFI>,
+    pub flat_points: HostVec<f32>,
+    pub contour_ranges: HostVec<RangeFFI>,
+    pub shape_ranges: HostVec<RangeFFI>,
+}
+
+impl Default for FlatF32ShapesBuffer {
+    #[inline]
+    fn default() -> Self {
+        Self {
+            flat_points: host_vec(),
+            contour_ranges: host_vec(),
+            shape_ranges: host_vec(),
+        }
+    }
+}
+
+/// Borrowed, layout-stable view of a [`FlatF32ShapesBuffer`].
+///
+/// Every pointer is valid for its matching length until the buffer is modified or freed.
+#[repr(C)]
+#[derive(Debug, Clone, Copy)]
+pub struct FlatF32ShapesView {
+    pub points: *const f32,
+    pub points_len: usize,
+    pub contours: *const RangeFFI,
+    pub contours_len: usize,
+    pub shapes: *const RangeFFI,
+    pub shapes_len: usize,
+}
+
+impl Default for FlatF32ShapesView {
+    #[inline]
+    fn default() -> Self {
+        Self {
+            points: ptr::null(),
+            points_len: 0,
+            contours: ptr::null(),
+            contours_len: 0,
+            shapes: ptr::null(),
+            shapes_len: 0,
+        }
+    }
 }
 
 impl FlatF32ShapesBuffer {
@@ -28,6 +71,19 @@ impl FlatF32ShapesBuffer {
         buffer
     }
 
+    /// Returns a layout-stable view of the stored arrays.
+    #[inline]
+    pub fn view(&self) -> FlatF32ShapesView {
+        FlatF32ShapesView {
+            points: self.flat_points.as_ptr(),
+            points_len: self.flat_points.len(),
+            contours: self.contour_ranges.as_ptr(),
+            contours_len: self.contour_ranges.len(),
+            shapes: self.shape_ranges.as_ptr(),
+            shapes_len: self.shape_ranges.len(),
+        }
+    }
+
     /// Returns `true` when no shapes are stored.
     #[inline]
     pub fn is_empty(&self) -> bool {
@@ -54,6 +110,63 @@ impl FlatF32ShapesBuffer {
         self.push_shapes(shapes);
     }
 
+    /// Replaces the buffer contents with copies of the provided flat arrays.
+    ///
+    /// The arrays use the same layout as the buffer fields. On error the buffer is left unchanged.
+    #[inline]
+    pub fn set_flat(
+        &mut self,
+        flat_points: &[f32],
+        contour_ranges: &[RangeFFI],
+        shape_ranges: &[RangeFFI],
+    ) -> Result<(), FlatLayoutError> {
+        check_layout(flat_points.len(), contour_ranges, shape_ranges)?;
+
+        self.clear();
+        self.flat_points.extend_from_slice(flat_points);
+        self.contour_ranges.extend_from_slice(contour_ranges);
+        self.shape_ranges.extend_from_slice(shape_ranges);
+
+        Ok(())
+    }
+
+    /// Starts a new empty shape; contours pushed afterwards are added to it.
+    #[inline]
+    pub fn begin_shape(&mut self) {
+        let start = self.contour_ranges.len() as u64;
+        self.shape_ranges.push(RangeFFI { start, end: start });
+    }
+
+    /// Appends a contour given as `[x0, y0, x1, y1, ...]` to the last shape, starting a new
+    /// shape when none exists yet.
+    ///
+    /// Returns [`FlatLayoutError::OddCoordinateCount`] for an odd number of coordinates.
+    #[inline]
+    pub fn push_contour_coords(&mut self, coords: &[f32]) -> Result<(), FlatLayoutError> {
+        if !coords.len().is_multiple_of(2) {
+            return Err(FlatLayoutError::OddCoordinateCount {
+                count: coords.len(),
+            });
+        }
+
+        if self.shape_ranges.is_empty() {
+            self.begin_shape();
+        }
+
+        let start = self.flat_points.len() as u64;
+        self.flat_points.extend_from_slice(coords);
+        self.contour_ranges.push(RangeFFI {
+            start,
+            end: self.flat_points.len() as u64,
+        });
+
+        if let Some(shape_range) = self.shape_ranges.last_mut() {
+            shape_range.end = self.contour_ranges.len() as u64;
+        }
+
+        Ok(())
+    }
+
     /// Populates the buffer from a list of shapes without clearing first.
     ///
     /// The caller is responsible for reserving enough capacity.
@@ -77,7 +190,30 @@ impl FlatF32ShapesBuffer {
         }
     }
 
+    /// Checks that every contour range is ordered, in bounds and aligned to a point, and that
+    /// every shape range is ordered and in bounds of the contour ranges. Each range must start at
+    /// or after the end of the previous one.
+    #[inline]
+    pub fn validate(&self) -> Result<(), FlatLayoutError> {
+        check_layout(
+            self.flat_points.len(),
+            &self.contour_ranges,
+            &self.shape_ranges,
+        )
+    }
+
+    /// Validates the buffer and converts it back into `Float32Shapes`.
+    #[inline]
+    pub fn try_to_shapes(&self) -> Result<Float32Shapes, FlatLayoutError> {
+        self.validate()?;
+        Ok(self.to_shapes())
+    }
+
     /// Converts the buffer back into `Float32Shapes`.
+    ///
+    /// # Panics
+    /// Panics when the ranges are malformed; use [`Self::try_to_shapes`] for buffers filled by
+    /// foreign code.
     #[inline]
     pub fn to_shapes(&self) -> Float32Shapes {
         let mut shapes: Float32Shapes = Vec::with_capacity(self.shape_ranges.len());
@@ -98,6 +234,65 @@ impl FlatF32ShapesBuffer {
         shapes
     }
 
+    /// Converts the buffer back into `Float32Shapes`, applying `policy` to points with a NaN or
+    /// infinite coordinate.
+    ///
+    /// Contours are reported by their index in `contour_ranges`. A shape whose outer contour is
+    /// dropped is dropped together with its holes.
+    ///
+    /// # Panics
+    /// Panics when the ranges are malformed; call [`Self::validate`] first for buffers filled by
+    /// foreign code.
+    #[inline]
+    pub fn to_finite_shapes(
+        &self,
+        policy: IShapeNonFinitePolicy,
+    ) -> Result<Float32Shapes, NonFiniteError> {
+        let mut shapes: Float32Shapes = Vec::with_capacity(self.shape_ranges.len());
+        for shape_range in &self.shape_ranges {
+            let start = shape_range.start as usize;
+            let end = shape_range.end as usize;
+            let mut shape: Option<Float32Shape> = Some(Vec::with_capacity(end - start));
+            for contour_index in start..end {
+                let contour_range = self.contour_ranges[contour_index];
+                let start = contour_range.start as usize;
+                let end = contour_range.end as usize;
+                let mut contour = self.slice_to_contour(&self.flat_points[start..end]);
+                if policy.apply(&mut contour, contour_index)? {
+                    if let Some(shape) = shape.as_mut() {
+                        shape.push(contour);
+                    }
+                } else if contour_index == shape_range.start as usize {
+                    shape = None;
+                }
+            }
+            shapes.extend(shape);
+        }
+
+        Ok(shapes)
+    }
+
+    /// Copies every contour into `contours`, applying `policy` like [`Self::to_finite_shapes`].
+    ///
+    /// Reuses the capacity of `contours` instead of allocating a nested `Vec` per contour.
+    ///
+    /// # Panics
+    /// Panics when the ranges are malformed; call [`Self::validate`] first for buffers filled by
+    /// foreign code.
+    #[inline]
+    pub fn finite_contours_into(
+        &self,
+        policy: IShapeNonFinitePolicy,
+        contours: &mut FlatContours<f32>,
+    ) -> Result<(), NonFiniteError> {
+        contours.set_flat(
+            &self.flat_points,
+            &self.contour_ranges,
+            &self.shape_ranges,
+            policy,
+        )
+    }
+
     #[inline]
     fn push_contour(&mut self, contour: &[Float32Point]) -> RangeFFI {
         let start = self.flat_points.len();
@@ -116,7 +311,7 @@ impl FlatF32ShapesBuffer {
 
     #[inline]
     fn slice_to_contour(&self, slice: &[f32]) -> Float32Contour {
-        debug_assert!(slice.len() % 2 == 0);
+        debug_assert!(slice.len().is_multiple_of(2));
         let mut contour = Vec::with_capacity(slice.len() / 2);
         for coords in slice.chunks_exact(2) {
             contour.push(FloatPoint::new(coords[0], coords[1]));
@@ -0,0 +1,152 @@
+use alloc::vec::Vec;
+use core::ops::Range;
+use core::slice;
+use i_triangle::i_overlay::i_float::float::number::FloatNumber;
+use i_triangle::i_overlay::i_float::float::point::FloatPoint;
+use i_triangle::i_overlay::i_shape::source::resource::ShapeResource;
+
+use super::int_shapes_buffer::RangeFFI;
+use super::non_finite::{IShapeNonFinitePolicy, NonFiniteError};
+
+/// Contours packed into a single point array, kept between calls so that refilling it does not
+/// allocate once its capacity has grown to fit the input.
+///
+/// Shape grouping is not kept: consumers such as the triangulator and the overlays only need the
+/// contours.
+#[derive(Debug, Clone)]
+pub struct FlatContours<T: FloatNumber> {
+    points: Vec<FloatPoint<T>>,
+    ranges: Vec<Range<usize>>,
+}
+
+impl<T: FloatNumber> Default for FlatContours<T> {
+    #[inline]
+    fn default() -> Self {
+        Self {
+            points: Vec::new(),
+            ranges: Vec::new(),
+        }
+    }
+}
+
+impl<T: FloatNumber> FlatContours<T> {
+    /// Creates an empty collection with room for `capacity` contours.
+    #[inline]
+    pub fn with_capacity(capacity: usize) -> Self {
+        Self {
+            points: Vec::new(),
+            ranges: Vec::with_capacity(capacity),
+        }
+    }
+
+    /// Returns the number of stored contours.
+    #[inline]
+    pub fn len(&self) -> usize {
+        self.ranges.len()
+    }
+
+    /// Returns `true` when no contours are stored.
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.ranges.is_empty()
+    }
+
+    /// Removes all contours while preserving the current capacity.
+    #[inline]
+    pub fn clear(&mut self) {
+        self.points.clear();
+        self.ranges.clear();
+    }
+
+    /// Replaces the contents with the contours of a flat shapes layout, applying `policy` to
+    /// points with a NaN or infinite coordinate.
+    ///
+    /// Contours are reported by their index in `contour_ranges`. A shape whose outer contour is
+    /// dropped is dropped together with its holes.
+    ///
+    /// # Panics
+    /// Panics when the ranges are malformed; validate layouts filled by foreign code first.
+    pub(crate) fn set_flat(
+        &mut self,
+        coords: &[T],
+        contour_ranges: &[RangeFFI],
+        shape_ranges: &[RangeFFI],
+        policy: IShapeNonFinitePolicy,
+    ) -> Result<(), NonFiniteError> {
+        self.clear();
+        self.points.reserve(coords.len() / 2);
+        self.ranges.reserve(contour_ranges.len());
+
+        for shape_range in shape_ranges {
+            let first = shape_range.start as usize;
+            let shape_contours = &contour_ranges[first..shape_range.end as usize];
+            for (index, contour_range) in (first..).zip(shape_contours) {
+                let slice = &coords[contour_range.start as usize..contour_range.end as usize];
+                if !self.push_coords(slice, policy, index)? && index == first {
+                    break;
+                }
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Appends the contour described by flat `[x0, y0, x1, y1, ...]` coordinates, applying
+    /// `policy` to points with a NaN or infinite coordinate.
+    ///
+    /// Returns `Ok(false)` when the policy leaves nothing of the contour, in which case nothing is
+    /// stored. Errors report the contour as `index`; a trailing odd coordinate is ignored.
+    pub(crate) fn push_coords(
+        &mut self,
+        coords: &[T],
+        policy: IShapeNonFinitePolicy,
+        index: usize,
+    ) -> Result<bool, NonFiniteError> {
+        let start = self.points.len();
+        self.points.extend(
+            coords
+                .chunks_exact(2)
+                .map(|xy| FloatPoint::new(xy[0], xy[1])),
+        );
+
+        let kept = policy.apply_from(&mut self.points, start, index);
+        if let Ok(true) = kept {
+            self.ranges.push(start..self.points.len());
+        } else {
+            self.points.truncate(start);
+        }
+
+        kept
+    }
+}
+
+impl<T: FloatNumber> ShapeResource<FloatPoint<T>, T> for FlatContours<T> {
+    type ResourceIter<'a>
+        = FlatContoursIter<'a, T>
+    where
+        T: 'a;
+
+    #[inline]
+    fn iter_paths(&self) -> Self::ResourceIter<'_> {
+        FlatContoursIter {
+            points: &self.points,
+            ranges: self.ranges.iter(),
+        }
+    }
+}
+
+/// Iterator over the contours of a [`FlatContours`].
+pub struct FlatContoursIter<'a, T: FloatNumber> {
+    points: &'a [FloatPoint<T>],
+    ranges: slice::Iter<'a, Range<usize>>,
+}
+
+impl<'a, T: FloatNumber> Iterator for FlatContoursIter<'a, T> {
+    type Item = &'a [FloatPoint<T>];
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        let range = self.ranges.next()?;
+        Some(&self.points[range.clone()])
+    }
+}
@@ -1,9 +1,14 @@
 use alloc::vec::Vec;
 use core::ops::Range;
+use core::ptr;
 use i_triangle::i_overlay::i_float::int::point::IntPoint;
+use i_triangle::i_overlay::i_shape::flat::buffer::FlatContoursBuffer;
 use i_triangle::i_overlay::i_shape::int::count::PointsCount;
 use i_triangle::i_overlay::i_shape::int::shape::{IntContour, IntShape, IntShapes};
 
+use super::layout::{FlatLayoutError, check_layout};
+use crate::allocator::{HostVec, host_vec};
+
 /// Half-open range helper that can safely cross the FFI boundary.
 #[repr(C)]
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
@@ -12,6 +17,34 @@ pub struct RangeFFI {
     pub end: u64,
 }
 
+impl RangeFFI {
+    /// Converts the range into a slice range over `len` coordinates.
+    ///
+    /// Returns `None` when the range is reversed, out of bounds or does not start and end on a
+    /// point boundary.
+    #[inline]
+    pub fn to_point_range(&self, len: usize) -> Option<Range<usize>> {
+        let start = usize::try_from(self.start).ok()?;
+        let end = usize::try_from(self.end).ok()?;
+
+        let is_valid =
+            start <= end && end <= len && start.is_multiple_of(2) && end.is_multiple_of(2);
+
+        is_valid.then_some(start..end)
+    }
+
+    /// Converts the range into a slice range over `len` elements.
+    ///
+    /// Returns `None` when the range is reversed or out of bounds.
+    #[inline]
+    pub fn to_index_range(&self, len: usize) -> Option<Range<usize>> {
+        let start = usize::try_from(self.start).ok()?;
+        let end = usize::try_from(self.end).ok()?;
+
+        (start <= end && end <= len).then_some(start..end)
+    }
+}
+
 impl From<Range<usize>> for RangeFFI {
     #[inline]
     fn from(value: Range<usize>) -> Self {
@@ -28,12 +61,50 @@ impl From<Range<usize>> for RangeFFI {
 /// - `flat_points` stores the coordinates as `[x0, y0, x1, y1, ...]`.
 /// - `contour_ranges` points into `flat_points`, describing the span of every contour.
 /// - `shape_ranges` points into `contour_ranges`, describing which contours belong to each shape.
-#[repr(C)]
-#[derive(Debug, Clone, Default)]
+#[derive(Debug, Clone)]
 pub struct FlatShapesBuffer {
-    pub flat_points: Vec<i32>,
-    pub contour_ranges: Vec<RangeFFI>,
-    pub shape_ranges: Vec<RangeFFI>,
+    pub flat_points: HostVec<i32>,
+    pub contour_ranges: HostVec<RangeFFI>,
+    pub shape_ranges: HostVec<RangeFFI>,
+}
+
+impl Default for FlatShapesBuffer {
+    #[inline]
+    fn default() -> Self {
+        Self {
+            flat_points: host_vec(),
+            contour_ranges: host_vec(),
+            shape_ranges: host_vec(),
+        }
+    }
+}
+
+/// Borrowed, layout-stable view of a [`FlatShapesBuffer`].
+///
+/// Every pointer is valid for its matching length until the buffer is modified or freed.
+#[repr(C)]
+#[derive(Debug, Clone, Copy)]
+pub struct FlatShapesView {
+    pub points: *const i32,
+    pub points_len: usize,
+    pub contours: *const RangeFFI,
+    pub contours_len: usize,
+    pub shapes: *const RangeFFI,
+    pub shapes_len: usize,
+}
+
+impl Default for FlatShapesView {
+    #[inline]
+    fn default() -> Self {
+        Self {
+            points: ptr::null(),
+            points_len: 0,
+            contours: ptr::null(),
+            contours_len: 0,
+            shapes: ptr::null(),
+            shapes_len: 0,
+        }
+    }
 }
 
 impl FlatShapesBuffer {
@@ -45,6 +116,19 @@ impl FlatShapesBuffer {
         buffer
     }
 
+    /// Returns a layout-stable view of the stored arrays.
+    #[inline]
+    pub fn view(&self) -> FlatShapesView {
+        FlatShapesView {
+            points: self.flat_points.as_ptr(),
+            points_len: self.flat_points.len(),
+            contours: self.contour_ranges.as_ptr(),
+            contours_len: self.contour_ranges.len(),
+            shapes: self.shape_ranges.as_ptr(),
+            shapes_len: self.shape_ranges.len(),
+        }
+    }
+
     /// Returns `true` when no shapes are stored.
     #[inline]
     pub fn is_empty(&self) -> bool {
@@ -71,6 +155,63 @@ impl FlatShapesBuffer {
         self.push_shapes(shapes);
     }
 
+    /// Replaces the buffer contents with copies of the provided flat arrays.
+    ///
+    /// The arrays use the same layout as the buffer fields. On error the buffer is left unchanged.
+    #[inline]
+    pub fn set_flat(
+        &mut self,
+        flat_points: &[i32],
+        contour_ranges: &[RangeFFI],
+        shape_ranges: &[RangeFFI],
+    ) -> Result<(), FlatLayoutError> {
+        check_layout(flat_points.len(), contour_ranges, shape_ranges)?;
+
+        self.clear();
+        self.flat_points.extend_from_slice(flat_points);
+        self.contour_ranges.extend_from_slice(contour_ranges);
+        self.shape_ranges.extend_from_slice(shape_ranges);
+
+        Ok(())
+    }
+
+    /// Starts a new empty shape; contours pushed afterwards are added to it.
+    #[inline]
+    pub fn begin_shape(&mut self) {
+        let start = self.contour_ranges.len() as u64;
+        self.shape_ranges.push(RangeFFI { start, end: start });
+    }
+
+    /// Appends a contour given as `[x0, y0, x1, y1, ...]` to the last shape, starting a new
+    /// shape when none exists yet.
+    ///
+    /// Returns [`FlatLayoutError::OddCoordinateCount`] for an odd number of coordinates.
+    #[inline]
+    pub fn push_contour_coords(&mut self, coords: &[i32]) -> Result<(), FlatLayoutError> {
+        if !coords.len().is_multiple_of(2) {
+            return Err(FlatLayoutError::OddCoordinateCount {
+                count: coords.len(),
+            });
+        }
+
+        if self.shape_ranges.is_empty() {
+            self.begin_shape();
+        }
+
+        let start = self.flat_points.len() as u64;
+        self.flat_points.extend_from_slice(coords);
+        self.contour_ranges.push(RangeFFI {
+            start,
+            end: self.flat_points.len() as u64,
+        });
+
+        if let Some(shape_range) = self.shape_ranges.last_mut() {
+            shape_range.end = self.contour_ranges.len() as u64;
+        }
+
+        Ok(())
+    }
+
     /// Populates the buffer from a list of shapes without clearing first.
     ///
     /// The caller is responsible for reserving enough capacity.
@@ -94,7 +235,30 @@ impl FlatShapesBuffer {
         }
     }
 
+    /// Checks that every contour range is ordered, in bounds and aligned to a point, and that
+    /// every shape range is ordered and in bounds of the contour ranges. Each range must start at
+    /// or after the end of the previous one.
+    #[inline]
+    pub fn validate(&self) -> Result<(), FlatLayoutError> {
+        check_layout(
+            self.flat_points.len(),
+            &self.contour_ranges,
+            &self.shape_ranges,
+        )
+    }
+
+    /// Validates the buffer and converts it back into `IntShapes`.
+    #[inline]
+    pub fn try_to_shapes(&self) -> Result<IntShapes, FlatLayoutError> {
+        self.validate()?;
+        Ok(self.to_shapes())
+    }
+
     /// Converts the buffer back into `IntShapes`.
+    ///
+    /// # Panics
+    /// Panics when the ranges are malformed; use [`Self::try_to_shapes`] for buffers filled by
+    /// foreign code.
     #[inline]
     pub fn to_shapes(&self) -> IntShapes {
         let mut shapes: IntShapes = Vec::with_capacity(self.shape_ranges.len());
@@ -115,6 +279,31 @@ impl FlatShapesBuffer {
         shapes
     }
 
+    /// Copies every contour into `contours`, reusing its capacity instead of allocating a nested
+    /// `Vec` per contour like [`Self::to_shapes`].
+    ///
+    /// # Panics
+    /// Panics when the ranges are malformed; call [`Self::validate`] first for buffers filled by
+    /// foreign code.
+    #[inline]
+    pub fn contours_into(&self, contours: &mut FlatContoursBuffer) {
+        contours.clear_and_reserve(self.flat_points.len() / 2, self.contour_ranges.len());
+
+        for shape_range in &self.shape_ranges {
+            let shape_contours =
+                &self.contour_ranges[shape_range.start as usize..shape_range.end as usize];
+            for contour_range in shape_contours {
+                let slice =
+                    &self.flat_points[contour_range.start as usize..contour_range.end as usize];
+                let start = contours.points.len();
+                contours
+                    .points
+                    .extend(slice.chunks_exact(2).map(|xy| IntPoint::new(xy[0], xy[1])));
+                contours.ranges.push(start..contours.points.len());
+            }
+        }
+    }
+
     #[inline]
     fn push_contour(&mut self, contour: &[IntPoint]) -> RangeFFI {
         let start = self.flat_points.len();
@@ -133,7 +322,7 @@ impl FlatShapesBuffer {
 
     #[inline]
     fn slice_to_contour(&self, slice: &[i32]) -> IntContour {
-        debug_assert!(slice.len() % 2 == 0);
+        debug_assert!(slice.len().is_multiple_of(2));
         let mut contour = Vec::with_capacity(slice.len() / 2);
         for coords in slice.chunks_exact(2) {
             contour.push(IntPoint::new(coords[0], coords[1]));
@@ -0,0 +1,118 @@
+use core::fmt;
+
+use super::int_shapes_buffer::RangeFFI;
+
+/// Errors that can occur when flat buffer arrays do not describe a consistent layout.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum FlatLayoutError {
+    /// The coordinate buffer length is not a multiple of two.
+    OddCoordinateCount { count: usize },
+    /// A contour range is reversed, out of bounds or not aligned to a point.
+    InvalidContourRange { index: usize, range: RangeFFI },
+    /// A shape range is reversed or out of bounds of the contour ranges.
+    InvalidShapeRange { index: usize, range: RangeFFI },
+    /// A contour range starts before the previous contour range ends.
+    OverlappingContourRange {
+        index: usize,
+        range: RangeFFI,
+        previous_end: u64,
+    },
+    /// A shape range starts before the previous shape range ends.
+    OverlappingShapeRange {
+        index: usize,
+        range: RangeFFI,
+        previous_end: u64,
+    },
+}
+
+impl fmt::Display for FlatLayoutError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            FlatLayoutError::OddCoordinateCount { count } => {
+                write!(f, "buffer has {count} coordinates")
+            }
+            FlatLayoutError::InvalidContourRange { index, range } => write!(
+                f,
+                "contour {index} range {}..{} does not fit the coordinates",
+                range.start, range.end
+            ),
+            FlatLayoutError::InvalidShapeRange { index, range } => write!(
+                f,
+                "shape {index} range {}..{} does not fit the contour ranges",
+                range.start, range.end
+            ),
+            FlatLayoutError::OverlappingContourRange {
+                index,
+                range,
+                previous_end,
+            } => write!(
+                f,
+                "contour {index} range {}..{} starts before the previous contour ends at {previous_end}",
+                range.start, range.end
+            ),
+            FlatLayoutError::OverlappingShapeRange {
+                index,
+                range,
+                previous_end,
+            } => write!(
+                f,
+                "shape {index} range {}..{} starts before the previous shape ends at {previous_end}",
+                range.start, range.end
+            ),
+        }
+    }
+}
+
+/// Checks that `contour_ranges` index into `points_len` coordinates and `shape_ranges` index into
+/// `contour_ranges`.
+///
+/// Each range must start at or after the end of the previous one, so no coordinate belongs to two
+/// contours and no contour to two shapes. Gaps between ranges are allowed.
+#[inline]
+pub(crate) fn check_layout(
+    points_len: usize,
+    contour_ranges: &[RangeFFI],
+    shape_ranges: &[RangeFFI],
+) -> Result<(), FlatLayoutError> {
+    if !points_len.is_multiple_of(2) {
+        return Err(FlatLayoutError::OddCoordinateCount { count: points_len });
+    }
+
+    let mut previous_end = 0;
+    for (index, range) in contour_ranges.iter().enumerate() {
+        if range.to_point_range(points_len).is_none() {
+            return Err(FlatLayoutError::InvalidContourRange {
+                index,
+                range: *range,
+            });
+        }
+        if range.start < previous_end {
+            return Err(FlatLayoutError::OverlappingContourRange {
+                index,
+                range: *range,
+                previous_end,
+            });
+        }
+        previous_end = range.end;
+    }
+
+    let mut previous_end = 0;
+    for (index, range) in shape_ranges.iter().enumerate() {
+        if range.to_index_range(contour_ranges.len()).is_none() {
+            return Err(FlatLayoutError::InvalidShapeRange {
+                index,
+                range: *range,
+            });
+        }
+        if range.start < previous_end {
+            return Err(FlatLayoutError::OverlappingShapeRange {
+                index,
+                range: *range,
+                previous_end,
+            });
+        }
+        previous_end = range.end;
+    }
+
+    Ok(())
+}
@@ -1,7 +1,15 @@
 pub mod f32_shapes_buffer;
 pub mod f64_shapes_buffer;
+pub mod flat_contours;
 pub mod int_shapes_buffer;
+pub mod layout;
+pub mod non_finite;
+pub mod slices;
 
-pub use f32_shapes_buffer::FlatF32ShapesBuffer;
-pub use f64_shapes_buffer::FlatF64ShapesBuffer;
-pub use int_shapes_buffer::{FlatShapesBuffer, RangeFFI};
+pub use f32_shapes_buffer::{FlatF32ShapesBuffer, FlatF32ShapesView};
+pub use f64_shapes_buffer::{FlatF64ShapesBuffer, FlatF64ShapesView};
+pub use flat_contours::FlatContours;
+pub use int_shapes_buffer::{FlatShapesBuffer, FlatShapesView, RangeFFI};
+pub use layout::FlatLayoutError;
+pub use non_finite::{IShapeNonFinitePolicy, NonFiniteError};
+pub use slices::{FlatF32ShapesSlices, FlatF64ShapesSlices, FlatShapesSlices, OutputSlicesError};
@@ -0,0 +1,97 @@
+use alloc::vec::Vec;
+use core::fmt;
+use i_triangle::i_overlay::i_float::float::number::FloatNumber;
+use i_triangle::i_overlay::i_float::float::point::FloatPoint;
+use i_triangle::i_overlay::i_shape::base::data::Contour;
+
+/// How float entry points treat input points with a NaN or infinite coordinate.
+///
+/// The core float-to-int conversion derives its bounds from the input, so a single non-finite
+/// point would corrupt the whole result.
+#[repr(C)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum IShapeNonFinitePolicy {
+    /// Fail with `IShapeStatus::NonFiniteValue`.
+    #[default]
+    Reject = 0,
+    /// Remove the offending points and keep the rest of their contour.
+    DropPoint = 1,
+    /// Remove every contour holding an offending point.
+    DropContour = 2,
+}
+
+/// A point with a non-finite coordinate found under [`IShapeNonFinitePolicy::Reject`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct NonFiniteError {
+    pub contour: usize,
+    pub point: usize,
+}
+
+impl fmt::Display for NonFiniteError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "contour {} point {} has a non-finite coordinate",
+            self.contour, self.point
+        )
+    }
+}
+
+impl IShapeNonFinitePolicy {
+    /// Applies the policy to `contour`, reporting errors against `index`.
+    ///
+    /// Returns `Ok(false)` when the contour must be dropped, including when dropping points left
+    /// it empty.
+    #[inline]
+    pub(crate) fn apply<T: FloatNumber>(
+        self,
+        contour: &mut Contour<FloatPoint<T>>,
+        index: usize,
+    ) -> Result<bool, NonFiniteError> {
+        self.apply_from(contour, 0, index)
+    }
+
+    /// Applies the policy to the contour stored in `points[start..]`, reporting errors against
+    /// `index`.
+    ///
+    /// Returns `Ok(false)` when the contour must be dropped, in which case `points` is truncated
+    /// back to `start`.
+    #[inline]
+    pub(crate) fn apply_from<T: FloatNumber>(
+        self,
+        points: &mut Vec<FloatPoint<T>>,
+        start: usize,
+        index: usize,
+    ) -> Result<bool, NonFiniteError> {
+        let Some(point) = points[start..].iter().position(|point| !is_finite(point)) else {
+            return Ok(true);
+        };
+
+        match self {
+            IShapeNonFinitePolicy::Reject => Err(NonFiniteError {
+                contour: index,
+                point,
+            }),
+            IShapeNonFinitePolicy::DropPoint => {
+                let mut end = start + point;
+                for i in end + 1..points.len() {
+                    if is_finite(&points[i]) {
+                        points[end] = points[i];
+                        end += 1;
+                    }
+                }
+                points.truncate(end);
+                Ok(end > start)
+            }
+            IShapeNonFinitePolicy::DropContour => {
+                points.truncate(start);
+                Ok(false)
+            }
+        }
+    }
+}
+
+#[inline]
+fn is_finite<T: FloatNumber>(point: &FloatPoint<T>) -> bool {
+    point.x.to_f64().is_finite() && point.y.to_f64().is_finite()
+}
@@ -0,0 +1,304 @@
+use alloc::vec::Vec;
+use core::{fmt, slice};
+use i_triangle::i_overlay::i_float::float::point::FloatPoint;
+use i_triangle::i_overlay::i_float::int::point::IntPoint;
+use i_triangle::i_overlay::i_shape::base::data::Shape;
+
+use super::int_shapes_buffer::RangeFFI;
+
+/// Errors that can occur when writing a result into caller-provided arrays.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum OutputSlicesError {
+    /// An array the result needs is null.
+    NullArray { name: &'static str, required: usize },
+    /// An array is smaller than the result; the required lengths have been reported.
+    InsufficientCapacity {
+        name: &'static str,
+        required: usize,
+        capacity: usize,
+    },
+}
+
+impl fmt::Display for OutputSlicesError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            OutputSlicesError::NullArray { name, required } => {
+                write!(f, "`{name}` is null but {required} elements are required")
+            }
+            OutputSlicesError::InsufficientCapacity {
+                name,
+                required,
+                capacity,
+            } => write!(
+                f,
+                "`{name}` needs {required} elements but has capacity {capacity}"
+            ),
+        }
+    }
+}
+
+/// Caller-owned output arrays that can receive a `T` result.
+pub(crate) trait OutputSlices<T: ?Sized> {
+    /// Reports the array lengths `value` needs and copies it in when every array is large enough.
+    fn write(&mut self, value: &T) -> Result<(), OutputSlicesError>;
+}
+
+/// One caller-provided array together with its capacity and reported length.
+pub(crate) struct ArrayOut<'a, T> {
+    pub(crate) name: &'static str,
+    pub(crate) ptr: *mut T,
+    pub(crate) capacity: usize,
+    pub(crate) len: &'a mut usize,
+}
+
+impl<T> ArrayOut<'_, T> {
+    /// Reports `required` as the array length and checks that the array can hold it.
+    #[inline]
+    pub(crate) fn require(&mut self, required: usize) -> Result<(), OutputSlicesError> {
+        *self.len = required;
+
+        if self.capacity < required {
+            return Err(OutputSlicesError::InsufficientCapacity {
+                name: self.name,
+                required,
+                capacity: self.capacity,
+            });
+        }
+
+        Ok(())
+    }
+
+    /// Returns the first `len` elements of the array, which must have passed [`Self::require`].
+    #[inline]
+    pub(crate) fn as_mut_slice(&mut self) -> Result<&mut [T], OutputSlicesError> {
+        let len = *self.len;
+        if len == 0 {
+            return Ok(&mut []);
+        }
+
+        if self.ptr.is_null() {
+            return Err(OutputSlicesError::NullArray {
+                name: self.name,
+                required: len,
+            });
+        }
+
+        Ok(unsafe { slice::from_raw_parts_mut(self.ptr, len) })
+    }
+}
+
+/// Caller-provided arrays receiving integer shapes laid out like `FlatShapesBuffer`.
+///
+/// Before the call every `*_capacity` holds the number of elements its array can take. After the
+/// call every `*_len` holds the number of elements the result needs, also when it did not fit.
+#[repr(C)]
+#[derive(Debug)]
+pub struct FlatShapesSlices {
+    pub points: *mut i32,
+    pub points_capacity: usize,
+    pub points_len: usize,
+    pub contours: *mut RangeFFI,
+    pub contours_capacity: usize,
+    pub contours_len: usize,
+    pub shapes: *mut RangeFFI,
+    pub shapes_capacity: usize,
+    pub shapes_len: usize,
+}
+
+/// Caller-provided arrays receiving `f32` shapes laid out like `FlatF32ShapesBuffer`.
+///
+/// Capacities and lengths follow the same rules as [`FlatShapesSlices`].
+#[repr(C)]
+#[derive(Debug)]
+pub struct FlatF32ShapesSlices {
+    pub points: *mut f32,
+    pub points_capacity: usize,
+    pub points_len: usize,
+    pub contours: *mut RangeFFI,
+    pub contours_capacity: usize,
+    pub contours_len: usize,
+    pub shapes: *mut RangeFFI,
+    pub shapes_capacity: usize,
+    pub shapes_len: usize,
+}
+
+/// Caller-provided arrays receiving `f64` shapes laid out like `FlatF64ShapesBuffer`.
+///
+/// Capacities and lengths follow the same rules as [`FlatShapesSlices`].
+#[repr(C)]
+#[derive(Debug)]
+pub struct FlatF64ShapesSlices {
+    pub points: *mut f64,
+    pub points_capacity: usize,
+    pub points_len: usize,
+    pub contours: *mut RangeFFI,
+    pub contours_capacity: usize,
+    pub contours_len: usize,
+    pub shapes: *mut RangeFFI,
+    pub shapes_capacity: usize,
+    pub shapes_len: usize,
+}
+
+impl FlatShapesSlices {
+    /// Resets every reported length to zero.
+    #[inline]
+    pub fn clear(&mut self) {
+        self.points_len = 0;
+        self.contours_len = 0;
+        self.shapes_len = 0;
+    }
+}
+
+impl FlatF32ShapesSlices {
+    /// Resets every reported length to zero.
+    #[inline]
+    pub fn clear(&mut self) {
+        self.points_len = 0;
+        self.contours_len = 0;
+        self.shapes_len = 0;
+    }
+}
+
+impl FlatF64ShapesSlices {
+    /// Resets every reported length to zero.
+    #[inline]
+    pub fn clear(&mut self) {
+        self.points_len = 0;
+        self.contours_len = 0;
+        self.shapes_len = 0;
+    }
+}
+
+impl OutputSlices<[Shape<IntPoint>]> for FlatShapesSlices {
+    #[inline]
+    fn write(&mut self, shapes: &[Shape<IntPoint>]) -> Result<(), OutputSlicesError> {
+        write_shapes(
+            shapes,
+            |point| [point.x, point.y],
+            ArrayOut {
+                name: "points",
+                ptr: self.points,
+                capacity: self.points_capacity,
+                len: &mut self.points_len,
+            },
+            ArrayOut {
+                name: "contours",
+                ptr: self.contours,
+                capacity: self.contours_capacity,
+                len: &mut self.contours_len,
+            },
+            ArrayOut {
+                name: "shapes",
+                ptr: self.shapes,
+                capacity: self.shapes_capacity,
+                len: &mut self.shapes_len,
+            },
+        )
+    }
+}
+
+impl OutputSlices<[Shape<FloatPoint<f32>>]> for FlatF32ShapesSlices {
+    #[inline]
+    fn write(&mut self, shapes: &[Shape<FloatPoint<f32>>]) -> Result<(), OutputSlicesError> {
+        write_shapes(
+            shapes,
+            |point| [point.x, point.y],
+            ArrayOut {
+                name: "points",
+                ptr: self.points,
+                capacity: self.points_capacity,
+                len: &mut self.points_len,
+            },
+            ArrayOut {
+                name: "contours",
+                ptr: self.contours,
+                capacity: self.contours_capacity,
+                len: &mut self.contours_len,
+            },
+            ArrayOut {
+                name: "shapes",
+                ptr: self.shapes,
+                capacity: self.shapes_capacity,
+                len: &mut self.shapes_len,
+            },
+        )
+    }
+}
+
+impl OutputSlices<[Shape<FloatPoint<f64>>]> for FlatF64ShapesSlices {
+    #[inline]
+    fn write(&mut self, shapes: &[Shape<FloatPoint<f64>>]) -> Result<(), OutputSlicesError> {
+        write_shapes(
+            shapes,
+            |point| [point.x, point.y],
+            ArrayOut {
+                name: "points",
+                ptr: self.points,
+                capacity: self.points_capacity,
+                len: &mut self.points_len,
+            },
+            ArrayOut {
+                name: "contours",
+                ptr: self.contours,
+                capacity: self.contours_capacity,
+                len: &mut self.contours_len,
+            },
+            ArrayOut {
+                name: "shapes",
+                ptr: self.shapes,
+                capacity: self.shapes_capacity,
+                len: &mut self.shapes_len,
+            },
+        )
+    }
+}
+
+#[inline]
+fn write_shapes<P, C: Copy>(
+    shapes: &[Shape<P>],
+    coords: fn(&P) -> [C; 2],
+    mut points_out: ArrayOut<'_, C>,
+    mut contours_out: ArrayOut<'_, RangeFFI>,
+    mut shapes_out: ArrayOut<'_, RangeFFI>,
+) -> Result<(), OutputSlicesError> {
+    let contour_count: usize = shapes.iter().map(Vec::len).sum();
+    let point_count: usize = shapes.iter().flatten().map(Vec::len).sum();
+
+    // Report every length before failing so a single query pass sizes all arrays.
+    let points_fit = points_out.require(point_count * 2);
+    let contours_fit = contours_out.require(contour_count);
+    let shapes_fit = shapes_out.require(shapes.len());
+    points_fit.and(contours_fit).and(shapes_fit)?;
+
+    let points = points_out.as_mut_slice()?;
+    let contours = contours_out.as_mut_slice()?;
+    let shape_ranges = shapes_out.as_mut_slice()?;
+
+    let mut point_offset = 0;
+    let mut contour_offset = 0;
+    for (shape, shape_range) in shapes.iter().zip(shape_ranges.iter_mut()) {
+        let shape_start = contour_offset;
+        for contour in shape {
+            let start = point_offset;
+            for point in contour {
+                let [x, y] = coords(point);
+                points[point_offset] = x;
+                points[point_offset + 1] = y;
+                point_offset += 2;
+            }
+
+            contours[contour_offset] = RangeFFI {
+                start: start as u64,
+                end: point_offset as u64,
+            };
+            contour_offset += 1;
+        }
+
+        *shape_range = RangeFFI {
+            start: shape_start as u64,
+            end: contour_offset as u64,
+        };
+    }
+
+    Ok(())
+}
@@ -0,0 +1,184 @@
+use alloc::format;
+use alloc::string::String;
+use core::cell::RefCell;
+use core::ffi::CStr;
+use core::fmt;
+
+#[cfg(feature = "host-allocator")]
+use crate::allocator::SetAllocatorError;
+use crate::bool::f32_overlay::AddContourError as Float32AddContourError;
+use crate::bool::f64_overlay::AddContourError as Float64AddContourError;
+use crate::bool::int_overlay::AddContourError as IntAddContourError;
+use crate::shape::{FlatLayoutError, NonFiniteError, OutputSlicesError};
+
+/// Result code returned by every fallible `extern "C"` entry point.
+#[repr(C)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum IShapeStatus {
+    /// The operation completed successfully.
+    Ok = 0,
+    /// A required handle or buffer pointer was null.
+    NullPointer = 1,
+    /// The coordinate buffer length is not a multiple of two.
+    OddCoordinateCount = 2,
+    /// The input does not contain enough points for the requested operation.
+    TooFewPoints = 3,
+    /// The line join kind is unknown or its value is not finite.
+    InvalidLineJoin = 4,
+    /// The line cap kind is unknown or its value is not finite.
+    InvalidLineCap = 5,
+    /// The stroke width is not a positive, finite number.
+    InvalidWidth = 6,
+    /// A range is reversed, out of bounds or not aligned to a point.
+    InvalidRange = 7,
+    /// An internal panic was caught at the FFI boundary; outputs of the call are cleared.
+    Panic = 8,
+    /// A caller-provided output array is too small; the required lengths have been reported.
+    InsufficientCapacity = 9,
+    /// An input coordinate or offset is NaN or infinite.
+    NonFiniteValue = 10,
+}
+
+impl IShapeStatus {
+    /// Returns `true` when the status represents success.
+    #[inline]
+    pub fn is_ok(self) -> bool {
+        self == IShapeStatus::Ok
+    }
+
+    /// Returns a static, NUL-terminated description of the status.
+    #[inline]
+    pub fn message(self) -> &'static CStr {
+        match self {
+            IShapeStatus::Ok => c"ok",
+            IShapeStatus::NullPointer => c"a required pointer was null",
+            IShapeStatus::OddCoordinateCount => c"coordinate count is not a multiple of two",
+            IShapeStatus::TooFewPoints => c"not enough points for the requested operation",
+            IShapeStatus::InvalidLineJoin => c"invalid line join kind or value",
+            IShapeStatus::InvalidLineCap => c"invalid line cap kind or value",
+            IShapeStatus::InvalidWidth => c"stroke width must be positive and finite",
+            IShapeStatus::InvalidRange => c"range is reversed, out of bounds or misaligned",
+            IShapeStatus::Panic => c"internal panic caught at the FFI boundary",
+            IShapeStatus::InsufficientCapacity => c"output array capacity is too small",
+            IShapeStatus::NonFiniteValue => c"coordinate or offset is NaN or infinite",
+        }
+    }
+}
+
+impl From<IntAddContourError> for IShapeStatus {
+    #[inline]
+    fn from(value: IntAddContourError) -> Self {
+        match value {
+            IntAddContourError::OddCoordinateCount => IShapeStatus::OddCoordinateCount,
+        }
+    }
+}
+
+impl From<Float32AddContourError> for IShapeStatus {
+    #[inline]
+    fn from(value: Float32AddContourError) -> Self {
+        match value {
+            Float32AddContourError::OddCoordinateCount => IShapeStatus::OddCoordinateCount,
+            Float32AddContourError::NonFinite(err) => err.into(),
+        }
+    }
+}
+
+impl From<Float64AddContourError> for IShapeStatus {
+    #[inline]
+    fn from(value: Float64AddContourError) -> Self {
+        match value {
+            Float64AddContourError::OddCoordinateCount => IShapeStatus::OddCoordinateCount,
+            Float64AddContourError::NonFinite(err) => err.into(),
+        }
+    }
+}
+
+impl From<FlatLayoutError> for IShapeStatus {
+    #[inline]
+    fn from(value: FlatLayoutError) -> Self {
+        match value {
+            FlatLayoutError::OddCoordinateCount { .. } => IShapeStatus::OddCoordinateCount,
+            FlatLayoutError::InvalidContourRange { .. }
+            | FlatLayoutError::InvalidShapeRange { .. }
+            | FlatLayoutError::OverlappingContourRange { .. }
+            | FlatLayoutError::OverlappingShapeRange { .. } => IShapeStatus::InvalidRange,
+        }
+    }
+}
+
+impl From<NonFiniteError> for IShapeStatus {
+    #[inline]
+    fn from(_: NonFiniteError) -> Self {
+        IShapeStatus::NonFiniteValue
+    }
+}
+
+impl From<OutputSlicesError> for IShapeStatus {
+    #[inline]
+    fn from(value: OutputSlicesError) -> Self {
+        match value {
+            OutputSlicesError::NullArray { .. } => IShapeStatus::NullPointer,
+            OutputSlicesError::InsufficientCapacity { .. } => IShapeStatus::InsufficientCapacity,
+        }
+    }
+}
+
+#[cfg(feature = "host-allocator")]
+impl From<SetAllocatorError> for IShapeStatus {
+    #[inline]
+    fn from(value: SetAllocatorError) -> Self {
+        match value {
+            SetAllocatorError::MissingFunction => IShapeStatus::NullPointer,
+        }
+    }
+}
+
+thread_local! {
+    static LAST_ERROR: RefCell<Option<String>> = const { RefCell::new(None) };
+}
+
+/// Records `status` together with a context description as the calling thread's last error.
+#[inline]
+pub(crate) fn set_last_error(status: IShapeStatus, detail: fmt::Arguments<'_>) {
+    let message = format!("{}: {}", status.message().to_string_lossy(), detail);
+    LAST_ERROR.with(|last| *last.borrow_mut() = Some(message));
+}
+
+/// Records the last error and returns `status`, so failure paths can `return fail(...)`.
+#[inline]
+pub(crate) fn fail(status: IShapeStatus, detail: fmt::Arguments<'_>) -> IShapeStatus {
+    set_last_error(status, detail);
+    status
+}
+
+/// Forgets the calling thread's last error.
+#[inline]
+pub(crate) fn clear_last_error() {
+    LAST_ERROR.with(|last| *last.borrow_mut() = None);
+}
+
+/// Copies the calling thread's last error into `dst` as a NUL-terminated string, truncating it
+/// when `dst` is too small. Returns the full message length including the terminator, or `0`
+/// when no error has been recorded.
+#[inline]
+pub(crate) fn copy_last_error(dst: &mut [u8]) -> usize {
+    LAST_ERROR.with(|last| {
+        let last = last.borrow();
+        let Some(message) = last.as_deref() else {
+            if let Some(first) = dst.first_mut() {
+                *first = 0;
+            }
+            return 0;
+        };
+
+        let bytes = message.as_bytes();
+        if !dst.is_empty() {
+            let copied = bytes.len().min(dst.len() - 1);
+            dst[..copied].copy_from_slice(&bytes[..copied]);
+            dst[copied] = 0;
+        }
+
+        bytes.len() + 1
+    })
+}
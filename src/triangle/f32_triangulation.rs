@@ -1,27 +1,72 @@
-use alloc::vec::Vec;
+use core::ptr;
 use i_triangle::float::triangulation::Triangulation as CoreTriangulation;
 use i_triangle::i_overlay::i_float::float::point::FloatPoint;
 
 use super::IntTriangulationIndex;
+use crate::allocator::{HostVec, host_vec};
 
 type Float32Point = FloatPoint<f32>;
 type Triangulation32 = CoreTriangulation<Float32Point, IntTriangulationIndex>;
 
 /// Flattened representation of a single-precision triangulation for FFI usage.
-#[repr(C)]
-#[derive(Debug, Clone, Default)]
+#[derive(Debug, Clone)]
 pub struct FlatF32Triangulation {
-    pub flat_points: Vec<f32>,
-    pub indices: Vec<IntTriangulationIndex>,
+    pub flat_points: HostVec<f32>,
+    pub indices: HostVec<IntTriangulationIndex>,
+}
+
+impl Default for FlatF32Triangulation {
+    #[inline]
+    fn default() -> Self {
+        Self {
+            flat_points: host_vec(),
+            indices: host_vec(),
+        }
+    }
+}
+
+/// Borrowed, layout-stable view of a [`FlatF32Triangulation`].
+///
+/// Every pointer is valid for its matching length until the buffer is modified or freed.
+#[repr(C)]
+#[derive(Debug, Clone, Copy)]
+pub struct FlatF32TriangulationView {
+    pub points: *const f32,
+    pub points_len: usize,
+    pub indices: *const IntTriangulationIndex,
+    pub indices_len: usize,
+}
+
+impl Default for FlatF32TriangulationView {
+    #[inline]
+    fn default() -> Self {
+        Self {
+            points: ptr::null(),
+            points_len: 0,
+            indices: ptr::null(),
+            indices_len: 0,
+        }
+    }
 }
 
 impl FlatF32Triangulation {
     /// Constructs an empty buffer reserving enough capacity for the provided counts.
     #[inline]
     pub fn with_capacity(points: usize, triangles: usize) -> Self {
-        Self {
-            flat_points: Vec::with_capacity(points * 2),
-            indices: Vec::with_capacity(triangles * 3),
+        let mut buffer = Self::default();
+        buffer.flat_points.reserve(points * 2);
+        buffer.indices.reserve(triangles * 3);
+        buffer
+    }
+
+    /// Returns a layout-stable view of the stored arrays.
+    #[inline]
+    pub fn view(&self) -> FlatF32TriangulationView {
+        FlatF32TriangulationView {
+            points: self.flat_points.as_ptr(),
+            points_len: self.flat_points.len(),
+            indices: self.indices.as_ptr(),
+            indices_len: self.indices.len(),
         }
     }
 
@@ -1,27 +1,72 @@
-use alloc::vec::Vec;
+use core::ptr;
 use i_triangle::float::triangulation::Triangulation as CoreTriangulation;
 use i_triangle::i_overlay::i_float::float::point::FloatPoint;
 
 use super::IntTriangulationIndex;
+use crate::allocator::{HostVec, host_vec};
 
 type Float64Point = FloatPoint<f64>;
 type Triangulation64 = CoreTriangulation<Float64Point, IntTriangulationIndex>;
 
 /// Flattened representation of a double-precision triangulation for FFI usage.
-#[repr(C)]
-#[derive(Debug, Clone, Default)]
+#[derive(Debug, Clone)]
 pub struct FlatF64Triangulation {
-    pub flat_points: Vec<f64>,
-    pub indices: Vec<IntTriangulationIndex>,
+    pub flat_points: HostVec<f64>,
+    pub indices: HostVec<IntTriangulationIndex>,
+}
+
+impl Default for FlatF64Triangulation {
+    #[inline]
+    fn default() -> Self {
+        Self {
+            flat_points: host_vec(),
+            indices: host_vec(),
+        }
+    }
+}
+
+/// Borrowed, layout-stable view of a [`FlatF64Triangulation`].
+///
+/// Every pointer is valid for its matching length until the buffer is modified or freed.
+#[repr(C)]
+#[derive(Debug, Clone, Copy)]
+pub struct FlatF64TriangulationView {
+    pub points: *const f64,
+    pub points_len: usize,
+    pub indices: *const IntTriangulationIndex,
+    pub indices_len: usize,
+}
+
+impl Default for FlatF64TriangulationView {
+    #[inline]
+    fn default() -> Self {
+        Self {
+            points: ptr::null(),
+            points_len: 0,
+            indices: ptr::null(),
+            indices_len: 0,
+        }
+    }
 }
 
 impl FlatF64Triangulation {
     /// Constructs an empty buffer reserving enough capacity for the provided counts.
     #[inline]
     pub fn with_capacity(points: usize, triangles: usize) -> Self {
-        Self {
-            flat_points: Vec::with_capacity(points * 2),
-            indices: Vec::with_capacity(triangles * 3),
+        let mut buffer = Self::default();
+        buffer.flat_points.reserve(points * 2);
+        buffer.indices.reserve(triangles * 3);
+        buffer
+    }
+
+    /// Returns a layout-stable view of the stored arrays.
+    #[inline]
+    pub fn view(&self) -> FlatF64TriangulationView {
+        FlatF64TriangulationView {
+            points: self.flat_points.as_ptr(),
+            points_len: self.flat_points.len(),
+            indices: self.indices.as_ptr(),
+            indices_len: self.indices.len(),
         }
     }
 
@@ -1,20 +1,68 @@
+use i_triangle::float::triangulation::Triangulation as CoreTriangulation;
 use i_triangle::float::triangulator::Triangulator as CoreTriangulator;
 use i_triangle::i_overlay::core::solver::Solver;
+use i_triangle::i_overlay::i_float::float::point::FloatPoint;
 
-use super::{IntTriangulationIndex, types::IntTriangulatorValidation};
+use super::IntTriangulationIndex;
+use super::types::{FloatTriangulatorOptions, IntTriangulatorValidation};
+use crate::shape::{FlatContours, FlatF64ShapesBuffer, IShapeNonFinitePolicy, NonFiniteError};
+
+type Triangulation64 = CoreTriangulation<FloatPoint<f64>, IntTriangulationIndex>;
 
 /// FFI-safe wrapper around `i_triangle::float::triangulator::Triangulator` producing `f64` results.
+///
+/// Keeps the contours and triangulation of the last call, so triangulating inputs of a similar
+/// size again does not allocate.
 pub struct Float64Triangulator {
     pub(crate) inner: CoreTriangulator<IntTriangulationIndex>,
+    pub(crate) non_finite: IShapeNonFinitePolicy,
+    contours: FlatContours<f64>,
+    triangulation: Triangulation64,
 }
 
 impl Float64Triangulator {
+    /// Creates a triangulator that rejects non-finite input coordinates.
     #[inline]
     pub fn new(max_points_count: usize, validation: IntTriangulatorValidation) -> Self {
-        let mut solver = Solver::default();
-        solver.multithreading = None;
+        Self::with_options(max_points_count, validation.into())
+    }
+
+    #[inline]
+    pub fn with_options(max_points_count: usize, options: FloatTriangulatorOptions) -> Self {
+        let solver = Solver {
+            multithreading: None,
+            ..Default::default()
+        };
         Self {
-            inner: CoreTriangulator::new(max_points_count, validation.into(), solver),
+            inner: CoreTriangulator::new(max_points_count, options.validation.into(), solver),
+            non_finite: options.non_finite,
+            contours: FlatContours::default(),
+            triangulation: Triangulation64::with_capacity(0),
+        }
+    }
+
+    /// Triangulates every contour of `shapes`, applying the `non_finite` policy first.
+    ///
+    /// The result borrows the triangulator and is replaced by the next call.
+    ///
+    /// # Panics
+    /// Panics when the ranges are malformed; call [`FlatF64ShapesBuffer::validate`] first for
+    /// buffers filled by foreign code.
+    #[inline]
+    pub fn triangulate_flat(
+        &mut self,
+        shapes: &FlatF64ShapesBuffer,
+    ) -> Result<&Triangulation64, NonFiniteError> {
+        shapes.finite_contours_into(self.non_finite, &mut self.contours)?;
+
+        if self.contours.is_empty() {
+            self.triangulation.points.clear();
+            self.triangulation.indices.clear();
+        } else {
+            self.inner
+                .triangulate_into(&self.contours, &mut self.triangulation);
         }
+
+        Ok(&self.triangulation)
     }
 }
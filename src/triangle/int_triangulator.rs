@@ -1,7 +1,10 @@
 use i_triangle::i_overlay::core::solver::Solver;
+use i_triangle::i_overlay::i_shape::flat::buffer::FlatContoursBuffer;
+use i_triangle::int::triangulation::IntTriangulation;
 use i_triangle::int::triangulator::IntTriangulator as CoreIntTriangulator;
 
 use super::{IntTriangulationIndex, types::IntTriangulatorValidation};
+use crate::shape::FlatShapesBuffer;
 
 /// Errors that can occur when interpreting a flat contour buffer.
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
@@ -10,18 +13,52 @@ pub enum TriangulateContourError {
 }
 
 /// FFI-safe wrapper around `i_triangle::int::triangulator::IntTriangulator`.
+///
+/// Keeps the contours and triangulation of the last call, so triangulating inputs of a similar
+/// size again does not allocate.
 pub struct IntTriangulator {
     pub(crate) inner: CoreIntTriangulator<IntTriangulationIndex>,
+    contours: FlatContoursBuffer,
+    triangulation: IntTriangulation<IntTriangulationIndex>,
 }
 
 impl IntTriangulator {
     /// Constructs a new triangulator with explicit validation configuration.
     #[inline]
     pub fn new(max_points_count: usize, validation: IntTriangulatorValidation) -> Self {
-        let mut solver = Solver::default();
-        solver.multithreading = None;
+        let solver = Solver {
+            multithreading: None,
+            ..Default::default()
+        };
         Self {
             inner: CoreIntTriangulator::new(max_points_count, validation.into(), solver),
+            contours: FlatContoursBuffer::default(),
+            triangulation: IntTriangulation::with_capacity(0),
         }
     }
+
+    /// Triangulates every contour of `shapes`.
+    ///
+    /// The result borrows the triangulator and is replaced by the next call.
+    ///
+    /// # Panics
+    /// Panics when the ranges are malformed; call [`FlatShapesBuffer::validate`] first for
+    /// buffers filled by foreign code.
+    #[inline]
+    pub fn triangulate_flat(
+        &mut self,
+        shapes: &FlatShapesBuffer,
+    ) -> &IntTriangulation<IntTriangulationIndex> {
+        shapes.contours_into(&mut self.contours);
+
+        if self.contours.is_empty() {
+            self.triangulation.points.clear();
+            self.triangulation.indices.clear();
+        } else {
+            self.inner
+                .triangulate_flat_into(&mut self.contours, &mut self.triangulation);
+        }
+
+        &self.triangulation
+    }
 }
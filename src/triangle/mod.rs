@@ -4,14 +4,18 @@ pub mod f64_triangulation;
 pub mod f64_triangulator;
 pub mod int_triangulation;
 pub mod int_triangulator;
+pub mod slices;
 pub mod types;
 
 pub type IntTriangulationIndex = u32;
 
-pub use f32_triangulation::FlatF32Triangulation;
+pub use f32_triangulation::{FlatF32Triangulation, FlatF32TriangulationView};
 pub use f32_triangulator::Float32Triangulator;
-pub use f64_triangulation::FlatF64Triangulation;
+pub use f64_triangulation::{FlatF64Triangulation, FlatF64TriangulationView};
 pub use f64_triangulator::Float64Triangulator;
-pub use int_triangulation::FlatIntTriangulation;
+pub use int_triangulation::{FlatIntTriangulation, FlatIntTriangulationView};
 pub use int_triangulator::IntTriangulator;
-pub use types::IntTriangulatorValidation;
+pub use slices::{
+    FlatF32TriangulationSlices, FlatF64TriangulationSlices, FlatIntTriangulationSlices,
+};
+pub use types::{FloatTriangulatorOptions, IntTriangulatorValidation};
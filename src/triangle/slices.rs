@@ -0,0 +1,181 @@
+use i_triangle::float::triangulation::Triangulation as CoreTriangulation;
+use i_triangle::i_overlay::i_float::float::point::FloatPoint;
+use i_triangle::int::triangulation::IntTriangulation as CoreIntTriangulation;
+
+use super::IntTriangulationIndex;
+use crate::shape::slices::{ArrayOut, OutputSlices, OutputSlicesError};
+
+/// Caller-provided arrays receiving an integer triangulation laid out like `FlatIntTriangulation`.
+///
+/// Before the call every `*_capacity` holds the number of elements its array can take. After the
+/// call every `*_len` holds the number of elements the result needs, also when it did not fit.
+#[repr(C)]
+#[derive(Debug)]
+pub struct FlatIntTriangulationSlices {
+    pub points: *mut i32,
+    pub points_capacity: usize,
+    pub points_len: usize,
+    pub indices: *mut IntTriangulationIndex,
+    pub indices_capacity: usize,
+    pub indices_len: usize,
+}
+
+/// Caller-provided arrays receiving an `f32` triangulation laid out like `FlatF32Triangulation`.
+///
+/// Capacities and lengths follow the same rules as [`FlatIntTriangulationSlices`].
+#[repr(C)]
+#[derive(Debug)]
+pub struct FlatF32TriangulationSlices {
+    pub points: *mut f32,
+    pub points_capacity: usize,
+    pub points_len: usize,
+    pub indices: *mut IntTriangulationIndex,
+    pub indices_capacity: usize,
+    pub indices_len: usize,
+}
+
+/// Caller-provided arrays receiving an `f64` triangulation laid out like `FlatF64Triangulation`.
+///
+/// Capacities and lengths follow the same rules as [`FlatIntTriangulationSlices`].
+#[repr(C)]
+#[derive(Debug)]
+pub struct FlatF64TriangulationSlices {
+    pub points: *mut f64,
+    pub points_capacity: usize,
+    pub points_len: usize,
+    pub indices: *mut IntTriangulationIndex,
+    pub indices_capacity: usize,
+    pub indices_len: usize,
+}
+
+impl FlatIntTriangulationSlices {
+    /// Resets every reported length to zero.
+    #[inline]
+    pub fn clear(&mut self) {
+        self.points_len = 0;
+        self.indices_len = 0;
+    }
+}
+
+impl FlatF32TriangulationSlices {
+    /// Resets every reported length to zero.
+    #[inline]
+    pub fn clear(&mut self) {
+        self.points_len = 0;
+        self.indices_len = 0;
+    }
+}
+
+impl FlatF64TriangulationSlices {
+    /// Resets every reported length to zero.
+    #[inline]
+    pub fn clear(&mut self) {
+        self.points_len = 0;
+        self.indices_len = 0;
+    }
+}
+
+impl OutputSlices<CoreIntTriangulation<IntTriangulationIndex>> for FlatIntTriangulationSlices {
+    #[inline]
+    fn write(
+        &mut self,
+        triangulation: &CoreIntTriangulation<IntTriangulationIndex>,
+    ) -> Result<(), OutputSlicesError> {
+        write_triangulation(
+            &triangulation.points,
+            &triangulation.indices,
+            |point| [point.x, point.y],
+            ArrayOut {
+                name: "points",
+                ptr: self.points,
+                capacity: self.points_capacity,
+                len: &mut self.points_len,
+            },
+            ArrayOut {
+                name: "indices",
+                ptr: self.indices,
+                capacity: self.indices_capacity,
+                len: &mut self.indices_len,
+            },
+        )
+    }
+}
+
+impl OutputSlices<CoreTriangulation<FloatPoint<f32>, IntTriangulationIndex>>
+    for FlatF32TriangulationSlices
+{
+    #[inline]
+    fn write(
+        &mut self,
+        triangulation: &CoreTriangulation<FloatPoint<f32>, IntTriangulationIndex>,
+    ) -> Result<(), OutputSlicesError> {
+        write_triangulation(
+            &triangulation.points,
+            &triangulation.indices,
+            |point| [point.x, point.y],
+            ArrayOut {
+                name: "points",
+                ptr: self.points,
+                capacity: self.points_capacity,
+                len: &mut self.points_len,
+            },
+            ArrayOut {
+                name: "indices",
+                ptr: self.indices,
+                capacity: self.indices_capacity,
+                len: &mut self.indices_len,
+            },
+        )
+    }
+}
+
+impl OutputSlices<CoreTriangulation<FloatPoint<f64>, IntTriangulationIndex>>
+    for FlatF64TriangulationSlices
+{
+    #[inline]
+    fn write(
+        &mut self,
+        triangulation: &CoreTriangulation<FloatPoint<f64>, IntTriangulationIndex>,
+    ) -> Result<(), OutputSlicesError> {
+        write_triangulation(
+            &triangulation.points,
+            &triangulation.indices,
+            |point| [point.x, point.y],
+            ArrayOut {
+                name: "points",
+                ptr: self.points,
+                capacity: self.points_capacity,
+                len: &mut self.points_len,
+            },
+            ArrayOut {
+                name: "indices",
+                ptr: self.indices,
+                capacity: self.indices_capacity,
+                len: &mut self.indices_len,
+            },
+        )
+    }
+}
+
+#[inline]
+fn write_triangulation<P, C: Copy>(
+    points: &[P],
+    indices: &[IntTriangulationIndex],
+    coords: fn(&P) -> [C; 2],
+    mut points_out: ArrayOut<'_, C>,
+    mut indices_out: ArrayOut<'_, IntTriangulationIndex>,
+) -> Result<(), OutputSlicesError> {
+    // Report every length before failing so a single query pass sizes all arrays.
+    let points_fit = points_out.require(points.len() * 2);
+    let indices_fit = indices_out.require(indices.len());
+    points_fit.and(indices_fit)?;
+
+    let flat_points = points_out.as_mut_slice()?;
+    for (chunk, point) in flat_points.chunks_exact_mut(2).zip(points) {
+        chunk.copy_from_slice(&coords(point));
+    }
+
+    indices_out.as_mut_slice()?.copy_from_slice(indices);
+
+    Ok(())
+}
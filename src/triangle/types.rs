@@ -1,4 +1,5 @@
 use crate::bool::{IntFillRule, IntOverlayOptions};
+use crate::shape::IShapeNonFinitePolicy;
 use i_triangle::int::validation::Validation as CoreValidation;
 
 /// FFI-safe representation of `i_triangle::int::validation::Validation`.
@@ -40,3 +41,23 @@ impl Default for IntTriangulatorValidation {
         }
     }
 }
+
+/// Options for the `f32` and `f64` triangulators: the validation shared with the integer
+/// triangulator plus the policy for non-finite input coordinates.
+#[repr(C)]
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub struct FloatTriangulatorOptions {
+    pub validation: IntTriangulatorValidation,
+    /// Applied to every contour of the triangulated shapes.
+    pub non_finite: IShapeNonFinitePolicy,
+}
+
+impl From<IntTriangulatorValidation> for FloatTriangulatorOptions {
+    #[inline]
+    fn from(validation: IntTriangulatorValidation) -> Self {
+        Self {
+            validation,
+            non_finite: IShapeNonFinitePolicy::default(),
+        }
+    }
+}
@@ -0,0 +1,36 @@
+use core::ffi::CStr;
+
+/// Version of the C ABI exposed by this library, emitted into `ishape.h` for compile-time checks.
+///
+/// Bumped whenever an existing entry point, struct layout or status code changes incompatibly;
+/// additions keep the current value.
+pub const ISHAPE_ABI_VERSION: u32 = 2;
+
+/// Crate version followed by the `i_triangle` and `i_overlay` versions it was built against,
+/// e.g. `0.1.0 (i_triangle 0.38.0, i_overlay 4.1.1)`.
+pub const VERSION: &CStr = match CStr::from_bytes_with_nul(
+    concat!(
+        env!("CARGO_PKG_VERSION"),
+        " (i_triangle ",
+        env!("ISHAPE_I_TRIANGLE_VERSION"),
+        ", i_overlay ",
+        env!("ISHAPE_I_OVERLAY_VERSION"),
+        ")\0"
+    )
+    .as_bytes(),
+) {
+    Ok(version) => version,
+    Err(_) => panic!("version string must not contain NUL bytes"),
+};
+
+/// Optional capabilities and whether they were compiled in; names match the crate's cargo
+/// features.
+const FEATURES: &[(&[u8], bool)] = &[(b"host-allocator", cfg!(feature = "host-allocator"))];
+
+/// Returns `true` when the optional capability `name` was compiled in.
+#[inline]
+pub fn has_feature(name: &[u8]) -> bool {
+    FEATURES
+        .iter()
+        .any(|&(feature, enabled)| enabled && feature == name)
+}
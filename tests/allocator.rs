@@ -0,0 +1,131 @@
+//! Host allocator hooks. They are process-wide, so this binary holds a single test that no other
+//! test can race with.
+
+#![cfg(feature = "host-allocator")]
+
+mod common;
+
+use std::alloc::{GlobalAlloc, Layout, System};
+use std::ffi::c_void;
+use std::ptr;
+use std::sync::atomic::{AtomicIsize, AtomicUsize, Ordering};
+
+use common::{Handle, last_error, square};
+use i_shape_ffi::*;
+
+/// Calls and outstanding bytes seen by one set of hooks, passed as their `user_data`.
+#[derive(Default)]
+struct Usage {
+    calls: AtomicUsize,
+    live_bytes: AtomicIsize,
+}
+
+impl Usage {
+    fn of(user_data: *mut c_void) -> &'static Usage {
+        unsafe { &*user_data.cast::<Usage>() }
+    }
+
+    fn calls(&self) -> usize {
+        self.calls.load(Ordering::SeqCst)
+    }
+
+    fn live_bytes(&self) -> isize {
+        self.live_bytes.load(Ordering::SeqCst)
+    }
+
+    fn record(&self, bytes: isize) {
+        self.calls.fetch_add(1, Ordering::SeqCst);
+        self.live_bytes.fetch_add(bytes, Ordering::SeqCst);
+    }
+}
+
+unsafe extern "C" fn alloc(size: usize, align: usize, user_data: *mut c_void) -> *mut c_void {
+    Usage::of(user_data).record(size as isize);
+    unsafe {
+        System
+            .alloc(Layout::from_size_align(size, align).unwrap())
+            .cast()
+    }
+}
+
+unsafe extern "C" fn realloc(
+    ptr: *mut c_void,
+    old_size: usize,
+    align: usize,
+    new_size: usize,
+    user_data: *mut c_void,
+) -> *mut c_void {
+    Usage::of(user_data).record(new_size as isize - old_size as isize);
+    let layout = Layout::from_size_align(old_size, align).unwrap();
+    unsafe { System.realloc(ptr.cast(), layout, new_size).cast() }
+}
+
+unsafe extern "C" fn free(ptr: *mut c_void, size: usize, align: usize, user_data: *mut c_void) {
+    Usage::of(user_data).record(-(size as isize));
+    unsafe { System.dealloc(ptr.cast(), Layout::from_size_align(size, align).unwrap()) }
+}
+
+fn fill(buffer: &Handle<FlatF64ShapesBuffer>) {
+    for i in 0..32 {
+        let coords = square(i as f64, 0.0, 1.0);
+        let status =
+            ishape_flat_f64_shapes_push_contour(buffer.ptr(), coords.as_ptr(), coords.len());
+        assert_eq!(status, IShapeStatus::Ok);
+    }
+}
+
+#[test]
+fn handles_and_buffers_are_released_through_the_hooks_that_allocated_them() {
+    let first = Box::leak(Box::new(Usage::default()));
+    let second = Box::leak(Box::new(Usage::default()));
+    let user_data = |usage: &Usage| ptr::from_ref(usage).cast_mut().cast::<c_void>();
+
+    assert_eq!(
+        ishape_set_allocator(Some(alloc), None, None, user_data(first)),
+        IShapeStatus::NullPointer
+    );
+    assert!(
+        last_error()
+            .unwrap()
+            .contains("both be set or both be null")
+    );
+
+    // The first hooks grow buffers with their realloc function, the second ones without.
+    let status = ishape_set_allocator(Some(alloc), Some(realloc), Some(free), user_data(first));
+    assert_eq!(status, IShapeStatus::Ok);
+    let buffer = Handle::new(ishape_flat_f64_shapes_create(), ishape_flat_f64_shapes_free);
+    let overlay = Handle::new(
+        ishape_overlay_f64_create(0, Float64OverlayOptions::default()),
+        ishape_overlay_f64_free,
+    );
+    fill(&buffer);
+    assert!(first.calls() > 2 && first.live_bytes() > 0);
+
+    let status = ishape_set_allocator(Some(alloc), None, Some(free), user_data(second));
+    assert_eq!(status, IShapeStatus::Ok);
+    let other = Handle::new(ishape_flat_f64_shapes_create(), ishape_flat_f64_shapes_free);
+    fill(&other);
+    assert!(second.calls() > 2 && second.live_bytes() > 0);
+
+    // Memory from the first hooks still grows and is released through them.
+    let first_calls = first.calls();
+    let second_calls = second.calls();
+    fill(&buffer);
+    assert!(first.calls() > first_calls);
+    assert_eq!(second.calls(), second_calls);
+    drop(buffer);
+    drop(overlay);
+    assert_eq!(first.live_bytes(), 0);
+    assert_eq!(second.calls(), second_calls);
+
+    // Null hooks restore the global allocator for new handles only.
+    let status = ishape_set_allocator(None, None, None, ptr::null_mut());
+    assert_eq!(status, IShapeStatus::Ok);
+    let global = Handle::new(ishape_flat_f64_shapes_create(), ishape_flat_f64_shapes_free);
+    fill(&global);
+    drop(global);
+    assert_eq!(second.calls(), second_calls);
+
+    drop(other);
+    assert_eq!(second.live_bytes(), 0);
+}
@@ -0,0 +1,229 @@
+//! Helpers shared by the integration tests, which drive the library through its `extern "C"`
+//! entry points exactly as a C caller would.
+
+#![allow(dead_code)]
+
+use std::ffi::CStr;
+use std::ptr;
+
+use i_shape_ffi::*;
+
+/// A heap object owned by the library, released through its matching `*_free` entry point.
+pub struct Handle<T> {
+    ptr: *mut T,
+    free: extern "C" fn(*mut T),
+}
+
+impl<T> Handle<T> {
+    pub fn new(ptr: *mut T, free: extern "C" fn(*mut T)) -> Self {
+        assert!(!ptr.is_null(), "constructor returned null");
+        Self { ptr, free }
+    }
+
+    pub fn ptr(&self) -> *mut T {
+        self.ptr
+    }
+}
+
+impl<T> Drop for Handle<T> {
+    fn drop(&mut self) {
+        (self.free)(self.ptr);
+    }
+}
+
+pub fn int_shapes_buffer() -> Handle<FlatShapesBuffer> {
+    Handle::new(ishape_flat_shapes_create(), ishape_flat_shapes_free)
+}
+
+pub fn f32_shapes_buffer() -> Handle<FlatF32ShapesBuffer> {
+    Handle::new(ishape_flat_f32_shapes_create(), ishape_flat_f32_shapes_free)
+}
+
+pub fn f64_shapes_buffer() -> Handle<FlatF64ShapesBuffer> {
+    Handle::new(ishape_flat_f64_shapes_create(), ishape_flat_f64_shapes_free)
+}
+
+/// Shapes read back from a flat buffer: shapes of contours of `[x, y]` points.
+pub type Shapes = Vec<Vec<Vec<[f64; 2]>>>;
+
+/// Reads flat shape arrays through raw pointers and checks that their ranges are consistent.
+pub fn read_shapes<T: Copy + Into<f64>>(
+    points: *const T,
+    points_len: usize,
+    contours: *const RangeFFI,
+    contours_len: usize,
+    shapes: *const RangeFFI,
+    shapes_len: usize,
+) -> Shapes {
+    let points = raw_slice(points, points_len);
+    let contours = raw_slice(contours, contours_len);
+    let shapes = raw_slice(shapes, shapes_len);
+
+    assert_eq!(points.len() % 2, 0, "odd coordinate count");
+
+    shapes
+        .iter()
+        .map(|shape| {
+            assert!(shape.start <= shape.end && shape.end as usize <= contours.len());
+            contours[shape.start as usize..shape.end as usize]
+                .iter()
+                .map(|contour| {
+                    let (start, end) = (contour.start as usize, contour.end as usize);
+                    assert!(start <= end && end <= points.len() && start % 2 == 0);
+                    points[start..end]
+                        .chunks_exact(2)
+                        .map(|xy| [xy[0].into(), xy[1].into()])
+                        .collect()
+                })
+                .collect()
+        })
+        .collect()
+}
+
+pub fn read_int_shapes(buffer: *const FlatShapesBuffer) -> Shapes {
+    let view = ishape_flat_shapes_view(buffer);
+    read_shapes(
+        view.points,
+        view.points_len,
+        view.contours,
+        view.contours_len,
+        view.shapes,
+        view.shapes_len,
+    )
+}
+
+pub fn read_f32_shapes(buffer: *const FlatF32ShapesBuffer) -> Shapes {
+    let view = ishape_flat_f32_shapes_view(buffer);
+    read_shapes(
+        view.points,
+        view.points_len,
+        view.contours,
+        view.contours_len,
+        view.shapes,
+        view.shapes_len,
+    )
+}
+
+pub fn read_f64_shapes(buffer: *const FlatF64ShapesBuffer) -> Shapes {
+    let view = ishape_flat_f64_shapes_view(buffer);
+    read_shapes(
+        view.points,
+        view.points_len,
+        view.contours,
+        view.contours_len,
+        view.shapes,
+        view.shapes_len,
+    )
+}
+
+/// Signed shoelace area; counterclockwise contours are positive.
+pub fn contour_area(contour: &[[f64; 2]]) -> f64 {
+    let mut area = 0.0;
+    for (i, a) in contour.iter().enumerate() {
+        let b = contour[(i + 1) % contour.len()];
+        area += a[0] * b[1] - b[0] * a[1];
+    }
+    0.5 * area
+}
+
+/// Total area of shapes whose outer contours are counterclockwise and holes clockwise.
+pub fn shapes_area(shapes: &Shapes) -> f64 {
+    shapes
+        .iter()
+        .flatten()
+        .map(|contour| contour_area(contour))
+        .sum()
+}
+
+/// Absolute area enclosed by the shapes, independent of the output winding.
+pub fn abs_shapes_area(shapes: &Shapes) -> f64 {
+    shapes
+        .iter()
+        .map(|shape| {
+            let mut contours = shape.iter().map(|contour| contour_area(contour));
+            let outer = contours.next().unwrap_or(0.0);
+            let holes: f64 = contours.map(f64::abs).sum();
+            outer.abs() - holes
+        })
+        .sum()
+}
+
+pub fn assert_close(actual: f64, expected: f64, tolerance: f64) {
+    assert!(
+        (actual - expected).abs() <= tolerance,
+        "expected {expected} ± {tolerance}, got {actual}"
+    );
+}
+
+/// Axis-aligned counterclockwise square as `[x0, y0, x1, y1, ...]`.
+pub fn square(x: f64, y: f64, size: f64) -> Vec<f64> {
+    vec![x, y, x + size, y, x + size, y + size, x, y + size]
+}
+
+/// The same square in clockwise order.
+pub fn square_cw(x: f64, y: f64, size: f64) -> Vec<f64> {
+    vec![x, y, x, y + size, x + size, y + size, x + size, y]
+}
+
+/// Returns the calling thread's last error message, or `None` when none is recorded.
+pub fn last_error() -> Option<String> {
+    let len = ishape_last_error_message(ptr::null_mut(), 0);
+    if len == 0 {
+        return None;
+    }
+
+    let mut buffer = vec![0 as std::ffi::c_char; len];
+    assert_eq!(ishape_last_error_message(buffer.as_mut_ptr(), len), len);
+    let message = unsafe { CStr::from_ptr(buffer.as_ptr()) };
+    Some(message.to_str().unwrap().to_owned())
+}
+
+/// Checks a triangulation: every index is in bounds, triangles are non-degenerate and share one
+/// winding, and their total area equals `expected_area`.
+pub fn check_triangulation<T: Copy + Into<f64>>(
+    points: &[T],
+    indices: &[IntTriangulationIndex],
+    expected_area: f64,
+    tolerance: f64,
+) {
+    assert_eq!(points.len() % 2, 0, "odd coordinate count");
+    assert_eq!(
+        indices.len() % 3,
+        0,
+        "index count is not a multiple of three"
+    );
+    assert!(!indices.is_empty(), "no triangles");
+
+    let point_count = points.len() / 2;
+    let point = |index: IntTriangulationIndex| {
+        let index = index as usize;
+        assert!(index < point_count, "index {index} out of {point_count}");
+        [points[2 * index].into(), points[2 * index + 1].into()]
+    };
+
+    let mut total = 0.0;
+    let mut sign = 0.0;
+    for triangle in indices.chunks_exact(3) {
+        let area = contour_area(&[point(triangle[0]), point(triangle[1]), point(triangle[2])]);
+        assert!(area != 0.0, "degenerate triangle {triangle:?}");
+        if sign == 0.0 {
+            sign = area.signum();
+        }
+        assert_eq!(
+            area.signum(),
+            sign,
+            "triangle {triangle:?} has a flipped winding"
+        );
+        total += area.abs();
+    }
+
+    assert_close(total, expected_area, tolerance);
+}
+
+pub fn raw_slice<'a, T>(ptr: *const T, len: usize) -> &'a [T] {
+    if len == 0 {
+        return &[];
+    }
+    assert!(!ptr.is_null(), "null pointer with length {len}");
+    unsafe { std::slice::from_raw_parts(ptr, len) }
+}
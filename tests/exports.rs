@@ -0,0 +1,93 @@
+//! The shared library exports every `#[no_mangle]` item, so all of them must carry the
+//! `ishape_` prefix, and every one of them must be exercised by the integration tests.
+
+use std::fs;
+use std::path::{Path, PathBuf};
+
+#[test]
+fn exported_symbols_are_prefixed() {
+    let exported = exported_symbols();
+    assert!(!exported.is_empty(), "no exported symbols found");
+
+    for (path, name) in exported {
+        assert!(
+            name.starts_with("ishape_"),
+            "{}: exported symbol `{name}` must start with `ishape_`",
+            path.display()
+        );
+    }
+}
+
+#[test]
+fn exported_symbols_are_tested() {
+    let tests = Path::new(env!("CARGO_MANIFEST_DIR")).join("tests");
+    let sources: Vec<String> = rust_files(&tests)
+        .iter()
+        .filter(|path| !path.ends_with("exports.rs"))
+        .map(|path| fs::read_to_string(path).unwrap())
+        .collect();
+
+    let untested: Vec<String> = exported_symbols()
+        .into_iter()
+        .map(|(_, name)| name)
+        .filter(|name| !sources.iter().any(|source| mentions(source, name)))
+        .collect();
+
+    assert!(untested.is_empty(), "not covered by any test: {untested:?}");
+}
+
+/// Returns every `#[no_mangle]` item under `src` together with the file declaring it.
+fn exported_symbols() -> Vec<(PathBuf, String)> {
+    let src = Path::new(env!("CARGO_MANIFEST_DIR")).join("src");
+    let mut exported = Vec::new();
+
+    for path in rust_files(&src) {
+        let text = fs::read_to_string(&path).unwrap();
+        let mut lines = text.lines();
+        while let Some(line) = lines.next() {
+            if !line.contains("no_mangle") {
+                continue;
+            }
+
+            let item = lines
+                .by_ref()
+                .map(str::trim)
+                .find(|line| !line.starts_with("#[") && !line.starts_with("///"))
+                .unwrap_or_default();
+            let name = item
+                .split("fn ")
+                .nth(1)
+                .or_else(|| item.split("static ").nth(1))
+                .and_then(|rest| rest.split(['(', ':', '<']).next())
+                .map(|name| name.trim_start_matches("mut ").trim())
+                .unwrap_or(item);
+
+            exported.push((path.clone(), name.to_owned()));
+        }
+    }
+
+    exported
+}
+
+/// Returns `true` when `source` contains `name` as a whole identifier.
+fn mentions(source: &str, name: &str) -> bool {
+    source.match_indices(name).any(|(index, _)| {
+        let is_ident = |c: char| c.is_alphanumeric() || c == '_';
+        let before = source[..index].chars().next_back();
+        let after = source[index + name.len()..].chars().next();
+        !before.is_some_and(is_ident) && !after.is_some_and(is_ident)
+    })
+}
+
+fn rust_files(dir: &Path) -> Vec<PathBuf> {
+    let mut files = Vec::new();
+    for entry in fs::read_dir(dir).unwrap() {
+        let path = entry.unwrap().path();
+        if path.is_dir() {
+            files.extend(rust_files(&path));
+        } else if path.extension().is_some_and(|ext| ext == "rs") {
+            files.push(path);
+        }
+    }
+    files
+}
@@ -0,0 +1,12 @@
+//! Fails when the committed C header no longer matches the one generated by `build.rs`.
+
+#[test]
+fn committed_header_is_up_to_date() {
+    let generated = include_str!(concat!(env!("OUT_DIR"), "/ishape.h"));
+    let committed = include_str!("../include/ishape.h");
+
+    assert!(
+        generated == committed,
+        "include/ishape.h is stale; regenerate it with `ISHAPE_UPDATE_HEADER=1 cargo build`"
+    );
+}
@@ -0,0 +1,428 @@
+mod common;
+
+use common::{
+    Handle, abs_shapes_area, assert_close, check_triangulation, f32_shapes_buffer,
+    f64_shapes_buffer, last_error, raw_slice, read_f64_shapes, square, square_cw,
+};
+use i_shape_ffi::*;
+
+const POLICIES: [IShapeNonFinitePolicy; 3] = [
+    IShapeNonFinitePolicy::Reject,
+    IShapeNonFinitePolicy::DropPoint,
+    IShapeNonFinitePolicy::DropContour,
+];
+const BEVEL: u32 = 0;
+
+/// `contour` with an extra point after its second vertex holding `value` as its `x`.
+fn with_bad_point(contour: &[f64], value: f64) -> Vec<f64> {
+    let mut coords = contour.to_vec();
+    coords.splice(4..4, [value, 5.0]);
+    coords
+}
+
+fn overlay_area(policy: IShapeNonFinitePolicy, contours: &[Vec<f64>]) -> Result<f64, IShapeStatus> {
+    let options = Float64OverlayOptions {
+        non_finite: policy,
+        ..Default::default()
+    };
+    let overlay = Handle::new(
+        ishape_overlay_f64_create(0, options),
+        ishape_overlay_f64_free,
+    );
+    for contour in contours {
+        let status = ishape_overlay_f64_add_contour(
+            overlay.ptr(),
+            contour.as_ptr(),
+            contour.len(),
+            IntShapeType::Subject,
+        );
+        if status != IShapeStatus::Ok {
+            return Err(status);
+        }
+    }
+
+    let output = f64_shapes_buffer();
+    let status = ishape_overlay_f64_overlay_into_flat(
+        overlay.ptr(),
+        IntOverlayRule::Subject,
+        IntFillRule::NonZero,
+        output.ptr(),
+    );
+    assert_eq!(status, IShapeStatus::Ok);
+    Ok(abs_shapes_area(&read_f64_shapes(output.ptr())))
+}
+
+#[test]
+fn overlay_applies_policy_per_contour() {
+    for value in [f64::NAN, f64::INFINITY, f64::NEG_INFINITY] {
+        let contours = [
+            square(20.0, 0.0, 5.0),
+            with_bad_point(&square(0.0, 0.0, 10.0), value),
+        ];
+        for policy in POLICIES {
+            let area = overlay_area(policy, &contours);
+            match policy {
+                IShapeNonFinitePolicy::Reject => {
+                    assert_eq!(area, Err(IShapeStatus::NonFiniteValue));
+                    let message = last_error().unwrap();
+                    assert!(message.contains("contour 1 point 2"), "{message}");
+                }
+                IShapeNonFinitePolicy::DropPoint => assert_close(area.unwrap(), 125.0, 1e-6),
+                IShapeNonFinitePolicy::DropContour => assert_close(area.unwrap(), 25.0, 1e-6),
+            }
+        }
+    }
+}
+
+#[test]
+fn overlay_non_finite_errors_count_contours_from_clear() {
+    let overlay = Handle::new(
+        ishape_overlay_f64_create(0, Float64OverlayOptions::default()),
+        ishape_overlay_f64_free,
+    );
+    let bad = with_bad_point(&square(0.0, 0.0, 10.0), f64::NAN);
+    let add = |coords: &[f64]| {
+        ishape_overlay_f64_add_contour(
+            overlay.ptr(),
+            coords.as_ptr(),
+            coords.len(),
+            IntShapeType::Subject,
+        )
+    };
+
+    // The empty contour is accepted and counted although nothing is queued for it.
+    assert_eq!(add(&[]), IShapeStatus::Ok);
+    assert_eq!(add(&bad), IShapeStatus::NonFiniteValue);
+    let message = last_error().unwrap();
+    assert!(message.contains("contour 1 point 2"), "{message}");
+
+    ishape_overlay_f64_clear(overlay.ptr());
+    assert_eq!(add(&bad), IShapeStatus::NonFiniteValue);
+    let message = last_error().unwrap();
+    assert!(message.contains("contour 0 point 2"), "{message}");
+}
+
+#[test]
+fn overlay_drop_point_tolerates_degenerate_leftovers() {
+    // Only two finite points remain, which is too few for an area.
+    let contour = vec![0.0, 0.0, f64::NAN, 1.0, 10.0, 0.0, 5.0, f64::INFINITY];
+    let area = overlay_area(IShapeNonFinitePolicy::DropPoint, &[contour]);
+    assert_eq!(area, Ok(0.0));
+}
+
+fn outline_contour(
+    coords: &[f64],
+    offset: f64,
+    policy: IShapeNonFinitePolicy,
+) -> Result<f64, IShapeStatus> {
+    let output = f64_shapes_buffer();
+    let style = FfiOutlineStyle {
+        outer_offset: offset,
+        inner_offset: offset,
+        non_finite: policy,
+        ..Default::default()
+    };
+    let status = ishape_outline_f64_contour_to_flat_with_style(
+        coords.as_ptr(),
+        coords.len(),
+        style,
+        output.ptr(),
+    );
+    match status {
+        IShapeStatus::Ok => Ok(abs_shapes_area(&read_f64_shapes(output.ptr()))),
+        status => Err(status),
+    }
+}
+
+#[test]
+fn outline_contour_applies_policy() {
+    let clean = square(0.0, 0.0, 10.0);
+    let expected = outline_contour(&clean, 1.0, IShapeNonFinitePolicy::Reject).unwrap();
+    let coords = with_bad_point(&clean, f64::NAN);
+
+    assert_eq!(
+        outline_contour(&coords, 1.0, IShapeNonFinitePolicy::Reject),
+        Err(IShapeStatus::NonFiniteValue)
+    );
+    assert_close(
+        outline_contour(&coords, 1.0, IShapeNonFinitePolicy::DropPoint).unwrap(),
+        expected,
+        1e-6,
+    );
+    assert_eq!(
+        outline_contour(&coords, 1.0, IShapeNonFinitePolicy::DropContour),
+        Ok(0.0)
+    );
+}
+
+#[test]
+fn outline_rejects_non_finite_offsets_under_every_policy() {
+    let coords = square(0.0, 0.0, 10.0);
+    for policy in POLICIES {
+        for offset in [f64::NAN, f64::INFINITY] {
+            assert_eq!(
+                outline_contour(&coords, offset, policy),
+                Err(IShapeStatus::NonFiniteValue)
+            );
+        }
+    }
+
+    let output = f64_shapes_buffer();
+    let status =
+        ishape_outline_f64_contour_to_flat(coords.as_ptr(), coords.len(), f64::NAN, output.ptr());
+    assert_eq!(status, IShapeStatus::NonFiniteValue);
+}
+
+#[test]
+fn styled_outline_rejects_non_finite_points() {
+    let coords = with_bad_point(&square(0.0, 0.0, 10.0), f64::NAN);
+    let output = f64_shapes_buffer();
+    let status = ishape_outline_f64_contour_to_flat_styled(
+        coords.as_ptr(),
+        coords.len(),
+        1.0,
+        1.0,
+        BEVEL,
+        0.0,
+        output.ptr(),
+    );
+    assert_eq!(status, IShapeStatus::NonFiniteValue);
+
+    let input = f64_shapes_buffer();
+    let status = ishape_flat_f64_shapes_push_contour(input.ptr(), coords.as_ptr(), coords.len());
+    assert_eq!(status, IShapeStatus::Ok);
+    let status =
+        ishape_outline_f64_shapes_to_flat_styled(input.ptr(), 1.0, 1.0, BEVEL, 0.0, output.ptr());
+    assert_eq!(status, IShapeStatus::NonFiniteValue);
+}
+
+#[test]
+fn outline_shapes_drop_contour_removes_hole_or_whole_shape() {
+    let outline = |outer: &[f64], hole: &[f64], policy| {
+        let input = f64_shapes_buffer();
+        for contour in [outer, hole] {
+            let status =
+                ishape_flat_f64_shapes_push_contour(input.ptr(), contour.as_ptr(), contour.len());
+            assert_eq!(status, IShapeStatus::Ok);
+        }
+
+        let output = f64_shapes_buffer();
+        let style = FfiOutlineStyle {
+            outer_offset: -1.0,
+            inner_offset: -1.0,
+            non_finite: policy,
+            ..Default::default()
+        };
+        let status = ishape_outline_f64_shapes_to_flat_with_style(input.ptr(), style, output.ptr());
+        match status {
+            IShapeStatus::Ok => Ok(abs_shapes_area(&read_f64_shapes(output.ptr()))),
+            status => Err(status),
+        }
+    };
+
+    let outer = square(0.0, 0.0, 10.0);
+    let hole = square_cw(3.0, 3.0, 4.0);
+    let bad_outer = with_bad_point(&outer, f64::NAN);
+    let bad_hole = with_bad_point(&hole, f64::INFINITY);
+
+    assert_eq!(
+        outline(&outer, &bad_hole, IShapeNonFinitePolicy::Reject),
+        Err(IShapeStatus::NonFiniteValue)
+    );
+    assert!(last_error().unwrap().contains("contour 1 point 2"));
+
+    // Dropping the hole leaves the plain inflated square: 12 × 12 minus four bevelled corners.
+    assert_close(
+        outline(&outer, &bad_hole, IShapeNonFinitePolicy::DropContour).unwrap(),
+        142.0,
+        1e-6,
+    );
+    assert_eq!(
+        outline(&bad_outer, &hole, IShapeNonFinitePolicy::DropContour),
+        Ok(0.0)
+    );
+}
+
+#[test]
+fn stroke_paths_use_style_policy() {
+    let points = [0.0, 0.0, 10.0, 0.0, 0.0, 20.0, f64::NAN, 20.0, 10.0, 20.0];
+    let ranges = [
+        RangeFFI { start: 0, end: 4 },
+        RangeFFI { start: 4, end: 10 },
+    ];
+    let closed = [false, false];
+    let stroke = |policy| {
+        let style = FfiStrokeStyle {
+            width: 2.0,
+            non_finite: policy,
+            ..Default::default()
+        };
+        let output = f64_shapes_buffer();
+        let status = ishape_stroke_f64_paths_to_flat(
+            points.as_ptr(),
+            points.len(),
+            ranges.as_ptr(),
+            closed.as_ptr(),
+            ranges.len(),
+            style,
+            output.ptr(),
+        );
+        match status {
+            IShapeStatus::Ok => Ok(abs_shapes_area(&read_f64_shapes(output.ptr()))),
+            status => Err(status),
+        }
+    };
+
+    assert_eq!(
+        stroke(IShapeNonFinitePolicy::Reject),
+        Err(IShapeStatus::NonFiniteValue)
+    );
+    assert!(last_error().unwrap().contains("path 1 point 1"));
+    assert_close(
+        stroke(IShapeNonFinitePolicy::DropPoint).unwrap(),
+        40.0,
+        1e-6,
+    );
+    assert_close(
+        stroke(IShapeNonFinitePolicy::DropContour).unwrap(),
+        20.0,
+        1e-6,
+    );
+}
+
+#[test]
+fn stroke_contour_uses_style_policy() {
+    let coords = [0.0, 0.0, 5.0, f64::NEG_INFINITY, 10.0, 0.0];
+    for policy in POLICIES {
+        let style = FfiStrokeStyle {
+            width: 2.0,
+            non_finite: policy,
+            ..Default::default()
+        };
+        let output = f64_shapes_buffer();
+        let status = ishape_stroke_f64_contour_to_flat(
+            coords.as_ptr(),
+            coords.len(),
+            false,
+            style,
+            output.ptr(),
+        );
+        let area = abs_shapes_area(&read_f64_shapes(output.ptr()));
+        match policy {
+            IShapeNonFinitePolicy::Reject => assert_eq!(status, IShapeStatus::NonFiniteValue),
+            IShapeNonFinitePolicy::DropPoint => {
+                assert_eq!(status, IShapeStatus::Ok);
+                assert_close(area, 20.0, 1e-6);
+            }
+            IShapeNonFinitePolicy::DropContour => {
+                assert_eq!(status, IShapeStatus::Ok);
+                assert_eq!(area, 0.0);
+            }
+        }
+    }
+
+    let output = f64_shapes_buffer();
+    let status = ishape_stroke_f64_contour_to_flat_styled(
+        coords.as_ptr(),
+        coords.len(),
+        2.0,
+        false,
+        BEVEL,
+        0.0,
+        0,
+        0.0,
+        0,
+        0.0,
+        output.ptr(),
+    );
+    assert_eq!(status, IShapeStatus::NonFiniteValue);
+}
+
+#[test]
+fn triangulation_applies_options_policy() {
+    let input = f64_shapes_buffer();
+    for contour in [
+        square(0.0, 0.0, 10.0),
+        with_bad_point(&square_cw(2.0, 2.0, 6.0), f64::NAN),
+    ] {
+        let status =
+            ishape_flat_f64_shapes_push_contour(input.ptr(), contour.as_ptr(), contour.len());
+        assert_eq!(status, IShapeStatus::Ok);
+    }
+
+    for policy in POLICIES {
+        let options = FloatTriangulatorOptions {
+            non_finite: policy,
+            ..Default::default()
+        };
+        let triangulator = Handle::new(
+            ishape_triangle_f64_triangulator_create_with_options(0, options),
+            ishape_triangle_f64_triangulator_free,
+        );
+        let output = Handle::new(
+            ishape_triangle_flat_f64_triangulation_create(),
+            ishape_triangle_flat_f64_triangulation_free,
+        );
+        let status = ishape_triangle_f64_triangulator_triangulate_flat(
+            triangulator.ptr(),
+            input.ptr(),
+            output.ptr(),
+        );
+        if policy == IShapeNonFinitePolicy::Reject {
+            assert_eq!(status, IShapeStatus::NonFiniteValue);
+            continue;
+        }
+
+        assert_eq!(status, IShapeStatus::Ok);
+        let view = ishape_triangle_flat_f64_triangulation_view(output.ptr());
+        let points = raw_slice(view.points, view.points_len);
+        let indices = raw_slice(view.indices, view.indices_len);
+        let expected = match policy {
+            IShapeNonFinitePolicy::DropPoint => 64.0,
+            _ => 100.0,
+        };
+        check_triangulation(points, indices, expected, 1e-6);
+    }
+}
+
+#[test]
+fn f32_triangulator_rejects_non_finite_points_unless_configured() {
+    let contour: Vec<f32> = with_bad_point(&square(0.0, 0.0, 10.0), f64::INFINITY)
+        .into_iter()
+        .map(|value| value as f32)
+        .collect();
+    let input = f32_shapes_buffer();
+    let status = ishape_flat_f32_shapes_push_contour(input.ptr(), contour.as_ptr(), contour.len());
+    assert_eq!(status, IShapeStatus::Ok);
+
+    let triangulate = |triangulator: *mut Float32Triangulator| {
+        let triangulator = Handle::new(triangulator, ishape_triangle_f32_triangulator_free);
+        let output = Handle::new(
+            ishape_triangle_flat_f32_triangulation_create(),
+            ishape_triangle_flat_f32_triangulation_free,
+        );
+        let status = ishape_triangle_f32_triangulator_triangulate_flat(
+            triangulator.ptr(),
+            input.ptr(),
+            output.ptr(),
+        );
+        let view = ishape_triangle_flat_f32_triangulation_view(output.ptr());
+        (status, view.indices_len)
+    };
+
+    let validation = IntTriangulatorValidation::default();
+    assert_eq!(
+        triangulate(ishape_triangle_f32_triangulator_create(0, validation)),
+        (IShapeStatus::NonFiniteValue, 0)
+    );
+
+    let options = FloatTriangulatorOptions {
+        validation,
+        non_finite: IShapeNonFinitePolicy::DropPoint,
+    };
+    let (status, indices_len) = triangulate(ishape_triangle_f32_triangulator_create_with_options(
+        0, options,
+    ));
+    assert_eq!(status, IShapeStatus::Ok);
+    assert_eq!(indices_len, 6);
+}
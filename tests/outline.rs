@@ -0,0 +1,504 @@
+mod common;
+
+use std::ptr;
+
+use common::{Handle, assert_close, shapes_area, square, square_cw};
+use i_shape_ffi::*;
+
+const BEVEL: u32 = 0;
+const MITER: u32 = 1;
+const ROUND: u32 = 2;
+
+/// A 10 × 10 square offset by 1 grows to `100 + 40` plus its corners: four triangles of area
+/// `1/2` for a bevel join, four unit squares for a miter join and a unit circle for a round one.
+const INFLATED_BEVEL: f64 = 142.0;
+const INFLATED_MITER: f64 = 144.0;
+const INFLATED_ROUND: f64 = 140.0 + std::f64::consts::PI;
+/// The same square offset by 1 towards its inside.
+const DEFLATED: f64 = 64.0;
+
+macro_rules! outline_tests {
+    (
+        $module:ident,
+        coord: $coord:ty,
+        buffer: $buffer:ty,
+        slices: $slices:ident,
+        tolerance: $tolerance:expr,
+        contour_to_flat: $contour_to_flat:ident,
+        contour_to_flat_styled: $contour_to_flat_styled:ident,
+        shapes_to_flat: $shapes_to_flat:ident,
+        shapes_to_flat_styled: $shapes_to_flat_styled:ident,
+        contour_to_flat_with_style: $contour_to_flat_with_style:ident,
+        shapes_to_flat_with_style: $shapes_to_flat_with_style:ident,
+        contour_to_slices: $contour_to_slices:ident,
+        shapes_to_slices: $shapes_to_slices:ident,
+        new_buffer: $new_buffer:path,
+        read: $read:path,
+        push_contour: $push_contour:ident,
+        set: $set:ident $(,)?
+    ) => {
+        mod $module {
+            use super::*;
+
+            fn coords(contour: &[f64]) -> Vec<$coord> {
+                contour.iter().map(|&v| v as $coord).collect()
+            }
+
+            fn contour_outline(contour: &[f64], offset: f64) -> common::Shapes {
+                let coords = coords(contour);
+                let output = $new_buffer();
+                let status = $contour_to_flat(
+                    coords.as_ptr(),
+                    coords.len(),
+                    offset as $coord,
+                    output.ptr(),
+                );
+                assert_eq!(status, IShapeStatus::Ok);
+                $read(output.ptr())
+            }
+
+            fn contour_outline_styled(contour: &[f64], offset: f64, join: u32) -> common::Shapes {
+                let coords = coords(contour);
+                let output = $new_buffer();
+                let status = $contour_to_flat_styled(
+                    coords.as_ptr(),
+                    coords.len(),
+                    offset as $coord,
+                    offset as $coord,
+                    join,
+                    0.1,
+                    output.ptr(),
+                );
+                assert_eq!(status, IShapeStatus::Ok);
+                $read(output.ptr())
+            }
+
+            /// A 10 × 10 square with a 6 × 6 hole, outer counterclockwise and hole clockwise.
+            fn square_with_hole() -> Handle<$buffer> {
+                let buffer = $new_buffer();
+                for contour in [square(0.0, 0.0, 10.0), square_cw(2.0, 2.0, 6.0)] {
+                    let coords = coords(&contour);
+                    let status = $push_contour(buffer.ptr(), coords.as_ptr(), coords.len());
+                    assert_eq!(status, IShapeStatus::Ok);
+                }
+                buffer
+            }
+
+            fn empty_slices() -> $slices {
+                $slices {
+                    points: ptr::null_mut(),
+                    points_capacity: 0,
+                    points_len: 0,
+                    contours: ptr::null_mut(),
+                    contours_capacity: 0,
+                    contours_len: 0,
+                    shapes: ptr::null_mut(),
+                    shapes_capacity: 0,
+                    shapes_len: 0,
+                }
+            }
+
+            /// Runs `write` once to query the required lengths and once more into arrays of
+            /// exactly that size.
+            fn write_through_slices(
+                mut write: impl FnMut(&mut $slices) -> IShapeStatus,
+            ) -> common::Shapes {
+                let mut slices = empty_slices();
+                assert_eq!(write(&mut slices), IShapeStatus::InsufficientCapacity);
+
+                let mut points = vec![0 as $coord; slices.points_len];
+                let mut contours = vec![RangeFFI { start: 0, end: 0 }; slices.contours_len];
+                let mut shapes = vec![RangeFFI { start: 0, end: 0 }; slices.shapes_len];
+                slices.points = points.as_mut_ptr();
+                slices.points_capacity = points.len();
+                slices.contours = contours.as_mut_ptr();
+                slices.contours_capacity = contours.len();
+                slices.shapes = shapes.as_mut_ptr();
+                slices.shapes_capacity = shapes.len();
+                assert_eq!(write(&mut slices), IShapeStatus::Ok);
+
+                common::read_shapes(
+                    slices.points,
+                    slices.points_len,
+                    slices.contours,
+                    slices.contours_len,
+                    slices.shapes,
+                    slices.shapes_len,
+                )
+            }
+
+            #[test]
+            fn contour_outline_inflates_and_deflates() {
+                let contour = square(0.0, 0.0, 10.0);
+                let inflated = shapes_area(&contour_outline(&contour, -1.0));
+                let deflated = shapes_area(&contour_outline(&contour, 1.0));
+                assert_close(inflated, INFLATED_BEVEL, $tolerance);
+                assert_close(deflated, DEFLATED, $tolerance);
+            }
+
+            #[test]
+            fn contour_outline_retries_reversed_winding() {
+                for offset in [1.0, -1.0] {
+                    let ccw = contour_outline(&square(0.0, 0.0, 10.0), offset);
+                    let cw = contour_outline(&square_cw(0.0, 0.0, 10.0), offset);
+                    assert!(
+                        !cw.is_empty(),
+                        "clockwise input produced nothing for {offset}"
+                    );
+                    assert_close(shapes_area(&cw), shapes_area(&ccw), $tolerance);
+                }
+            }
+
+            #[test]
+            fn contour_outline_styled_applies_every_join() {
+                let contour = square(0.0, 0.0, 10.0);
+                let bevel = shapes_area(&contour_outline_styled(&contour, -1.0, BEVEL));
+                let miter = shapes_area(&contour_outline_styled(&contour, -1.0, MITER));
+                let round = shapes_area(&contour_outline_styled(&contour, -1.0, ROUND));
+
+                assert_close(bevel, INFLATED_BEVEL, $tolerance);
+                assert_close(miter, INFLATED_MITER, $tolerance);
+                assert!(
+                    bevel < round && round <= INFLATED_ROUND + $tolerance,
+                    "{round}"
+                );
+
+                let reversed = contour_outline_styled(&square_cw(0.0, 0.0, 10.0), -1.0, MITER);
+                assert_close(shapes_area(&reversed), INFLATED_MITER, $tolerance);
+
+                let deflated = contour_outline_styled(&contour, 1.0, MITER);
+                assert_close(shapes_area(&deflated), DEFLATED, $tolerance);
+            }
+
+            #[test]
+            fn shapes_outline_follows_offset_sign() {
+                let shapes = square_with_hole();
+                let output = $new_buffer();
+
+                // A negative offset shrinks the hole from 6 × 6 to 4 × 4 while the outer square
+                // grows.
+                let status = $shapes_to_flat(shapes.ptr(), -1.0, output.ptr());
+                assert_eq!(status, IShapeStatus::Ok);
+                let result = $read(output.ptr());
+                assert_eq!(result.len(), 1);
+                assert_eq!(result[0].len(), 2);
+                assert_close(shapes_area(&result), INFLATED_BEVEL - 16.0, $tolerance);
+
+                // A positive one shrinks the outer square to 8 × 8 and grows the hole like an
+                // inflated 6 × 6 square with bevel corners, to `36 + 24 + 2`.
+                let status = $shapes_to_flat(shapes.ptr(), 1.0, output.ptr());
+                assert_eq!(status, IShapeStatus::Ok);
+                assert_close(shapes_area(&$read(output.ptr())), 64.0 - 62.0, $tolerance);
+            }
+
+            #[test]
+            fn shapes_outline_styled_moves_holes_by_inner_offset() {
+                let shapes = square_with_hole();
+                let output = $new_buffer();
+
+                let status =
+                    $shapes_to_flat_styled(shapes.ptr(), -1.0, 0.0, MITER, 0.0, output.ptr());
+                assert_eq!(status, IShapeStatus::Ok);
+                assert_close(
+                    shapes_area(&$read(output.ptr())),
+                    INFLATED_MITER - 36.0,
+                    $tolerance,
+                );
+
+                let status =
+                    $shapes_to_flat_styled(shapes.ptr(), -1.0, -1.0, MITER, 0.0, output.ptr());
+                assert_eq!(status, IShapeStatus::Ok);
+                assert_close(
+                    shapes_area(&$read(output.ptr())),
+                    INFLATED_MITER - 16.0,
+                    $tolerance,
+                );
+            }
+
+            #[test]
+            fn with_style_matches_styled_output() {
+                let style = FfiOutlineStyle {
+                    outer_offset: -1.0,
+                    inner_offset: -1.0,
+                    join: FfiLineJoin {
+                        kind: FfiLineJoinKind::Miter,
+                        value: 0.1,
+                    },
+                    ..Default::default()
+                };
+                let contour = coords(&square(0.0, 0.0, 10.0));
+                let output = $new_buffer();
+                let status = $contour_to_flat_with_style(
+                    contour.as_ptr(),
+                    contour.len(),
+                    style,
+                    output.ptr(),
+                );
+                assert_eq!(status, IShapeStatus::Ok);
+                assert_eq!(
+                    $read(output.ptr()),
+                    contour_outline_styled(&square(0.0, 0.0, 10.0), -1.0, MITER)
+                );
+
+                let shapes = square_with_hole();
+                let style = FfiOutlineStyle {
+                    inner_offset: 0.0,
+                    ..style
+                };
+                let status =
+                    $shapes_to_flat_styled(shapes.ptr(), -1.0, 0.0, MITER, 0.1, output.ptr());
+                assert_eq!(status, IShapeStatus::Ok);
+                let expected = $read(output.ptr());
+                let status = $shapes_to_flat_with_style(shapes.ptr(), style, output.ptr());
+                assert_eq!(status, IShapeStatus::Ok);
+                assert_eq!($read(output.ptr()), expected);
+            }
+
+            #[test]
+            fn with_style_rejects_bad_style() {
+                let contour = coords(&square(0.0, 0.0, 10.0));
+                let shapes = square_with_hole();
+                let output = $new_buffer();
+                let outline = |style| {
+                    let contour_status = $contour_to_flat_with_style(
+                        contour.as_ptr(),
+                        contour.len(),
+                        style,
+                        output.ptr(),
+                    );
+                    let shapes_status =
+                        $shapes_to_flat_with_style(shapes.ptr(), style, output.ptr());
+                    assert_eq!(contour_status, shapes_status);
+                    contour_status
+                };
+
+                let bad_join = FfiOutlineStyle {
+                    join: FfiLineJoin {
+                        kind: FfiLineJoinKind::Round,
+                        value: f64::NAN,
+                    },
+                    ..Default::default()
+                };
+                assert_eq!(outline(bad_join), IShapeStatus::InvalidLineJoin);
+
+                // Twice the largest coordinate overflows to infinity in either precision.
+                let huge = f64::from(<$coord>::MAX) * 2.0;
+                for style in [
+                    FfiOutlineStyle {
+                        outer_offset: huge,
+                        ..Default::default()
+                    },
+                    FfiOutlineStyle {
+                        inner_offset: f64::NAN,
+                        ..Default::default()
+                    },
+                ] {
+                    assert_eq!(outline(style), IShapeStatus::NonFiniteValue);
+                }
+            }
+
+            #[test]
+            fn slices_match_flat_output() {
+                let contour = coords(&square(0.0, 0.0, 10.0));
+                let expected = contour_outline(&square(0.0, 0.0, 10.0), 1.0);
+                let actual = write_through_slices(|slices| {
+                    $contour_to_slices(contour.as_ptr(), contour.len(), 1.0, slices)
+                });
+                assert_eq!(actual, expected);
+
+                let shapes = square_with_hole();
+                let output = $new_buffer();
+                assert_eq!(
+                    $shapes_to_flat(shapes.ptr(), 1.0, output.ptr()),
+                    IShapeStatus::Ok
+                );
+                let actual =
+                    write_through_slices(|slices| $shapes_to_slices(shapes.ptr(), 1.0, slices));
+                assert_eq!(actual, $read(output.ptr()));
+            }
+
+            #[test]
+            fn shapes_outline_skips_degenerate_contours() {
+                let shapes = square_with_hole();
+                let output = $new_buffer();
+                assert_eq!(
+                    $shapes_to_flat(shapes.ptr(), 1.0, output.ptr()),
+                    IShapeStatus::Ok
+                );
+                let expected = $read(output.ptr());
+
+                // The same shape followed by an empty hole and a two-point hole, plus a shape
+                // whose outer contour is empty.
+                let mut points = coords(&square(0.0, 0.0, 10.0));
+                points.extend(coords(&square_cw(2.0, 2.0, 6.0)));
+                points.extend(coords(&[4.0, 4.0, 5.0, 5.0]));
+                let contours = [(0, 8), (8, 16), (16, 16), (16, 20), (20, 20), (20, 20)]
+                    .map(|(start, end)| RangeFFI { start, end });
+                let shape_ranges = [RangeFFI { start: 0, end: 4 }, RangeFFI { start: 4, end: 6 }];
+                let status = $set(
+                    shapes.ptr(),
+                    points.as_ptr(),
+                    points.len(),
+                    contours.as_ptr(),
+                    contours.len(),
+                    shape_ranges.as_ptr(),
+                    shape_ranges.len(),
+                );
+                assert_eq!(status, IShapeStatus::Ok);
+
+                assert_eq!(
+                    $shapes_to_flat(shapes.ptr(), 1.0, output.ptr()),
+                    IShapeStatus::Ok
+                );
+                assert_eq!($read(output.ptr()), expected);
+            }
+
+            #[test]
+            fn shapes_outline_rejects_malformed_layout() {
+                let shapes = square_with_hole();
+                let output = $new_buffer();
+                let buffer = unsafe { &mut *shapes.ptr() };
+                buffer.shape_ranges[0].end += 1;
+
+                assert_eq!(
+                    $shapes_to_flat(shapes.ptr(), 1.0, output.ptr()),
+                    IShapeStatus::InvalidRange
+                );
+                assert_eq!(
+                    $shapes_to_slices(shapes.ptr(), 1.0, &mut empty_slices()),
+                    IShapeStatus::InvalidRange
+                );
+            }
+
+            #[test]
+            fn empty_shapes_clear_output() {
+                let shapes = $new_buffer();
+                let output = $new_buffer();
+                let contour = coords(&square(0.0, 0.0, 10.0));
+                let status = $contour_to_flat(contour.as_ptr(), contour.len(), 1.0, output.ptr());
+                assert_eq!(status, IShapeStatus::Ok);
+
+                assert_eq!(
+                    $shapes_to_flat(shapes.ptr(), 1.0, output.ptr()),
+                    IShapeStatus::Ok
+                );
+                assert!($read(output.ptr()).is_empty());
+
+                let mut slices = empty_slices();
+                assert_eq!(
+                    $shapes_to_slices(shapes.ptr(), 1.0, &mut slices),
+                    IShapeStatus::Ok
+                );
+                assert_eq!(
+                    slices.points_len + slices.contours_len + slices.shapes_len,
+                    0
+                );
+            }
+
+            #[test]
+            fn contour_outline_rejects_bad_input() {
+                let output = $new_buffer();
+                let contour = coords(&square(0.0, 0.0, 10.0));
+                let outline = |points: *const $coord, count| {
+                    $contour_to_flat(points, count, 1.0, output.ptr())
+                };
+
+                assert_eq!(
+                    outline(contour.as_ptr(), 7),
+                    IShapeStatus::OddCoordinateCount
+                );
+                assert_eq!(outline(contour.as_ptr(), 4), IShapeStatus::TooFewPoints);
+                assert_eq!(outline(ptr::null(), 0), IShapeStatus::TooFewPoints);
+                assert_eq!(outline(ptr::null(), 8), IShapeStatus::NullPointer);
+                assert_eq!(
+                    $contour_to_flat(contour.as_ptr(), contour.len(), 1.0, ptr::null_mut()),
+                    IShapeStatus::NullPointer
+                );
+                assert_eq!(
+                    $contour_to_slices(contour.as_ptr(), contour.len(), 1.0, ptr::null_mut()),
+                    IShapeStatus::NullPointer
+                );
+
+                for (join, value) in [
+                    (3, 0.0),
+                    (MITER, <$coord>::NAN),
+                    (ROUND, <$coord>::INFINITY),
+                ] {
+                    let status = $contour_to_flat_styled(
+                        contour.as_ptr(),
+                        contour.len(),
+                        1.0,
+                        1.0,
+                        join,
+                        value,
+                        output.ptr(),
+                    );
+                    assert_eq!(status, IShapeStatus::InvalidLineJoin, "join {join}");
+                }
+            }
+
+            #[test]
+            fn shapes_outline_rejects_bad_input() {
+                let shapes = square_with_hole();
+                let output = $new_buffer();
+
+                assert_eq!(
+                    $shapes_to_flat(ptr::null(), 1.0, output.ptr()),
+                    IShapeStatus::NullPointer
+                );
+                assert_eq!(
+                    $shapes_to_flat(shapes.ptr(), 1.0, ptr::null_mut()),
+                    IShapeStatus::NullPointer
+                );
+                assert_eq!(
+                    $shapes_to_slices(ptr::null(), 1.0, &mut empty_slices()),
+                    IShapeStatus::NullPointer
+                );
+                assert_eq!(
+                    $shapes_to_flat_styled(shapes.ptr(), 1.0, 1.0, 7, 0.0, output.ptr()),
+                    IShapeStatus::InvalidLineJoin
+                );
+            }
+        }
+    };
+}
+
+outline_tests!(
+    f64,
+    coord: f64,
+    buffer: FlatF64ShapesBuffer,
+    slices: FlatF64ShapesSlices,
+    tolerance: 1e-6,
+    contour_to_flat: ishape_outline_f64_contour_to_flat,
+    contour_to_flat_styled: ishape_outline_f64_contour_to_flat_styled,
+    shapes_to_flat: ishape_outline_f64_shapes_to_flat,
+    shapes_to_flat_styled: ishape_outline_f64_shapes_to_flat_styled,
+    contour_to_flat_with_style: ishape_outline_f64_contour_to_flat_with_style,
+    shapes_to_flat_with_style: ishape_outline_f64_shapes_to_flat_with_style,
+    contour_to_slices: ishape_outline_f64_contour_to_slices,
+    shapes_to_slices: ishape_outline_f64_shapes_to_slices,
+    new_buffer: common::f64_shapes_buffer,
+    read: common::read_f64_shapes,
+    push_contour: ishape_flat_f64_shapes_push_contour,
+    set: ishape_flat_f64_shapes_set,
+);
+
+outline_tests!(
+    f32,
+    coord: f32,
+    buffer: FlatF32ShapesBuffer,
+    slices: FlatF32ShapesSlices,
+    tolerance: 1e-3,
+    contour_to_flat: ishape_outline_f32_contour_to_flat,
+    contour_to_flat_styled: ishape_outline_f32_contour_to_flat_styled,
+    shapes_to_flat: ishape_outline_f32_shapes_to_flat,
+    shapes_to_flat_styled: ishape_outline_f32_shapes_to_flat_styled,
+    contour_to_flat_with_style: ishape_outline_f32_contour_to_flat_with_style,
+    shapes_to_flat_with_style: ishape_outline_f32_shapes_to_flat_with_style,
+    contour_to_slices: ishape_outline_f32_contour_to_slices,
+    shapes_to_slices: ishape_outline_f32_shapes_to_slices,
+    new_buffer: common::f32_shapes_buffer,
+    read: common::read_f32_shapes,
+    push_contour: ishape_flat_f32_shapes_push_contour,
+    set: ishape_flat_f32_shapes_set,
+);
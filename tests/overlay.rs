@@ -0,0 +1,458 @@
+mod common;
+
+use std::ptr;
+
+use common::{Handle, assert_close, shapes_area, square};
+use i_shape_ffi::*;
+
+const OVERLAY_RULES: [IntOverlayRule; 7] = [
+    IntOverlayRule::Subject,
+    IntOverlayRule::Clip,
+    IntOverlayRule::Intersect,
+    IntOverlayRule::Union,
+    IntOverlayRule::Difference,
+    IntOverlayRule::InverseDifference,
+    IntOverlayRule::Xor,
+];
+
+const FILL_RULES: [IntFillRule; 4] = [
+    IntFillRule::EvenOdd,
+    IntFillRule::NonZero,
+    IntFillRule::Positive,
+    IntFillRule::Negative,
+];
+
+/// Subject: squares `A = [0, 10]²` and `B = [5, 15]²` overlapping by 25.
+/// Clip: square `D = [8, 18] × [0, 10]`.
+fn sample(counterclockwise: bool) -> (Vec<Vec<f64>>, Vec<Vec<f64>>) {
+    let contour = |x, y| {
+        let mut contour = square(x, y, 10.0);
+        if !counterclockwise {
+            let points: Vec<_> = contour.chunks_exact(2).rev().flatten().copied().collect();
+            contour = points;
+        }
+        contour
+    };
+
+    (
+        vec![contour(0.0, 0.0), contour(5.0, 5.0)],
+        vec![contour(8.0, 0.0)],
+    )
+}
+
+/// Expected result area of the sample, worked out by hand from the pairwise overlaps
+/// `A ∩ D = 20`, `B ∩ D = 35` and `A ∩ B ∩ D = 10`.
+fn expected_area(rule: IntOverlayRule, fill_rule: IntFillRule, counterclockwise: bool) -> f64 {
+    // Counterclockwise contours wind positively.
+    let filled = match fill_rule {
+        IntFillRule::EvenOdd | IntFillRule::NonZero => true,
+        IntFillRule::Positive => counterclockwise,
+        IntFillRule::Negative => !counterclockwise,
+    };
+    if !filled {
+        return 0.0;
+    }
+
+    // With even-odd filling the overlap of A and B is a hole in the subject.
+    let (subject, intersect) = match fill_rule {
+        IntFillRule::EvenOdd => (150.0, 35.0),
+        _ => (175.0, 45.0),
+    };
+    let clip = 100.0;
+
+    match rule {
+        IntOverlayRule::Subject => subject,
+        IntOverlayRule::Clip => clip,
+        IntOverlayRule::Intersect => intersect,
+        IntOverlayRule::Union => subject + clip - intersect,
+        IntOverlayRule::Difference => subject - intersect,
+        IntOverlayRule::InverseDifference => clip - intersect,
+        IntOverlayRule::Xor => subject + clip - 2.0 * intersect,
+    }
+}
+
+macro_rules! overlay_tests {
+    (
+        $module:ident,
+        coord: $coord:ty,
+        handle: $handle:ty,
+        options: $options:ty,
+        slices: $slices:ident,
+        tolerance: $tolerance:expr,
+        create: $create:ident,
+        free: $free:ident,
+        clear: $clear:ident,
+        add_contour: $add_contour:ident,
+        into_flat: $into_flat:ident,
+        into_slices: $into_slices:ident,
+        new_buffer: $new_buffer:path,
+        read: $read:path $(,)?
+    ) => {
+        mod $module {
+            use super::*;
+
+            fn coords(contour: &[f64]) -> Vec<$coord> {
+                contour.iter().map(|&v| v as $coord).collect()
+            }
+
+            fn overlay_with(subject: &[Vec<f64>], clip: &[Vec<f64>]) -> Handle<$handle> {
+                let handle = Handle::new($create(0, <$options>::default()), $free);
+                for (contours, shape_type) in
+                    [(subject, IntShapeType::Subject), (clip, IntShapeType::Clip)]
+                {
+                    for contour in contours {
+                        let coords = coords(contour);
+                        let status =
+                            $add_contour(handle.ptr(), coords.as_ptr(), coords.len(), shape_type);
+                        assert_eq!(status, IShapeStatus::Ok);
+                    }
+                }
+                handle
+            }
+
+            fn overlay_into_flat(
+                subject: &[Vec<f64>],
+                clip: &[Vec<f64>],
+                rule: IntOverlayRule,
+                fill_rule: IntFillRule,
+            ) -> common::Shapes {
+                let handle = overlay_with(subject, clip);
+                let output = $new_buffer();
+                let status = $into_flat(handle.ptr(), rule, fill_rule, output.ptr());
+                assert_eq!(status, IShapeStatus::Ok);
+                $read(output.ptr())
+            }
+
+            fn empty_slices() -> $slices {
+                $slices {
+                    points: ptr::null_mut(),
+                    points_capacity: 0,
+                    points_len: 0,
+                    contours: ptr::null_mut(),
+                    contours_capacity: 0,
+                    contours_len: 0,
+                    shapes: ptr::null_mut(),
+                    shapes_capacity: 0,
+                    shapes_len: 0,
+                }
+            }
+
+            #[test]
+            fn every_rule_and_fill_rule_matches_expected_area() {
+                for counterclockwise in [true, false] {
+                    let (subject, clip) = sample(counterclockwise);
+                    for rule in OVERLAY_RULES {
+                        for fill_rule in FILL_RULES {
+                            let shapes = overlay_into_flat(&subject, &clip, rule, fill_rule);
+                            let expected = expected_area(rule, fill_rule, counterclockwise);
+                            let area = shapes_area(&shapes);
+                            assert!(
+                                (area - expected).abs() <= $tolerance,
+                                "{rule:?} {fill_rule:?} ccw={counterclockwise}: \
+                                 expected {expected}, got {area}"
+                            );
+                            assert_eq!(shapes.is_empty(), expected == 0.0);
+                        }
+                    }
+                }
+            }
+
+            #[test]
+            fn even_odd_subject_keeps_overlap_as_hole() {
+                let (subject, clip) = sample(true);
+                let shapes = overlay_into_flat(
+                    &subject,
+                    &clip,
+                    IntOverlayRule::Subject,
+                    IntFillRule::EvenOdd,
+                );
+                let contours: usize = shapes.iter().map(Vec::len).sum();
+                assert_eq!(contours, 2, "two L-shaped pieces without holes");
+                assert_close(shapes_area(&shapes), 150.0, $tolerance);
+            }
+
+            #[test]
+            fn slices_match_flat_output_after_capacity_query() {
+                let (subject, clip) = sample(true);
+                for rule in OVERLAY_RULES {
+                    let expected = overlay_into_flat(&subject, &clip, rule, IntFillRule::NonZero);
+
+                    let handle = overlay_with(&subject, &clip);
+                    let mut slices = empty_slices();
+                    let status =
+                        $into_slices(handle.ptr(), rule, IntFillRule::NonZero, &mut slices);
+                    assert_eq!(status, IShapeStatus::InsufficientCapacity);
+                    assert!(
+                        slices.points_len > 0 && slices.contours_len > 0 && slices.shapes_len > 0
+                    );
+
+                    let mut points = vec![0 as $coord; slices.points_len];
+                    let mut contours = vec![RangeFFI { start: 0, end: 0 }; slices.contours_len];
+                    let mut shapes = vec![RangeFFI { start: 0, end: 0 }; slices.shapes_len];
+                    slices.points = points.as_mut_ptr();
+                    slices.points_capacity = points.len();
+                    slices.contours = contours.as_mut_ptr();
+                    slices.contours_capacity = contours.len();
+                    slices.shapes = shapes.as_mut_ptr();
+                    slices.shapes_capacity = shapes.len();
+
+                    let status =
+                        $into_slices(handle.ptr(), rule, IntFillRule::NonZero, &mut slices);
+                    assert_eq!(status, IShapeStatus::Ok);
+
+                    let actual = common::read_shapes(
+                        slices.points,
+                        slices.points_len,
+                        slices.contours,
+                        slices.contours_len,
+                        slices.shapes,
+                        slices.shapes_len,
+                    );
+                    assert_eq!(actual, expected, "{rule:?}");
+                }
+            }
+
+            #[test]
+            fn empty_result_fits_empty_slices() {
+                let (subject, clip) = sample(true);
+                let handle = overlay_with(&subject, &clip);
+                let mut slices = empty_slices();
+                let status = $into_slices(
+                    handle.ptr(),
+                    IntOverlayRule::Union,
+                    IntFillRule::Negative,
+                    &mut slices,
+                );
+                assert_eq!(status, IShapeStatus::Ok);
+                assert_eq!(
+                    slices.points_len + slices.contours_len + slices.shapes_len,
+                    0
+                );
+            }
+
+            #[test]
+            fn empty_overlay_produces_empty_output() {
+                let handle = overlay_with(&[], &[]);
+                let output = $new_buffer();
+                let status = $into_flat(
+                    handle.ptr(),
+                    IntOverlayRule::Union,
+                    IntFillRule::NonZero,
+                    output.ptr(),
+                );
+                assert_eq!(status, IShapeStatus::Ok);
+                assert!($read(output.ptr()).is_empty());
+
+                // Empty contours are accepted and ignored.
+                let status = $add_contour(handle.ptr(), ptr::null(), 0, IntShapeType::Subject);
+                assert_eq!(status, IShapeStatus::Ok);
+            }
+
+            #[test]
+            fn output_is_replaced_not_appended() {
+                let (subject, clip) = sample(true);
+                let handle = overlay_with(&subject, &clip);
+                let output = $new_buffer();
+                for _ in 0..2 {
+                    let status = $into_flat(
+                        handle.ptr(),
+                        IntOverlayRule::Union,
+                        IntFillRule::NonZero,
+                        output.ptr(),
+                    );
+                    assert_eq!(status, IShapeStatus::Ok);
+                    assert_close(shapes_area(&$read(output.ptr())), 230.0, $tolerance);
+                }
+            }
+
+            #[test]
+            fn one_handle_runs_every_rule_in_turn() {
+                let (subject, clip) = sample(true);
+                let handle = overlay_with(&subject, &clip);
+                let output = $new_buffer();
+                for fill_rule in FILL_RULES {
+                    for rule in OVERLAY_RULES {
+                        let status = $into_flat(handle.ptr(), rule, fill_rule, output.ptr());
+                        assert_eq!(status, IShapeStatus::Ok);
+                        let expected = overlay_into_flat(&subject, &clip, rule, fill_rule);
+                        assert_eq!($read(output.ptr()), expected, "{rule:?} {fill_rule:?}");
+                    }
+                }
+            }
+
+            #[test]
+            fn clear_empties_handle_for_refilling() {
+                let (subject, clip) = sample(true);
+                let handle = overlay_with(&subject, &clip);
+                let output = $new_buffer();
+                let (rule, fill_rule) = (IntOverlayRule::Union, IntFillRule::NonZero);
+                assert_eq!(
+                    $into_flat(handle.ptr(), rule, fill_rule, output.ptr()),
+                    IShapeStatus::Ok
+                );
+
+                $clear(handle.ptr());
+                assert_eq!(
+                    $into_flat(handle.ptr(), rule, fill_rule, output.ptr()),
+                    IShapeStatus::Ok
+                );
+                assert!($read(output.ptr()).is_empty());
+
+                // Error messages count contours from the clear.
+                let coords = coords(&square(20.0, 0.0, 4.0));
+                let status = $add_contour(handle.ptr(), coords.as_ptr(), 7, IntShapeType::Clip);
+                assert_eq!(status, IShapeStatus::OddCoordinateCount);
+                assert!(
+                    common::last_error()
+                        .unwrap()
+                        .contains("contour 0 has 7 coordinates")
+                );
+
+                let status = $add_contour(
+                    handle.ptr(),
+                    coords.as_ptr(),
+                    coords.len(),
+                    IntShapeType::Clip,
+                );
+                assert_eq!(status, IShapeStatus::Ok);
+                assert_eq!(
+                    $into_flat(handle.ptr(), rule, fill_rule, output.ptr()),
+                    IShapeStatus::Ok
+                );
+                assert_close(shapes_area(&$read(output.ptr())), 16.0, $tolerance);
+
+                $clear(ptr::null_mut());
+            }
+
+            #[test]
+            fn add_contour_rejects_bad_input() {
+                let handle = overlay_with(&[], &[]);
+                let coords = coords(&square(0.0, 0.0, 10.0));
+
+                let status = $add_contour(handle.ptr(), coords.as_ptr(), 7, IntShapeType::Subject);
+                assert_eq!(status, IShapeStatus::OddCoordinateCount);
+                assert!(
+                    common::last_error()
+                        .unwrap()
+                        .contains("contour 0 has 7 coordinates")
+                );
+
+                let status = $add_contour(handle.ptr(), ptr::null(), 8, IntShapeType::Clip);
+                assert_eq!(status, IShapeStatus::NullPointer);
+
+                let status = $add_contour(
+                    ptr::null_mut(),
+                    coords.as_ptr(),
+                    coords.len(),
+                    IntShapeType::Clip,
+                );
+                assert_eq!(status, IShapeStatus::NullPointer);
+
+                // Rejected contours are not queued.
+                let output = $new_buffer();
+                let status = $into_flat(
+                    handle.ptr(),
+                    IntOverlayRule::Union,
+                    IntFillRule::NonZero,
+                    output.ptr(),
+                );
+                assert_eq!(status, IShapeStatus::Ok);
+                assert!($read(output.ptr()).is_empty());
+            }
+
+            #[test]
+            fn add_contour_errors_count_every_accepted_call() {
+                let handle = overlay_with(&[], &[]);
+                let status = $add_contour(handle.ptr(), ptr::null(), 0, IntShapeType::Subject);
+                assert_eq!(status, IShapeStatus::Ok);
+
+                let coords = coords(&square(0.0, 0.0, 10.0));
+                let status = $add_contour(handle.ptr(), coords.as_ptr(), 7, IntShapeType::Clip);
+                assert_eq!(status, IShapeStatus::OddCoordinateCount);
+                assert!(
+                    common::last_error()
+                        .unwrap()
+                        .contains("contour 1 has 7 coordinates")
+                );
+            }
+
+            #[test]
+            fn overlay_rejects_null_pointers() {
+                let handle = overlay_with(&[], &[]);
+                let output = $new_buffer();
+                let mut slices = empty_slices();
+                let (rule, fill_rule) = (IntOverlayRule::Union, IntFillRule::NonZero);
+
+                assert_eq!(
+                    $into_flat(ptr::null_mut(), rule, fill_rule, output.ptr()),
+                    IShapeStatus::NullPointer
+                );
+                assert_eq!(
+                    $into_flat(handle.ptr(), rule, fill_rule, ptr::null_mut()),
+                    IShapeStatus::NullPointer
+                );
+                assert_eq!(
+                    $into_slices(ptr::null_mut(), rule, fill_rule, &mut slices),
+                    IShapeStatus::NullPointer
+                );
+                assert_eq!(
+                    $into_slices(handle.ptr(), rule, fill_rule, ptr::null_mut()),
+                    IShapeStatus::NullPointer
+                );
+
+                $free(ptr::null_mut());
+            }
+        }
+    };
+}
+
+overlay_tests!(
+    int,
+    coord: i32,
+    handle: IntOverlay,
+    options: IntOverlayOptions,
+    slices: FlatShapesSlices,
+    tolerance: 0.0,
+    create: ishape_overlay_int_create,
+    free: ishape_overlay_int_free,
+    clear: ishape_overlay_int_clear,
+    add_contour: ishape_overlay_int_add_contour,
+    into_flat: ishape_overlay_int_overlay_into_flat,
+    into_slices: ishape_overlay_int_overlay_into_slices,
+    new_buffer: common::int_shapes_buffer,
+    read: common::read_int_shapes,
+);
+
+overlay_tests!(
+    f64,
+    coord: f64,
+    handle: Float64Overlay,
+    options: Float64OverlayOptions,
+    slices: FlatF64ShapesSlices,
+    tolerance: 1e-6,
+    create: ishape_overlay_f64_create,
+    free: ishape_overlay_f64_free,
+    clear: ishape_overlay_f64_clear,
+    add_contour: ishape_overlay_f64_add_contour,
+    into_flat: ishape_overlay_f64_overlay_into_flat,
+    into_slices: ishape_overlay_f64_overlay_into_slices,
+    new_buffer: common::f64_shapes_buffer,
+    read: common::read_f64_shapes,
+);
+
+overlay_tests!(
+    f32,
+    coord: f32,
+    handle: Float32Overlay,
+    options: Float32OverlayOptions,
+    slices: FlatF32ShapesSlices,
+    tolerance: 1e-3,
+    create: ishape_overlay_f32_create,
+    free: ishape_overlay_f32_free,
+    clear: ishape_overlay_f32_clear,
+    add_contour: ishape_overlay_f32_add_contour,
+    into_flat: ishape_overlay_f32_overlay_into_flat,
+    into_slices: ishape_overlay_f32_overlay_into_slices,
+    new_buffer: common::f32_shapes_buffer,
+    read: common::read_f32_shapes,
+);
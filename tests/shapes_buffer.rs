@@ -0,0 +1,531 @@
+mod common;
+
+use std::ptr;
+
+use common::{Handle, raw_slice, read_shapes};
+use i_shape_ffi::*;
+
+const fn range(start: u64, end: u64) -> RangeFFI {
+    RangeFFI { start, end }
+}
+
+macro_rules! shapes_buffer_tests {
+    (
+        $module:ident, $buffer:ty, $coord:ty,
+        create: $create:ident,
+        with_capacity: $with_capacity:ident,
+        clear: $clear:ident,
+        free: $free:ident,
+        points_ptr: $points_ptr:ident,
+        points_len: $points_len:ident,
+        contours_ptr: $contours_ptr:ident,
+        contours_len: $contours_len:ident,
+        shapes_ptr: $shapes_ptr:ident,
+        shapes_len: $shapes_len:ident,
+        view: $view:ident,
+        set: $set:ident,
+        begin_shape: $begin_shape:ident,
+        push_contour: $push_contour:ident $(,)?
+    ) => {
+        mod $module {
+            use super::*;
+
+            // A square with a square hole, followed by a triangle.
+            const POINTS: [$coord; 22] = [
+                0 as $coord,
+                0 as $coord,
+                10 as $coord,
+                0 as $coord,
+                10 as $coord,
+                10 as $coord,
+                0 as $coord,
+                10 as $coord,
+                2 as $coord,
+                2 as $coord,
+                2 as $coord,
+                8 as $coord,
+                8 as $coord,
+                8 as $coord,
+                8 as $coord,
+                2 as $coord,
+                20 as $coord,
+                0 as $coord,
+                30 as $coord,
+                0 as $coord,
+                25 as $coord,
+                5 as $coord,
+            ];
+            const CONTOURS: [RangeFFI; 3] = [range(0, 8), range(8, 16), range(16, 22)];
+            const SHAPES: [RangeFFI; 2] = [range(0, 2), range(2, 3)];
+
+            fn read(buffer: *const $buffer) -> common::Shapes {
+                let shapes = read_shapes(
+                    $points_ptr(buffer),
+                    $points_len(buffer),
+                    $contours_ptr(buffer),
+                    $contours_len(buffer),
+                    $shapes_ptr(buffer),
+                    $shapes_len(buffer),
+                );
+
+                let view = $view(buffer);
+                assert_eq!(view.points, $points_ptr(buffer));
+                assert_eq!(view.points_len, $points_len(buffer));
+                assert_eq!(view.contours, $contours_ptr(buffer));
+                assert_eq!(view.contours_len, $contours_len(buffer));
+                assert_eq!(view.shapes, $shapes_ptr(buffer));
+                assert_eq!(view.shapes_len, $shapes_len(buffer));
+
+                shapes
+            }
+
+            fn set_sample(buffer: *mut $buffer) -> IShapeStatus {
+                $set(
+                    buffer,
+                    POINTS.as_ptr(),
+                    POINTS.len(),
+                    CONTOURS.as_ptr(),
+                    CONTOURS.len(),
+                    SHAPES.as_ptr(),
+                    SHAPES.len(),
+                )
+            }
+
+            #[test]
+            fn new_buffers_are_empty() {
+                for buffer in [
+                    Handle::new($create(), $free),
+                    Handle::new($with_capacity(64, 8, 2), $free),
+                ] {
+                    assert!(read(buffer.ptr()).is_empty());
+                    assert_eq!($points_len(buffer.ptr()), 0);
+                }
+            }
+
+            #[test]
+            fn null_buffer_accessors_are_empty() {
+                assert!($points_ptr(ptr::null()).is_null());
+                assert_eq!($points_len(ptr::null()), 0);
+                assert!($contours_ptr(ptr::null()).is_null());
+                assert_eq!($contours_len(ptr::null()), 0);
+                assert!($shapes_ptr(ptr::null()).is_null());
+                assert_eq!($shapes_len(ptr::null()), 0);
+
+                let view = $view(ptr::null());
+                assert!(view.points.is_null() && view.contours.is_null() && view.shapes.is_null());
+                assert_eq!(view.points_len + view.contours_len + view.shapes_len, 0);
+
+                $clear(ptr::null_mut());
+                $free(ptr::null_mut());
+            }
+
+            #[test]
+            fn set_copies_flat_arrays() {
+                let buffer = Handle::new($create(), $free);
+                assert_eq!(set_sample(buffer.ptr()), IShapeStatus::Ok);
+
+                assert_eq!(
+                    raw_slice($points_ptr(buffer.ptr()), $points_len(buffer.ptr())),
+                    &POINTS
+                );
+                let shapes = read(buffer.ptr());
+                assert_eq!(shapes.len(), 2);
+                assert_eq!(shapes[0].len(), 2);
+                assert_eq!(shapes[1].len(), 1);
+                assert_eq!(shapes[1][0], vec![[20.0, 0.0], [30.0, 0.0], [25.0, 5.0]]);
+
+                $clear(buffer.ptr());
+                assert!(read(buffer.ptr()).is_empty());
+            }
+
+            #[test]
+            fn set_with_empty_arrays_clears() {
+                let buffer = Handle::new($create(), $free);
+                assert_eq!(set_sample(buffer.ptr()), IShapeStatus::Ok);
+
+                let status = $set(buffer.ptr(), ptr::null(), 0, ptr::null(), 0, ptr::null(), 0);
+                assert_eq!(status, IShapeStatus::Ok);
+                assert!(read(buffer.ptr()).is_empty());
+            }
+
+            #[test]
+            fn set_requires_ranges_in_order_without_overlap() {
+                let buffer = Handle::new($create(), $free);
+                assert_eq!(set_sample(buffer.ptr()), IShapeStatus::Ok);
+                let before = read(buffer.ptr());
+
+                let set = |contours: &[RangeFFI], shapes: &[RangeFFI]| {
+                    $set(
+                        buffer.ptr(),
+                        POINTS.as_ptr(),
+                        POINTS.len(),
+                        contours.as_ptr(),
+                        contours.len(),
+                        shapes.as_ptr(),
+                        shapes.len(),
+                    )
+                };
+
+                assert_eq!(
+                    set(&[range(0, 8), range(6, 16)], &[]),
+                    IShapeStatus::InvalidRange
+                );
+                assert!(common::last_error().unwrap().contains(
+                    "contour 1 range 6..16 starts before the previous contour ends at 8"
+                ));
+                assert_eq!(
+                    set(&[range(8, 16), range(0, 8)], &[]),
+                    IShapeStatus::InvalidRange
+                );
+                assert_eq!(
+                    set(&CONTOURS, &[range(0, 2), range(1, 3)]),
+                    IShapeStatus::InvalidRange
+                );
+                assert!(
+                    common::last_error()
+                        .unwrap()
+                        .contains("shape 1 range 1..3 starts before the previous shape ends at 2")
+                );
+                assert_eq!(
+                    set(&CONTOURS, &[range(2, 3), range(0, 2)]),
+                    IShapeStatus::InvalidRange
+                );
+                assert_eq!(read(buffer.ptr()), before);
+
+                // Gaps and repeated empty ranges do not overlap anything.
+                let contours = [range(0, 8), range(8, 8), range(8, 8), range(16, 22)];
+                let shapes = [range(0, 1), range(3, 4)];
+                assert_eq!(set(&contours, &shapes), IShapeStatus::Ok);
+            }
+
+            #[test]
+            fn set_rejects_malformed_layouts_without_modifying() {
+                let buffer = Handle::new($create(), $free);
+                assert_eq!(set_sample(buffer.ptr()), IShapeStatus::Ok);
+                let before = read(buffer.ptr());
+
+                let set = |points: &[$coord], contours: &[RangeFFI], shapes: &[RangeFFI]| {
+                    $set(
+                        buffer.ptr(),
+                        points.as_ptr(),
+                        points.len(),
+                        contours.as_ptr(),
+                        contours.len(),
+                        shapes.as_ptr(),
+                        shapes.len(),
+                    )
+                };
+
+                assert_eq!(
+                    set(&POINTS[..21], &[], &[]),
+                    IShapeStatus::OddCoordinateCount
+                );
+                assert_eq!(
+                    set(&POINTS, &[range(0, 24)], &[]),
+                    IShapeStatus::InvalidRange
+                );
+                assert_eq!(
+                    set(&POINTS, &[range(8, 0)], &[]),
+                    IShapeStatus::InvalidRange
+                );
+                assert_eq!(
+                    set(&POINTS, &[range(1, 7)], &[]),
+                    IShapeStatus::InvalidRange
+                );
+                assert_eq!(
+                    set(&POINTS, &CONTOURS, &[range(0, 4)]),
+                    IShapeStatus::InvalidRange
+                );
+                assert_eq!(
+                    set(&POINTS, &CONTOURS, &[range(2, 1)]),
+                    IShapeStatus::InvalidRange
+                );
+                assert!(common::last_error().unwrap().contains("shape 0 range 2..1"));
+
+                let status = $set(buffer.ptr(), ptr::null(), 2, ptr::null(), 0, ptr::null(), 0);
+                assert_eq!(status, IShapeStatus::NullPointer);
+                let status = $set(
+                    ptr::null_mut(),
+                    ptr::null(),
+                    0,
+                    ptr::null(),
+                    0,
+                    ptr::null(),
+                    0,
+                );
+                assert_eq!(status, IShapeStatus::NullPointer);
+
+                assert_eq!(read(buffer.ptr()), before);
+            }
+
+            #[test]
+            fn push_contour_builds_shapes() {
+                let buffer = Handle::new($with_capacity(0, 0, 0), $free);
+
+                // The first contour starts a shape on its own.
+                assert_eq!(
+                    $push_contour(buffer.ptr(), POINTS.as_ptr(), 8),
+                    IShapeStatus::Ok
+                );
+                assert_eq!(
+                    $push_contour(buffer.ptr(), POINTS[8..].as_ptr(), 8),
+                    IShapeStatus::Ok
+                );
+                assert_eq!($begin_shape(buffer.ptr()), IShapeStatus::Ok);
+                assert_eq!(
+                    $push_contour(buffer.ptr(), POINTS[16..].as_ptr(), 6),
+                    IShapeStatus::Ok
+                );
+
+                let expected = Handle::new($create(), $free);
+                assert_eq!(set_sample(expected.ptr()), IShapeStatus::Ok);
+                assert_eq!(read(buffer.ptr()), read(expected.ptr()));
+
+                // An empty shape is kept as an empty range.
+                assert_eq!($begin_shape(buffer.ptr()), IShapeStatus::Ok);
+                let shapes = read(buffer.ptr());
+                assert_eq!(shapes.len(), 3);
+                assert!(shapes[2].is_empty());
+            }
+
+            #[test]
+            fn push_contour_rejects_bad_input() {
+                let buffer = Handle::new($create(), $free);
+
+                assert_eq!(
+                    $push_contour(buffer.ptr(), POINTS.as_ptr(), 7),
+                    IShapeStatus::OddCoordinateCount
+                );
+                assert_eq!(
+                    $push_contour(buffer.ptr(), POINTS.as_ptr(), 0),
+                    IShapeStatus::TooFewPoints
+                );
+                assert_eq!(
+                    $push_contour(buffer.ptr(), ptr::null(), 8),
+                    IShapeStatus::NullPointer
+                );
+                assert_eq!(
+                    $push_contour(ptr::null_mut(), POINTS.as_ptr(), 8),
+                    IShapeStatus::NullPointer
+                );
+                assert_eq!($begin_shape(ptr::null_mut()), IShapeStatus::NullPointer);
+
+                assert!(read(buffer.ptr()).is_empty());
+            }
+        }
+    };
+}
+
+shapes_buffer_tests!(
+    int, FlatShapesBuffer, i32,
+    create: ishape_flat_shapes_create,
+    with_capacity: ishape_flat_shapes_with_capacity,
+    clear: ishape_flat_shapes_clear,
+    free: ishape_flat_shapes_free,
+    points_ptr: ishape_flat_shapes_points_ptr,
+    points_len: ishape_flat_shapes_points_len,
+    contours_ptr: ishape_flat_shapes_contours_ptr,
+    contours_len: ishape_flat_shapes_contours_len,
+    shapes_ptr: ishape_flat_shapes_shapes_ptr,
+    shapes_len: ishape_flat_shapes_shapes_len,
+    view: ishape_flat_shapes_view,
+    set: ishape_flat_shapes_set,
+    begin_shape: ishape_flat_shapes_begin_shape,
+    push_contour: ishape_flat_shapes_push_contour,
+);
+
+shapes_buffer_tests!(
+    f32, FlatF32ShapesBuffer, f32,
+    create: ishape_flat_f32_shapes_create,
+    with_capacity: ishape_flat_f32_shapes_with_capacity,
+    clear: ishape_flat_f32_shapes_clear,
+    free: ishape_flat_f32_shapes_free,
+    points_ptr: ishape_flat_f32_shapes_points_ptr,
+    points_len: ishape_flat_f32_shapes_points_len,
+    contours_ptr: ishape_flat_f32_shapes_contours_ptr,
+    contours_len: ishape_flat_f32_shapes_contours_len,
+    shapes_ptr: ishape_flat_f32_shapes_shapes_ptr,
+    shapes_len: ishape_flat_f32_shapes_shapes_len,
+    view: ishape_flat_f32_shapes_view,
+    set: ishape_flat_f32_shapes_set,
+    begin_shape: ishape_flat_f32_shapes_begin_shape,
+    push_contour: ishape_flat_f32_shapes_push_contour,
+);
+
+shapes_buffer_tests!(
+    f64, FlatF64ShapesBuffer, f64,
+    create: ishape_flat_f64_shapes_create,
+    with_capacity: ishape_flat_f64_shapes_with_capacity,
+    clear: ishape_flat_f64_shapes_clear,
+    free: ishape_flat_f64_shapes_free,
+    points_ptr: ishape_flat_f64_shapes_points_ptr,
+    points_len: ishape_flat_f64_shapes_points_len,
+    contours_ptr: ishape_flat_f64_shapes_contours_ptr,
+    contours_len: ishape_flat_f64_shapes_contours_len,
+    shapes_ptr: ishape_flat_f64_shapes_shapes_ptr,
+    shapes_len: ishape_flat_f64_shapes_shapes_len,
+    view: ishape_flat_f64_shapes_view,
+    set: ishape_flat_f64_shapes_set,
+    begin_shape: ishape_flat_f64_shapes_begin_shape,
+    push_contour: ishape_flat_f64_shapes_push_contour,
+);
+
+macro_rules! triangulation_buffer_tests {
+    (
+        $module:ident,
+        create: $create:ident,
+        with_capacity: $with_capacity:ident,
+        clear: $clear:ident,
+        free: $free:ident,
+        points_ptr: $points_ptr:ident,
+        points_len: $points_len:ident,
+        indices_ptr: $indices_ptr:ident,
+        indices_len: $indices_len:ident,
+        view: $view:ident $(,)?
+    ) => {
+        mod $module {
+            use super::*;
+
+            #[test]
+            fn new_buffers_are_empty() {
+                for buffer in [
+                    Handle::new($create(), $free),
+                    Handle::new($with_capacity(16, 4), $free),
+                ] {
+                    assert_eq!($points_len(buffer.ptr()), 0);
+                    assert_eq!($indices_len(buffer.ptr()), 0);
+
+                    let view = $view(buffer.ptr());
+                    assert_eq!(view.points, $points_ptr(buffer.ptr()));
+                    assert_eq!(view.indices, $indices_ptr(buffer.ptr()));
+                    assert_eq!(view.points_len + view.indices_len, 0);
+
+                    $clear(buffer.ptr());
+                    assert_eq!($points_len(buffer.ptr()), 0);
+                }
+            }
+
+            #[test]
+            fn null_buffer_accessors_are_empty() {
+                assert!($points_ptr(ptr::null()).is_null());
+                assert_eq!($points_len(ptr::null()), 0);
+                assert!($indices_ptr(ptr::null()).is_null());
+                assert_eq!($indices_len(ptr::null()), 0);
+
+                let view = $view(ptr::null());
+                assert!(view.points.is_null() && view.indices.is_null());
+                assert_eq!(view.points_len + view.indices_len, 0);
+
+                $clear(ptr::null_mut());
+                $free(ptr::null_mut());
+            }
+        }
+    };
+}
+
+triangulation_buffer_tests!(
+    int_triangulation,
+    create: ishape_triangle_flat_triangulation_create,
+    with_capacity: ishape_triangle_flat_triangulation_with_capacity,
+    clear: ishape_triangle_flat_triangulation_clear,
+    free: ishape_triangle_flat_triangulation_free,
+    points_ptr: ishape_triangle_flat_triangulation_points_ptr,
+    points_len: ishape_triangle_flat_triangulation_points_len,
+    indices_ptr: ishape_triangle_flat_triangulation_indices_ptr,
+    indices_len: ishape_triangle_flat_triangulation_indices_len,
+    view: ishape_triangle_flat_triangulation_view,
+);
+
+triangulation_buffer_tests!(
+    f32_triangulation,
+    create: ishape_triangle_flat_f32_triangulation_create,
+    with_capacity: ishape_triangle_flat_f32_triangulation_with_capacity,
+    clear: ishape_triangle_flat_f32_triangulation_clear,
+    free: ishape_triangle_flat_f32_triangulation_free,
+    points_ptr: ishape_triangle_flat_f32_triangulation_points_ptr,
+    points_len: ishape_triangle_flat_f32_triangulation_points_len,
+    indices_ptr: ishape_triangle_flat_f32_triangulation_indices_ptr,
+    indices_len: ishape_triangle_flat_f32_triangulation_indices_len,
+    view: ishape_triangle_flat_f32_triangulation_view,
+);
+
+triangulation_buffer_tests!(
+    f64_triangulation,
+    create: ishape_triangle_flat_f64_triangulation_create,
+    with_capacity: ishape_triangle_flat_f64_triangulation_with_capacity,
+    clear: ishape_triangle_flat_f64_triangulation_clear,
+    free: ishape_triangle_flat_f64_triangulation_free,
+    points_ptr: ishape_triangle_flat_f64_triangulation_points_ptr,
+    points_len: ishape_triangle_flat_f64_triangulation_points_len,
+    indices_ptr: ishape_triangle_flat_f64_triangulation_indices_ptr,
+    indices_len: ishape_triangle_flat_f64_triangulation_indices_len,
+    view: ishape_triangle_flat_f64_triangulation_view,
+);
+
+/// Compiles only while the public fields keep the same type with and without the
+/// `host-allocator` feature.
+#[test]
+fn buffer_fields_do_not_depend_on_features() {
+    use i_shape_ffi::allocator::{HostAllocator, HostVec};
+
+    let mut points: HostVec<f64> = HostVec::new_in(HostAllocator::current());
+    points.extend_from_slice(&[0.0, 1.0]);
+
+    let shapes = FlatF64ShapesBuffer {
+        flat_points: points.clone(),
+        ..Default::default()
+    };
+    let triangulation = FlatF64Triangulation {
+        flat_points: points,
+        ..Default::default()
+    };
+    let indices: HostVec<IntTriangulationIndex> = triangulation.indices.clone();
+
+    assert_eq!(&shapes.flat_points[..], &triangulation.flat_points[..]);
+    assert!(indices.is_empty());
+}
+
+#[test]
+fn empty_shapes_are_skipped_by_consumers() {
+    let with_empty_shapes = common::f64_shapes_buffer();
+    let plain = common::f64_shapes_buffer();
+    let contour = common::square(0.0, 0.0, 10.0);
+    for buffer in [&with_empty_shapes, &plain] {
+        let status = ishape_flat_f64_shapes_push_contour(buffer.ptr(), contour.as_ptr(), 8);
+        assert_eq!(status, IShapeStatus::Ok);
+    }
+    for _ in 0..2 {
+        assert_eq!(
+            ishape_flat_f64_shapes_begin_shape(with_empty_shapes.ptr()),
+            IShapeStatus::Ok
+        );
+    }
+
+    let outline = |input: &Handle<FlatF64ShapesBuffer>| {
+        let output = common::f64_shapes_buffer();
+        let status = ishape_outline_f64_shapes_to_flat(input.ptr(), 1.0, output.ptr());
+        assert_eq!(status, IShapeStatus::Ok);
+        common::read_f64_shapes(output.ptr())
+    };
+    assert_eq!(outline(&with_empty_shapes), outline(&plain));
+
+    let triangulate = |input: &Handle<FlatF64ShapesBuffer>| {
+        let triangulator = Handle::new(
+            ishape_triangle_f64_triangulator_create(0, IntTriangulatorValidation::default()),
+            ishape_triangle_f64_triangulator_free,
+        );
+        let output = Handle::new(
+            ishape_triangle_flat_f64_triangulation_create(),
+            ishape_triangle_flat_f64_triangulation_free,
+        );
+        let status = ishape_triangle_f64_triangulator_triangulate_flat(
+            triangulator.ptr(),
+            input.ptr(),
+            output.ptr(),
+        );
+        assert_eq!(status, IShapeStatus::Ok);
+        let view = ishape_triangle_flat_f64_triangulation_view(output.ptr());
+        raw_slice(view.indices, view.indices_len).to_vec()
+    };
+    assert_eq!(triangulate(&with_empty_shapes), triangulate(&plain));
+}
@@ -0,0 +1,129 @@
+mod common;
+
+use std::ffi::{CStr, c_char};
+use std::ptr;
+
+use common::last_error;
+use i_shape_ffi::*;
+
+const ALL_STATUSES: [IShapeStatus; 11] = [
+    IShapeStatus::Ok,
+    IShapeStatus::NullPointer,
+    IShapeStatus::OddCoordinateCount,
+    IShapeStatus::TooFewPoints,
+    IShapeStatus::InvalidLineJoin,
+    IShapeStatus::InvalidLineCap,
+    IShapeStatus::InvalidWidth,
+    IShapeStatus::InvalidRange,
+    IShapeStatus::Panic,
+    IShapeStatus::InsufficientCapacity,
+    IShapeStatus::NonFiniteValue,
+];
+
+#[test]
+fn version_reports_crate_and_dependencies() {
+    let version = unsafe { CStr::from_ptr(ishape_version()) }
+        .to_str()
+        .unwrap();
+    assert!(version.starts_with(env!("CARGO_PKG_VERSION")), "{version}");
+    assert!(version.contains("i_triangle "), "{version}");
+    assert!(version.contains("i_overlay "), "{version}");
+
+    assert_eq!(ishape_abi_version(), version::ISHAPE_ABI_VERSION);
+}
+
+#[test]
+fn has_feature_matches_cargo_features() {
+    assert_eq!(
+        ishape_has_feature(c"host-allocator".as_ptr()),
+        cfg!(feature = "host-allocator")
+    );
+    assert!(!ishape_has_feature(c"no-such-feature".as_ptr()));
+    assert!(!ishape_has_feature(c"".as_ptr()));
+    assert!(!ishape_has_feature(ptr::null()));
+}
+
+#[test]
+fn every_status_has_a_distinct_message() {
+    let mut messages = Vec::new();
+    for (code, status) in ALL_STATUSES.into_iter().enumerate() {
+        assert_eq!(status as usize, code);
+        assert_eq!(status.is_ok(), status == IShapeStatus::Ok);
+
+        let message = unsafe { CStr::from_ptr(ishape_status_message(status)) };
+        assert!(!message.is_empty());
+        assert!(!messages.contains(&message), "{message:?} is repeated");
+        messages.push(message);
+    }
+}
+
+#[test]
+fn last_error_is_recorded_truncated_and_cleared() {
+    ishape_last_error_clear();
+    assert_eq!(last_error(), None);
+    assert_eq!(ishape_last_error_message(ptr::null_mut(), 0), 0);
+
+    let mut empty = [b'x' as c_char; 4];
+    assert_eq!(
+        ishape_last_error_message(empty.as_mut_ptr(), empty.len()),
+        0
+    );
+    assert_eq!(empty[0], 0);
+
+    let status = ishape_flat_shapes_push_contour(ptr::null_mut(), ptr::null(), 0);
+    assert_eq!(status, IShapeStatus::NullPointer);
+
+    let message = last_error().unwrap();
+    let status_message = unsafe { CStr::from_ptr(ishape_status_message(status)) };
+    assert!(
+        message.starts_with(status_message.to_str().unwrap()),
+        "{message}"
+    );
+
+    let mut short = [b'x' as c_char; 5];
+    let len = ishape_last_error_message(short.as_mut_ptr(), short.len());
+    assert_eq!(len, message.len() + 1);
+    let truncated = unsafe { CStr::from_ptr(short.as_ptr()) };
+    assert_eq!(truncated.to_bytes(), &message.as_bytes()[..4]);
+
+    // Successful calls leave the previous error in place.
+    let buffer = common::int_shapes_buffer();
+    assert_eq!(
+        ishape_flat_shapes_begin_shape(buffer.ptr()),
+        IShapeStatus::Ok
+    );
+    assert_eq!(last_error(), Some(message));
+
+    ishape_last_error_clear();
+    assert_eq!(last_error(), None);
+}
+
+#[test]
+fn last_error_is_per_thread() {
+    assert_eq!(
+        ishape_flat_shapes_begin_shape(ptr::null_mut()),
+        IShapeStatus::NullPointer
+    );
+    assert!(last_error().is_some());
+
+    std::thread::spawn(|| assert_eq!(last_error(), None))
+        .join()
+        .unwrap();
+}
+
+#[test]
+fn panics_are_contained_and_recorded() {
+    ishape_last_error_clear();
+
+    // Reserving `usize::MAX` points overflows the capacity computation and panics.
+    let buffer = ishape_flat_f64_shapes_with_capacity(usize::MAX, 0, 0);
+    assert!(buffer.is_null());
+
+    let message = last_error().unwrap();
+    let status_message = unsafe { CStr::from_ptr(ishape_status_message(IShapeStatus::Panic)) };
+    assert!(
+        message.starts_with(status_message.to_str().unwrap()),
+        "{message}"
+    );
+    assert!(message.contains("capacity overflow"), "{message}");
+}
@@ -0,0 +1,684 @@
+mod common;
+
+use std::f64::consts::PI;
+use std::ptr;
+
+use common::{abs_shapes_area, assert_close, square};
+use i_shape_ffi::*;
+
+const JOINS: [FfiLineJoinKind; 3] = [
+    FfiLineJoinKind::Bevel,
+    FfiLineJoinKind::Miter,
+    FfiLineJoinKind::Round,
+];
+const CAPS: [FfiLineCapKind; 3] = [
+    FfiLineCapKind::Butt,
+    FfiLineCapKind::Round,
+    FfiLineCapKind::Square,
+];
+
+/// A horizontal segment of length 10.
+const SEGMENT: [f64; 4] = [0.0, 0.0, 10.0, 0.0];
+/// An open path turning left by 90° at `(10, 0)`.
+const CORNER: [f64; 6] = [0.0, 0.0, 10.0, 0.0, 10.0, 10.0];
+/// Stroke width used by every test; the half width is 1.
+const WIDTH: f64 = 2.0;
+
+fn style(
+    join: FfiLineJoinKind,
+    start_cap: FfiLineCapKind,
+    end_cap: FfiLineCapKind,
+) -> FfiStrokeStyle {
+    FfiStrokeStyle {
+        width: WIDTH,
+        join: FfiLineJoin {
+            kind: join,
+            value: 0.1,
+        },
+        start_cap: FfiLineCap {
+            kind: start_cap,
+            value: 0.1,
+        },
+        end_cap: FfiLineCap {
+            kind: end_cap,
+            value: 0.1,
+        },
+        non_finite: IShapeNonFinitePolicy::Reject,
+    }
+}
+
+/// Area a stroked [`SEGMENT`] gains from one cap.
+fn cap_area(cap: FfiLineCapKind) -> f64 {
+    match cap {
+        FfiLineCapKind::Butt => 0.0,
+        FfiLineCapKind::Round => 0.5 * PI,
+        FfiLineCapKind::Square => 2.0,
+    }
+}
+
+/// Area of the stroked [`CORNER`] with butt caps: two 10 × 2 bands overlapping by a unit square,
+/// plus the outer corner, which is a full unit square only for a miter join.
+fn corner_area(join: FfiLineJoinKind) -> f64 {
+    40.0 - match join {
+        FfiLineJoinKind::Bevel => 0.5,
+        FfiLineJoinKind::Miter => 0.0,
+        FfiLineJoinKind::Round => 1.0 - 0.25 * PI,
+    }
+}
+
+/// Round joins and caps are polygonal approximations, so they come out slightly smaller.
+fn round_tolerance(has_round: bool, tolerance: f64) -> (f64, f64) {
+    if has_round {
+        (0.05, tolerance)
+    } else {
+        (tolerance, tolerance)
+    }
+}
+
+macro_rules! stroke_tests {
+    (
+        $module:ident,
+        coord: $coord:ty,
+        slices: $slices:ident,
+        tolerance: $tolerance:expr,
+        contour_to_flat: $contour_to_flat:ident,
+        contour_to_flat_styled: $contour_to_flat_styled:ident,
+        contour_to_flat_custom_caps: $contour_to_flat_custom_caps:ident,
+        paths_to_flat: $paths_to_flat:ident,
+        paths_to_flat_styled: $paths_to_flat_styled:ident,
+        paths_to_flat_custom_caps: $paths_to_flat_custom_caps:ident,
+        contour_to_slices: $contour_to_slices:ident,
+        paths_to_slices: $paths_to_slices:ident,
+        new_buffer: $new_buffer:path,
+        read: $read:path $(,)?
+    ) => {
+        mod $module {
+            use super::*;
+
+            fn coords(contour: &[f64]) -> Vec<$coord> {
+                contour.iter().map(|&v| v as $coord).collect()
+            }
+
+            fn stroke(contour: &[f64], closed: bool, style: FfiStrokeStyle) -> common::Shapes {
+                let coords = coords(contour);
+                let output = $new_buffer();
+                let status =
+                    $contour_to_flat(coords.as_ptr(), coords.len(), closed, style, output.ptr());
+                assert_eq!(status, IShapeStatus::Ok);
+                $read(output.ptr())
+            }
+
+            fn stroke_styled(
+                contour: &[f64],
+                width: f64,
+                join: (u32, f64),
+                start_cap: (u32, f64),
+                end_cap: (u32, f64),
+            ) -> (IShapeStatus, common::Shapes) {
+                let coords = coords(contour);
+                let output = $new_buffer();
+                let status = $contour_to_flat_styled(
+                    coords.as_ptr(),
+                    coords.len(),
+                    width as $coord,
+                    false,
+                    join.0,
+                    join.1 as $coord,
+                    start_cap.0,
+                    start_cap.1 as $coord,
+                    end_cap.0,
+                    end_cap.1 as $coord,
+                    output.ptr(),
+                );
+                (status, $read(output.ptr()))
+            }
+
+            struct Paths {
+                points: Vec<$coord>,
+                ranges: Vec<RangeFFI>,
+                closed: Vec<bool>,
+            }
+
+            fn paths(paths: &[(&[f64], bool)]) -> Paths {
+                let mut result = Paths {
+                    points: Vec::new(),
+                    ranges: Vec::new(),
+                    closed: Vec::new(),
+                };
+                for (path, closed) in paths {
+                    let start = result.points.len() as u64;
+                    result.points.extend(coords(path));
+                    result.ranges.push(RangeFFI {
+                        start,
+                        end: result.points.len() as u64,
+                    });
+                    result.closed.push(*closed);
+                }
+                result
+            }
+
+            fn empty_slices() -> $slices {
+                $slices {
+                    points: ptr::null_mut(),
+                    points_capacity: 0,
+                    points_len: 0,
+                    contours: ptr::null_mut(),
+                    contours_capacity: 0,
+                    contours_len: 0,
+                    shapes: ptr::null_mut(),
+                    shapes_capacity: 0,
+                    shapes_len: 0,
+                }
+            }
+
+            fn write_through_slices(
+                mut write: impl FnMut(&mut $slices) -> IShapeStatus,
+            ) -> common::Shapes {
+                let mut slices = empty_slices();
+                assert_eq!(write(&mut slices), IShapeStatus::InsufficientCapacity);
+
+                let mut points = vec![0 as $coord; slices.points_len];
+                let mut contours = vec![RangeFFI { start: 0, end: 0 }; slices.contours_len];
+                let mut shapes = vec![RangeFFI { start: 0, end: 0 }; slices.shapes_len];
+                slices.points = points.as_mut_ptr();
+                slices.points_capacity = points.len();
+                slices.contours = contours.as_mut_ptr();
+                slices.contours_capacity = contours.len();
+                slices.shapes = shapes.as_mut_ptr();
+                slices.shapes_capacity = shapes.len();
+                assert_eq!(write(&mut slices), IShapeStatus::Ok);
+
+                common::read_shapes(
+                    slices.points,
+                    slices.points_len,
+                    slices.contours,
+                    slices.contours_len,
+                    slices.shapes,
+                    slices.shapes_len,
+                )
+            }
+
+            #[test]
+            fn every_cap_pair_adds_expected_area() {
+                for start in CAPS {
+                    for end in CAPS {
+                        let shapes =
+                            stroke(&SEGMENT, false, style(FfiLineJoinKind::Bevel, start, end));
+                        let expected = 10.0 * WIDTH + cap_area(start) + cap_area(end);
+                        let area = abs_shapes_area(&shapes);
+                        let has_round =
+                            start == FfiLineCapKind::Round || end == FfiLineCapKind::Round;
+                        let (below, above) = round_tolerance(has_round, $tolerance);
+                        assert!(
+                            area >= expected - below && area <= expected + above,
+                            "{start:?}/{end:?}: expected {expected}, got {area}"
+                        );
+                    }
+                }
+            }
+
+            #[test]
+            fn every_join_shapes_the_corner() {
+                for join in JOINS {
+                    let shapes = stroke(
+                        &CORNER,
+                        false,
+                        style(join, FfiLineCapKind::Butt, FfiLineCapKind::Butt),
+                    );
+                    let expected = corner_area(join);
+                    let area = abs_shapes_area(&shapes);
+                    let (below, above) =
+                        round_tolerance(join == FfiLineJoinKind::Round, $tolerance);
+                    assert!(
+                        area >= expected - below && area <= expected + above,
+                        "{join:?}: expected {expected}, got {area}"
+                    );
+                }
+            }
+
+            #[test]
+            fn closed_path_strokes_a_ring() {
+                let shapes = stroke(
+                    &square(0.0, 0.0, 10.0),
+                    true,
+                    style(
+                        FfiLineJoinKind::Miter,
+                        FfiLineCapKind::Butt,
+                        FfiLineCapKind::Butt,
+                    ),
+                );
+                assert_eq!(shapes.len(), 1);
+                assert_eq!(shapes[0].len(), 2, "ring with one hole");
+                assert_close(
+                    abs_shapes_area(&shapes),
+                    12.0 * 12.0 - 8.0 * 8.0,
+                    $tolerance,
+                );
+            }
+
+            #[test]
+            fn styled_entry_point_matches_struct_style() {
+                for (join_kind, join) in JOINS.into_iter().enumerate() {
+                    for (cap_kind, cap) in CAPS.into_iter().enumerate() {
+                        let (status, styled) = stroke_styled(
+                            &CORNER,
+                            WIDTH,
+                            (join_kind as u32, 0.1),
+                            (cap_kind as u32, 0.1),
+                            (cap_kind as u32, 0.1),
+                        );
+                        assert_eq!(status, IShapeStatus::Ok);
+                        assert_eq!(styled, stroke(&CORNER, false, style(join, cap, cap)));
+                    }
+                }
+            }
+
+            #[test]
+            fn styled_entry_point_rejects_bad_styles() {
+                let ok = (0, 0.0);
+                for width in [0.0, -1.0, f64::NAN, f64::INFINITY] {
+                    let (status, shapes) = stroke_styled(&SEGMENT, width, ok, ok, ok);
+                    assert_eq!(status, IShapeStatus::InvalidWidth, "width {width}");
+                    assert!(shapes.is_empty());
+                }
+
+                for join in [(3, 0.0), (1, f64::NAN), (2, f64::INFINITY)] {
+                    let (status, _) = stroke_styled(&SEGMENT, WIDTH, join, ok, ok);
+                    assert_eq!(status, IShapeStatus::InvalidLineJoin, "join {join:?}");
+                }
+
+                for cap in [(3, 0.0), (1, f64::NAN)] {
+                    let (status, _) = stroke_styled(&SEGMENT, WIDTH, ok, cap, ok);
+                    assert_eq!(status, IShapeStatus::InvalidLineCap, "start cap {cap:?}");
+                    let (status, _) = stroke_styled(&SEGMENT, WIDTH, ok, ok, cap);
+                    assert_eq!(status, IShapeStatus::InvalidLineCap, "end cap {cap:?}");
+                }
+            }
+
+            #[test]
+            fn struct_style_is_validated() {
+                let segment = coords(&SEGMENT);
+                let output = $new_buffer();
+                let stroke = |style| {
+                    $contour_to_flat(segment.as_ptr(), segment.len(), false, style, output.ptr())
+                };
+                let base = style(
+                    FfiLineJoinKind::Bevel,
+                    FfiLineCapKind::Butt,
+                    FfiLineCapKind::Butt,
+                );
+
+                assert_eq!(
+                    stroke(FfiStrokeStyle { width: 0.0, ..base }),
+                    IShapeStatus::InvalidWidth
+                );
+                assert_eq!(
+                    stroke(FfiStrokeStyle {
+                        join: FfiLineJoin {
+                            kind: FfiLineJoinKind::Miter,
+                            value: f64::NAN
+                        },
+                        ..base
+                    }),
+                    IShapeStatus::InvalidLineJoin
+                );
+                assert_eq!(
+                    stroke(FfiStrokeStyle {
+                        end_cap: FfiLineCap {
+                            kind: FfiLineCapKind::Round,
+                            value: f64::INFINITY
+                        },
+                        ..base
+                    }),
+                    IShapeStatus::InvalidLineCap
+                );
+                assert_eq!(stroke(FfiStrokeStyle::default()), IShapeStatus::Ok);
+            }
+
+            #[test]
+            fn struct_style_is_validated_after_conversion() {
+                let segment = coords(&SEGMENT);
+                let output = $new_buffer();
+                let stroke = |style| {
+                    $contour_to_flat(segment.as_ptr(), segment.len(), false, style, output.ptr())
+                };
+                let base = style(
+                    FfiLineJoinKind::Miter,
+                    FfiLineCapKind::Round,
+                    FfiLineCapKind::Round,
+                );
+
+                // Twice the largest coordinate overflows to infinity in either precision.
+                let huge = f64::from(<$coord>::MAX) * 2.0;
+                assert_eq!(
+                    stroke(FfiStrokeStyle {
+                        width: huge,
+                        ..base
+                    }),
+                    IShapeStatus::InvalidWidth
+                );
+                let join = FfiLineJoin {
+                    kind: FfiLineJoinKind::Miter,
+                    value: huge,
+                };
+                assert_eq!(
+                    stroke(FfiStrokeStyle { join, ..base }),
+                    IShapeStatus::InvalidLineJoin
+                );
+                let cap = FfiLineCap {
+                    kind: FfiLineCapKind::Round,
+                    value: huge,
+                };
+                assert_eq!(
+                    stroke(FfiStrokeStyle {
+                        start_cap: cap,
+                        ..base
+                    }),
+                    IShapeStatus::InvalidLineCap
+                );
+                assert!(common::last_error().unwrap().contains("start cap Round"));
+            }
+
+            #[test]
+            fn contour_stroke_rejects_bad_input() {
+                let segment = coords(&SEGMENT);
+                let output = $new_buffer();
+                let base = style(
+                    FfiLineJoinKind::Bevel,
+                    FfiLineCapKind::Butt,
+                    FfiLineCapKind::Butt,
+                );
+                let stroke = |points: *const $coord, count| {
+                    $contour_to_flat(points, count, false, base, output.ptr())
+                };
+
+                assert_eq!(
+                    stroke(segment.as_ptr(), 3),
+                    IShapeStatus::OddCoordinateCount
+                );
+                assert_eq!(stroke(segment.as_ptr(), 2), IShapeStatus::TooFewPoints);
+                assert_eq!(stroke(ptr::null(), 0), IShapeStatus::TooFewPoints);
+                assert_eq!(stroke(ptr::null(), 4), IShapeStatus::NullPointer);
+                assert_eq!(
+                    $contour_to_flat(
+                        segment.as_ptr(),
+                        segment.len(),
+                        false,
+                        base,
+                        ptr::null_mut()
+                    ),
+                    IShapeStatus::NullPointer
+                );
+                assert_eq!(
+                    $contour_to_slices(
+                        segment.as_ptr(),
+                        segment.len(),
+                        false,
+                        base,
+                        ptr::null_mut()
+                    ),
+                    IShapeStatus::NullPointer
+                );
+            }
+
+            #[test]
+            fn custom_caps_follow_templates() {
+                let segment = coords(&SEGMENT);
+                let output = $new_buffer();
+                let base = style(
+                    FfiLineJoinKind::Bevel,
+                    FfiLineCapKind::Butt,
+                    FfiLineCapKind::Round,
+                );
+                let stroke = |start: &[$coord], end: &[$coord]| {
+                    let status = $contour_to_flat_custom_caps(
+                        segment.as_ptr(),
+                        segment.len(),
+                        false,
+                        base,
+                        start.as_ptr(),
+                        start.len(),
+                        end.as_ptr(),
+                        end.len(),
+                        output.ptr(),
+                    );
+                    (status, abs_shapes_area(&$read(output.ptr())))
+                };
+
+                // `[1, -1, 1, 1]` is a square cap; an empty template keeps the style's cap.
+                let square_cap: [$coord; 4] = [1.0, -1.0, 1.0, 1.0];
+                let (status, area) = stroke(&square_cap, &square_cap);
+                assert_eq!(status, IShapeStatus::Ok);
+                assert_close(
+                    area,
+                    20.0 + 2.0 * cap_area(FfiLineCapKind::Square),
+                    $tolerance,
+                );
+
+                let (status, area) = stroke(&square_cap, &[]);
+                assert_eq!(status, IShapeStatus::Ok);
+                let expected =
+                    20.0 + cap_area(FfiLineCapKind::Square) + cap_area(FfiLineCapKind::Round);
+                assert!(
+                    area <= expected + $tolerance && area >= expected - 0.05,
+                    "{area}"
+                );
+
+                // A single tip point gives a triangular cap of area 1.
+                let (status, area) = stroke(&[1.0, 0.0], &[]);
+                assert_eq!(status, IShapeStatus::Ok);
+                assert!(
+                    area > 20.0 + 1.0 - 0.05
+                        && area < 20.0 + 1.0 + cap_area(FfiLineCapKind::Round) + $tolerance,
+                    "{area}"
+                );
+
+                assert_eq!(
+                    stroke(&square_cap[..3], &[]).0,
+                    IShapeStatus::OddCoordinateCount
+                );
+                assert_eq!(
+                    stroke(&[], &[<$coord>::NAN, 0.0]).0,
+                    IShapeStatus::InvalidLineCap
+                );
+
+                let status = $contour_to_flat_custom_caps(
+                    segment.as_ptr(),
+                    segment.len(),
+                    false,
+                    base,
+                    ptr::null(),
+                    4,
+                    ptr::null(),
+                    0,
+                    output.ptr(),
+                );
+                assert_eq!(status, IShapeStatus::NullPointer);
+            }
+
+            #[test]
+            fn paths_are_stroked_and_unioned() {
+                // Two crossing segments overlap in a 2 × 2 square; a closed square is disjoint.
+                let vertical: &[f64] = &[5.0, -5.0, 5.0, 5.0];
+                let ring = square(20.0, 0.0, 10.0);
+                let paths = paths(&[(&SEGMENT, false), (vertical, false), (&ring, true)]);
+                let base = style(
+                    FfiLineJoinKind::Miter,
+                    FfiLineCapKind::Butt,
+                    FfiLineCapKind::Butt,
+                );
+                let output = $new_buffer();
+
+                let status = $paths_to_flat(
+                    paths.points.as_ptr(),
+                    paths.points.len(),
+                    paths.ranges.as_ptr(),
+                    paths.closed.as_ptr(),
+                    paths.ranges.len(),
+                    base,
+                    output.ptr(),
+                );
+                assert_eq!(status, IShapeStatus::Ok);
+                let shapes = $read(output.ptr());
+                assert_eq!(shapes.len(), 2);
+                assert_close(
+                    abs_shapes_area(&shapes),
+                    20.0 + 20.0 - 4.0 + 80.0,
+                    $tolerance,
+                );
+
+                let status = $paths_to_flat_styled(
+                    paths.points.as_ptr(),
+                    paths.points.len(),
+                    paths.ranges.as_ptr(),
+                    paths.closed.as_ptr(),
+                    paths.ranges.len(),
+                    WIDTH as $coord,
+                    1,
+                    0.1,
+                    0,
+                    0.0,
+                    0,
+                    0.0,
+                    output.ptr(),
+                );
+                assert_eq!(status, IShapeStatus::Ok);
+                assert_eq!($read(output.ptr()), shapes);
+
+                let status = $paths_to_flat_custom_caps(
+                    paths.points.as_ptr(),
+                    paths.points.len(),
+                    paths.ranges.as_ptr(),
+                    paths.closed.as_ptr(),
+                    paths.ranges.len(),
+                    base,
+                    ptr::null(),
+                    0,
+                    ptr::null(),
+                    0,
+                    output.ptr(),
+                );
+                assert_eq!(status, IShapeStatus::Ok);
+                assert_eq!($read(output.ptr()), shapes);
+
+                let actual = write_through_slices(|slices| {
+                    $paths_to_slices(
+                        paths.points.as_ptr(),
+                        paths.points.len(),
+                        paths.ranges.as_ptr(),
+                        paths.closed.as_ptr(),
+                        paths.ranges.len(),
+                        base,
+                        slices,
+                    )
+                });
+                assert_eq!(actual, shapes);
+            }
+
+            #[test]
+            fn contour_slices_match_flat_output() {
+                let segment = coords(&CORNER);
+                let base = style(
+                    FfiLineJoinKind::Round,
+                    FfiLineCapKind::Round,
+                    FfiLineCapKind::Square,
+                );
+                let expected = stroke(&CORNER, false, base);
+                let actual = write_through_slices(|slices| {
+                    $contour_to_slices(segment.as_ptr(), segment.len(), false, base, slices)
+                });
+                assert_eq!(actual, expected);
+            }
+
+            #[test]
+            fn paths_reject_bad_layouts() {
+                let base = style(
+                    FfiLineJoinKind::Bevel,
+                    FfiLineCapKind::Butt,
+                    FfiLineCapKind::Butt,
+                );
+                let output = $new_buffer();
+                let points = coords(&CORNER);
+                let stroke = |ranges: &[RangeFFI], closed: &[bool]| {
+                    $paths_to_flat(
+                        points.as_ptr(),
+                        points.len(),
+                        ranges.as_ptr(),
+                        closed.as_ptr(),
+                        ranges.len(),
+                        base,
+                        output.ptr(),
+                    )
+                };
+
+                let range = |start, end| RangeFFI { start, end };
+                assert_eq!(stroke(&[range(0, 8)], &[false]), IShapeStatus::InvalidRange);
+                assert_eq!(stroke(&[range(4, 2)], &[false]), IShapeStatus::InvalidRange);
+                assert_eq!(stroke(&[range(1, 5)], &[false]), IShapeStatus::InvalidRange);
+                assert_eq!(stroke(&[range(0, 2)], &[false]), IShapeStatus::TooFewPoints);
+                assert_eq!(stroke(&[range(0, 6)], &[false]), IShapeStatus::Ok);
+
+                // No paths clears the output.
+                assert!(!$read(output.ptr()).is_empty());
+                assert_eq!(stroke(&[], &[]), IShapeStatus::Ok);
+                assert!($read(output.ptr()).is_empty());
+
+                let status = $paths_to_flat(
+                    points.as_ptr(),
+                    points.len(),
+                    ptr::null(),
+                    ptr::null(),
+                    1,
+                    base,
+                    output.ptr(),
+                );
+                assert_eq!(status, IShapeStatus::NullPointer);
+
+                let status = $paths_to_flat(
+                    points.as_ptr(),
+                    points.len(),
+                    [range(0, 6)].as_ptr(),
+                    [false].as_ptr(),
+                    1,
+                    base,
+                    ptr::null_mut(),
+                );
+                assert_eq!(status, IShapeStatus::NullPointer);
+            }
+        }
+    };
+}
+
+stroke_tests!(
+    f64,
+    coord: f64,
+    slices: FlatF64ShapesSlices,
+    tolerance: 1e-6,
+    contour_to_flat: ishape_stroke_f64_contour_to_flat,
+    contour_to_flat_styled: ishape_stroke_f64_contour_to_flat_styled,
+    contour_to_flat_custom_caps: ishape_stroke_f64_contour_to_flat_custom_caps,
+    paths_to_flat: ishape_stroke_f64_paths_to_flat,
+    paths_to_flat_styled: ishape_stroke_f64_paths_to_flat_styled,
+    paths_to_flat_custom_caps: ishape_stroke_f64_paths_to_flat_custom_caps,
+    contour_to_slices: ishape_stroke_f64_contour_to_slices,
+    paths_to_slices: ishape_stroke_f64_paths_to_slices,
+    new_buffer: common::f64_shapes_buffer,
+    read: common::read_f64_shapes,
+);
+
+stroke_tests!(
+    f32,
+    coord: f32,
+    slices: FlatF32ShapesSlices,
+    tolerance: 1e-3,
+    contour_to_flat: ishape_stroke_f32_contour_to_flat,
+    contour_to_flat_styled: ishape_stroke_f32_contour_to_flat_styled,
+    contour_to_flat_custom_caps: ishape_stroke_f32_contour_to_flat_custom_caps,
+    paths_to_flat: ishape_stroke_f32_paths_to_flat,
+    paths_to_flat_styled: ishape_stroke_f32_paths_to_flat_styled,
+    paths_to_flat_custom_caps: ishape_stroke_f32_paths_to_flat_custom_caps,
+    contour_to_slices: ishape_stroke_f32_contour_to_slices,
+    paths_to_slices: ishape_stroke_f32_paths_to_slices,
+    new_buffer: common::f32_shapes_buffer,
+    read: common::read_f32_shapes,
+);
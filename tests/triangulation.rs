@@ -0,0 +1,289 @@
+mod common;
+
+use std::ptr;
+
+use common::{Handle, check_triangulation, raw_slice, square, square_cw};
+use i_shape_ffi::*;
+
+/// A concave L-shaped polygon of area `10 × 10 - 5 × 5`.
+const L_SHAPE: [f64; 12] = [
+    0.0, 0.0, 10.0, 0.0, 10.0, 5.0, 5.0, 5.0, 5.0, 10.0, 0.0, 10.0,
+];
+
+macro_rules! triangulation_tests {
+    (
+        $module:ident,
+        coord: $coord:ty,
+        handle: $handle:ty,
+        shapes: $shapes:ty,
+        slices: $slices:ident,
+        tolerance: $tolerance:expr,
+        create: $create:ident,
+        free: $free:ident,
+        triangulate_flat: $triangulate_flat:ident,
+        triangulate_into_slices: $triangulate_into_slices:ident,
+        new_shapes: $new_shapes:path,
+        push_contour: $push_contour:ident,
+        begin_shape: $begin_shape:ident,
+        new_output: $new_output:ident,
+        free_output: $free_output:ident,
+        view: $view:ident $(,)?
+    ) => {
+        mod $module {
+            use super::*;
+
+            fn triangulator() -> Handle<$handle> {
+                Handle::new($create(256, IntTriangulatorValidation::default()), $free)
+            }
+
+            fn shapes(shapes: &[&[Vec<f64>]]) -> Handle<$shapes> {
+                let buffer = $new_shapes();
+                for shape in shapes {
+                    assert_eq!($begin_shape(buffer.ptr()), IShapeStatus::Ok);
+                    for contour in shape.iter() {
+                        let coords: Vec<$coord> = contour.iter().map(|&v| v as $coord).collect();
+                        let status = $push_contour(buffer.ptr(), coords.as_ptr(), coords.len());
+                        assert_eq!(status, IShapeStatus::Ok);
+                    }
+                }
+                buffer
+            }
+
+            fn triangulate(input: &Handle<$shapes>) -> (Vec<$coord>, Vec<IntTriangulationIndex>) {
+                let triangulator = triangulator();
+                let output = Handle::new($new_output(), $free_output);
+                let status = $triangulate_flat(triangulator.ptr(), input.ptr(), output.ptr());
+                assert_eq!(status, IShapeStatus::Ok);
+
+                let view = $view(output.ptr());
+                (
+                    raw_slice(view.points, view.points_len).to_vec(),
+                    raw_slice(view.indices, view.indices_len).to_vec(),
+                )
+            }
+
+            #[test]
+            fn square_with_hole_preserves_area() {
+                let input = shapes(&[&[square(0.0, 0.0, 10.0), square_cw(2.0, 2.0, 6.0)]]);
+                let (points, indices) = triangulate(&input);
+                assert_eq!(points.len(), 16, "no points are added or dropped");
+                check_triangulation(&points, &indices, 100.0 - 36.0, $tolerance);
+            }
+
+            #[test]
+            fn concave_polygon_preserves_area() {
+                let input = shapes(&[&[L_SHAPE.to_vec()]]);
+                let (points, indices) = triangulate(&input);
+                assert_eq!(indices.len(), 3 * (6 - 2));
+                check_triangulation(&points, &indices, 75.0, $tolerance);
+            }
+
+            #[test]
+            fn several_shapes_share_one_triangulation() {
+                let input = shapes(&[
+                    &[square(0.0, 0.0, 10.0), square_cw(2.0, 2.0, 6.0)],
+                    &[square(20.0, 0.0, 5.0)],
+                ]);
+                let (points, indices) = triangulate(&input);
+                check_triangulation(&points, &indices, 64.0 + 25.0, $tolerance);
+            }
+
+            #[test]
+            fn overlapping_shapes_are_resolved_by_validation() {
+                // Two squares overlapping by 25 are merged with the non-zero fill rule.
+                let input = shapes(&[&[square(0.0, 0.0, 10.0)], &[square(5.0, 5.0, 10.0)]]);
+                let (points, indices) = triangulate(&input);
+                check_triangulation(&points, &indices, 175.0, $tolerance);
+            }
+
+            #[test]
+            fn slices_match_flat_output_after_capacity_query() {
+                let input = shapes(&[&[square(0.0, 0.0, 10.0), square_cw(2.0, 2.0, 6.0)]]);
+                let (expected_points, expected_indices) = triangulate(&input);
+
+                let triangulator = triangulator();
+                let mut slices = $slices {
+                    points: ptr::null_mut(),
+                    points_capacity: 0,
+                    points_len: 0,
+                    indices: ptr::null_mut(),
+                    indices_capacity: 0,
+                    indices_len: 0,
+                };
+                let status = $triangulate_into_slices(triangulator.ptr(), input.ptr(), &mut slices);
+                assert_eq!(status, IShapeStatus::InsufficientCapacity);
+                assert_eq!(slices.points_len, expected_points.len());
+                assert_eq!(slices.indices_len, expected_indices.len());
+
+                let mut points = vec![0 as $coord; slices.points_len];
+                let mut indices = vec![0; slices.indices_len];
+                slices.points = points.as_mut_ptr();
+                slices.points_capacity = points.len();
+                slices.indices = indices.as_mut_ptr();
+                slices.indices_capacity = indices.len();
+
+                let status = $triangulate_into_slices(triangulator.ptr(), input.ptr(), &mut slices);
+                assert_eq!(status, IShapeStatus::Ok);
+                assert_eq!(points, expected_points);
+                assert_eq!(indices, expected_indices);
+
+                // Empty input clears the reported lengths.
+                let empty = $new_shapes();
+                let status = $triangulate_into_slices(triangulator.ptr(), empty.ptr(), &mut slices);
+                assert_eq!(status, IShapeStatus::Ok);
+                assert_eq!(slices.points_len + slices.indices_len, 0);
+            }
+
+            #[test]
+            fn reused_triangulator_matches_fresh_one() {
+                let large = shapes(&[
+                    &[square(0.0, 0.0, 10.0), square_cw(2.0, 2.0, 6.0)],
+                    &[square(20.0, 0.0, 5.0)],
+                ]);
+                let small = shapes(&[&[L_SHAPE.to_vec()]]);
+                let empty = $new_shapes();
+
+                let triangulator = triangulator();
+                let output = Handle::new($new_output(), $free_output);
+                for input in [&large, &small, &empty, &large, &small] {
+                    let status = $triangulate_flat(triangulator.ptr(), input.ptr(), output.ptr());
+                    assert_eq!(status, IShapeStatus::Ok);
+
+                    let view = $view(output.ptr());
+                    let (points, indices) = if input.ptr() == empty.ptr() {
+                        (Vec::new(), Vec::new())
+                    } else {
+                        triangulate(input)
+                    };
+                    assert_eq!(raw_slice(view.points, view.points_len), points.as_slice());
+                    assert_eq!(
+                        raw_slice(view.indices, view.indices_len),
+                        indices.as_slice()
+                    );
+                }
+            }
+
+            #[test]
+            fn empty_input_clears_output() {
+                let triangulator = triangulator();
+                let output = Handle::new($new_output(), $free_output);
+                let input = shapes(&[&[L_SHAPE.to_vec()]]);
+                let status = $triangulate_flat(triangulator.ptr(), input.ptr(), output.ptr());
+                assert_eq!(status, IShapeStatus::Ok);
+                assert!($view(output.ptr()).indices_len > 0);
+
+                let empty = $new_shapes();
+                let status = $triangulate_flat(triangulator.ptr(), empty.ptr(), output.ptr());
+                assert_eq!(status, IShapeStatus::Ok);
+                let view = $view(output.ptr());
+                assert_eq!(view.points_len + view.indices_len, 0);
+            }
+
+            #[test]
+            fn malformed_layout_is_rejected() {
+                let triangulator = triangulator();
+                let output = Handle::new($new_output(), $free_output);
+                let input = shapes(&[&[L_SHAPE.to_vec()]]);
+                let buffer = unsafe { &mut *input.ptr() };
+                buffer.contour_ranges[0].end += 1;
+
+                let status = $triangulate_flat(triangulator.ptr(), input.ptr(), output.ptr());
+                assert_eq!(status, IShapeStatus::InvalidRange);
+
+                let mut slices = $slices {
+                    points: ptr::null_mut(),
+                    points_capacity: 0,
+                    points_len: 0,
+                    indices: ptr::null_mut(),
+                    indices_capacity: 0,
+                    indices_len: 0,
+                };
+                let status = $triangulate_into_slices(triangulator.ptr(), input.ptr(), &mut slices);
+                assert_eq!(status, IShapeStatus::InvalidRange);
+            }
+
+            #[test]
+            fn null_pointers_are_rejected() {
+                let triangulator = triangulator();
+                let output = Handle::new($new_output(), $free_output);
+                let input = shapes(&[&[L_SHAPE.to_vec()]]);
+
+                assert_eq!(
+                    $triangulate_flat(ptr::null_mut(), input.ptr(), output.ptr()),
+                    IShapeStatus::NullPointer
+                );
+                assert_eq!(
+                    $triangulate_flat(triangulator.ptr(), ptr::null(), output.ptr()),
+                    IShapeStatus::NullPointer
+                );
+                assert_eq!(
+                    $triangulate_flat(triangulator.ptr(), input.ptr(), ptr::null_mut()),
+                    IShapeStatus::NullPointer
+                );
+                assert_eq!(
+                    $triangulate_into_slices(triangulator.ptr(), input.ptr(), ptr::null_mut()),
+                    IShapeStatus::NullPointer
+                );
+
+                $free(ptr::null_mut());
+            }
+        }
+    };
+}
+
+triangulation_tests!(
+    int,
+    coord: i32,
+    handle: IntTriangulator,
+    shapes: FlatShapesBuffer,
+    slices: FlatIntTriangulationSlices,
+    tolerance: 0.0,
+    create: ishape_triangle_int_triangulator_create,
+    free: ishape_triangle_int_triangulator_free,
+    triangulate_flat: ishape_triangle_int_triangulator_triangulate_flat,
+    triangulate_into_slices: ishape_triangle_int_triangulator_triangulate_into_slices,
+    new_shapes: common::int_shapes_buffer,
+    push_contour: ishape_flat_shapes_push_contour,
+    begin_shape: ishape_flat_shapes_begin_shape,
+    new_output: ishape_triangle_flat_triangulation_create,
+    free_output: ishape_triangle_flat_triangulation_free,
+    view: ishape_triangle_flat_triangulation_view,
+);
+
+triangulation_tests!(
+    f32,
+    coord: f32,
+    handle: Float32Triangulator,
+    shapes: FlatF32ShapesBuffer,
+    slices: FlatF32TriangulationSlices,
+    tolerance: 1e-3,
+    create: ishape_triangle_f32_triangulator_create,
+    free: ishape_triangle_f32_triangulator_free,
+    triangulate_flat: ishape_triangle_f32_triangulator_triangulate_flat,
+    triangulate_into_slices: ishape_triangle_f32_triangulator_triangulate_into_slices,
+    new_shapes: common::f32_shapes_buffer,
+    push_contour: ishape_flat_f32_shapes_push_contour,
+    begin_shape: ishape_flat_f32_shapes_begin_shape,
+    new_output: ishape_triangle_flat_f32_triangulation_create,
+    free_output: ishape_triangle_flat_f32_triangulation_free,
+    view: ishape_triangle_flat_f32_triangulation_view,
+);
+
+triangulation_tests!(
+    f64,
+    coord: f64,
+    handle: Float64Triangulator,
+    shapes: FlatF64ShapesBuffer,
+    slices: FlatF64TriangulationSlices,
+    tolerance: 1e-6,
+    create: ishape_triangle_f64_triangulator_create,
+    free: ishape_triangle_f64_triangulator_free,
+    triangulate_flat: ishape_triangle_f64_triangulator_triangulate_flat,
+    triangulate_into_slices: ishape_triangle_f64_triangulator_triangulate_into_slices,
+    new_shapes: common::f64_shapes_buffer,
+    push_contour: ishape_flat_f64_shapes_push_contour,
+    begin_shape: ishape_flat_f64_shapes_begin_shape,
+    new_output: ishape_triangle_flat_f64_triangulation_create,
+    free_output: ishape_triangle_flat_f64_triangulation_free,
+    view: ishape_triangle_flat_f64_triangulation_view,
+);